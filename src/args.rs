@@ -0,0 +1,1136 @@
+use clap::{ App, Arg, ArgMatches, SubCommand };
+
+
+use mitre_assistant::modules::parser::EnterpriseMatrixParser;
+use mitre_assistant::modules::webclient::WebClient;
+use mitre_assistant::modules::searcher::EnterpriseMatrixSearcher;
+use mitre_assistant::modules::expression::SearchExpression;
+use mitre_assistant::modules::importer::MatrixImporter;
+use mitre_assistant::modules::differ::EnterpriseMatrixDiffer;
+use mitre_assistant::modules::migrator::MappingMigrator;
+use mitre_assistant::modules::coverage::EnterpriseCoverage;
+use mitre_assistant::modules::navigator::NavigatorLayer;
+use mitre_assistant::modules::gridview::{ EnterpriseMatrixGrid, MIN_WIDTH };
+use mitre_assistant::modules::heatmap::EnterpriseHeatmap;
+use mitre_assistant::modules::shell::InteractiveShell;
+use mitre_assistant::structs::errors::AssistantError;
+
+
+/// # Globals
+/// Represent global variables used throughout this source file.
+//static _URL: &str = "https://github.com/dfirence/mitre-assistant";
+static _VERSION: &str = "v.0.0.12"; 
+static _AUTHOR: &str = "carlos diaz | @dfirence\n\n";
+static _ABOUT: &str = "Mitre Attack Assistant\n\n\tA more useful utility for the ATT&CK Matrix";
+
+
+/// # ArgumentsParser
+/// This object wraps the cli params into an object for convenience.
+/// It provides methods to parse the cli user inputs from here and 
+/// keep the main source file clean for developers contributing to `nginja`
+/// 
+/// # Example
+/// ```ignore
+/// let _args = ArgumentsParser::new();
+/// ```
+#[derive(Debug)]
+pub struct ArgumentsParser<'a> {
+    pub inputs: ArgMatches<'a>
+}
+impl ArgumentsParser<'_> {
+    /// # ArgumentsParser Constructor
+    /// Creates a new instance  of a cli arguments object
+    pub fn new() -> Self
+    {
+        ArgumentsParser {
+            inputs: App::new("\n\n\nmitre-assistant")
+                        .author(_AUTHOR)
+                        .version(_VERSION)
+                        .about(_ABOUT)
+                        .subcommand(
+                            SubCommand::with_name("download")
+                                       .author(_AUTHOR)
+                                       .version(_VERSION)
+                                       .about("Download a Matrix From The Mitre CTI Repo")
+                                       .arg(
+                                            Arg::with_name("matrix")
+                                                .short("m")
+                                                .long("matrix")
+                                                .value_name("matrix_name")
+                                                .takes_value(true)
+                                                .help("Load a Matrix From ATT&CK: (Enterprise|Mobile|Pre-Attack)")
+                                        )
+                                       .arg(
+                                            Arg::with_name("version")
+                                                .long("version")
+                                                .value_name("version")
+                                                .takes_value(true)
+                                                .help("Pin The Download To An ATT&CK Release, Tag Or Commit - e.g., v7.2 | Must use with `-m`")
+                                        )
+                                       .arg(
+                                            Arg::with_name("sha256")
+                                                .long("sha256")
+                                                .value_name("checksum")
+                                                .takes_value(true)
+                                                .help("Expected SHA-256 Of The Matrix, The Download Is Rejected When It Differs | Must use with `-m`")
+                                        )
+                        )
+                        .subcommand(
+                            SubCommand::with_name("update")
+                                       .author(_AUTHOR)
+                                       .version(_VERSION)
+                                       .about("Update a Downloaded Matrix When The Mitre CTI Repo Has A Newer One")
+                                       .arg(
+                                            Arg::with_name("matrix")
+                                                .short("m")
+                                                .long("matrix")
+                                                .value_name("matrix_name")
+                                                .takes_value(true)
+                                                .help("Update a Matrix From ATT&CK: (Enterprise|Mobile|Pre-Attack)")
+                                        )
+                                       .arg(
+                                            Arg::with_name("version")
+                                                .long("version")
+                                                .value_name("version")
+                                                .takes_value(true)
+                                                .help("Update The Matrix Of An ATT&CK Release, Tag Or Commit - e.g., v7.2 | Must use with `-m`")
+                                        )
+                                       .arg(
+                                            Arg::with_name("sha256")
+                                                .long("sha256")
+                                                .value_name("checksum")
+                                                .takes_value(true)
+                                                .help("Expected SHA-256 Of The Matrix, The Update Is Rejected When It Differs | Must use with `-m`")
+                                        )
+                                       .arg(
+                                            Arg::with_name("check")
+                                                .long("check")
+                                                .takes_value(false)
+                                                .help("Only Report Whether A Newer Matrix Is Available, Without Installing It | Must use with `-m`")
+                                        )
+                        )
+                        .subcommand(
+                            SubCommand::with_name("import")
+                                       .author(_AUTHOR)
+                                       .version(_VERSION)
+                                       .about("Import a Local STIX Bundle Into The Config Folder - i.e., Offline Networks")
+                                       .arg(
+                                            Arg::with_name("file")
+                                                .short("f")
+                                                .long("file")
+                                                .value_name("file")
+                                                .takes_value(true)
+                                                .help("Local STIX Bundle To Import, Use `-` Or Omit To Read From Stdin")
+                                        )
+                                       .arg(
+                                            Arg::with_name("matrix")
+                                                .short("m")
+                                                .long("matrix")
+                                                .value_name("matrix_name")
+                                                .takes_value(true)
+                                                .help("Expected Matrix Of The Bundle: (Enterprise|Mobile|Pre-Attack) | Detected When Omitted")
+                                        )
+                                       .arg(
+                                            Arg::with_name("version")
+                                                .long("version")
+                                                .value_name("version")
+                                                .takes_value(true)
+                                                .help("Install The Bundle As An ATT&CK Release, Tag Or Commit - e.g., v7.2")
+                                        )
+                        )
+                        .subcommand(
+                            SubCommand::with_name("baseline")
+                            .author(_AUTHOR)
+                            .version(_VERSION)
+                            .about("Parse a Matrix into comprehensive insights")
+                            .arg(
+                                 Arg::with_name("matrix")
+                                     .short("m")
+                                     .long("matrix")
+                                     .value_name("matrix_name")
+                                     .takes_value(true)
+                                     .help("Load a Matrix From ATT&CK: (Enterprise|Mobile|Pre-Attack)")
+                             )
+                            .arg(
+                                 Arg::with_name("version")
+                                     .long("version")
+                                     .value_name("version")
+                                     .takes_value(true)
+                                     .help("Baseline A Downloaded ATT&CK Release, Tag Or Commit - e.g., v7.2 | Must use with `-m`")
+                             )
+                        )
+                        .subcommand(
+                            SubCommand::with_name("search")
+                            .author(_AUTHOR)
+                            .version(_VERSION)
+                            .about("Search The Baseline")
+                            .arg(
+                                 Arg::with_name("matrix")
+                                     .short("m")
+                                     .long("matrix")
+                                     .value_name("matrix_name")
+                                     .takes_value(true)
+                                     .help("Load a Matrix From ATT&CK: (Enterprise|Mobile|Pre-Attack)")
+                             )
+                             .arg(
+                                Arg::with_name("term_search")
+                                .short("t")
+                                .long("term")
+                                .value_name("term_search")
+                                .takes_value(true)
+                                .help("Search By Technique Name Or A Query - e.g., Data Staged, \"tactic:persistence AND NOT platform:windows\" | Must use with `-m`")
+                             )
+                             .arg(
+                                Arg::with_name("subtechniques")
+                                .short("s")
+                                .long("subtechniques")
+                                .value_name("subtechniques")
+                                .takes_value(false)
+                                .help("Search & Render Subtechniques | Must use with `-m` and `-t`")                                 
+                             )
+                             .arg(
+                                Arg::with_name("export")
+                                .short("e")
+                                .long("export-to")
+                                .value_name("export_type")
+                                .takes_value(true)
+                                .help("Export Table Results: (csv|json|jsonl|markdown|html|navigator) | `csv` Must use with `-m`, `-t`, and `-f`")                                 
+                             )
+                             .arg(
+                                Arg::with_name("file")
+                                .short("f")
+                                .long("file")
+                                .value_name("file")
+                                .takes_value(true)
+                                .help("Output File | Must use with `-m`, `-t`, and `-e`")                                 
+                             )
+                             .arg(
+                                Arg::with_name("version")
+                                .long("version")
+                                .value_name("version")
+                                .takes_value(true)
+                                .help("Search The Baseline Of An ATT&CK Release, Tag Or Commit - e.g., v7.2 | Must use with `-m` and `-t`")
+                             )
+                             .arg(
+                                Arg::with_name("layer")
+                                .long("layer")
+                                .value_name("layer_file")
+                                .takes_value(true)
+                                .help("Search The Techniques Of An ATT&CK Navigator Layer | Must use with `-m`")
+                             )
+                             .arg(
+                                Arg::with_name("fulltext")
+                                .long("fulltext")
+                                .value_name("fulltext")
+                                .takes_value(false)
+                                .help("Search The Words Of The Descriptions & Detections - e.g., lsass memory | Must use with `-m` and `-t`")
+                             )
+                             .arg(
+                                Arg::with_name("regex")
+                                .long("regex")
+                                .value_name("regex")
+                                .takes_value(false)
+                                .conflicts_with("fulltext")
+                                .help("Search The IDs, Names, Aliases & Descriptions With A Regular Expression - e.g., \"^T1003\\.00[1-3]$\" | Must use with `-m` and `-t`")
+                             )
+                             .arg(
+                                Arg::with_name("platform")
+                                .long("platform")
+                                .value_name("platform")
+                                .takes_value(true)
+                                .help("Keep The Techniques Of Any Of The Platforms - e.g., linux,macos | Must use with `-m`")
+                             )
+                             .arg(
+                                Arg::with_name("tactic")
+                                .long("tactic")
+                                .value_name("tactic")
+                                .takes_value(true)
+                                .help("Keep The Techniques Of Any Of The Tactics - e.g., persistence | Must use with `-m`")
+                             )
+                             .arg(
+                                Arg::with_name("datasource")
+                                .long("datasource")
+                                .value_name("datasource")
+                                .takes_value(true)
+                                .help("Keep The Techniques With Any Of The Datasources - e.g., process-monitoring | Must use with `-m`")
+                             )
+                             .arg(
+                                Arg::with_name("adversary")
+                                .long("adversary")
+                                .value_name("adversary")
+                                .takes_value(true)
+                                .help("Keep The Techniques Used By Any Of The Adversaries - e.g., apt29 | Must use with `-m`")
+                             )                                                                                      
+                        )
+                        .subcommand(
+                            SubCommand::with_name("diff")
+                            .author(_AUTHOR)
+                            .version(_VERSION)
+                            .about("Compare Two Versions Of The Enterprise Matrix")
+                            .arg(
+                                 Arg::with_name("from")
+                                     .long("from")
+                                     .value_name("version_or_file")
+                                     .takes_value(true)
+                                     .help("Old Matrix: A Baseline Version - e.g., v7.2 or `current`, A Baseline File, Or A STIX Bundle | Must use with `--to`")
+                             )
+                            .arg(
+                                 Arg::with_name("to")
+                                     .long("to")
+                                     .value_name("version_or_file")
+                                     .takes_value(true)
+                                     .help("New Matrix: A Baseline Version - e.g., v8.0 or `current`, A Baseline File, Or A STIX Bundle | Must use with `--from`")
+                             )
+                            .arg(
+                                 Arg::with_name("export")
+                                     .short("e")
+                                     .long("export-to")
+                                     .value_name("export_type")
+                                     .takes_value(true)
+                                     .help("Export Changes: (csv|json) | `csv` Must use with `-f`")
+                             )
+                            .arg(
+                                 Arg::with_name("file")
+                                     .short("f")
+                                     .long("file")
+                                     .value_name("file")
+                                     .takes_value(true)
+                                     .help("Output File | Must use with `-e`")
+                             )
+                        )
+                        .subcommand(
+                            SubCommand::with_name("migrate")
+                            .author(_AUTHOR)
+                            .version(_VERSION)
+                            .about("Migrate The Technique IDs Of Your Mappings To The Enterprise Baseline")
+                            .arg(
+                                 Arg::with_name("file")
+                                     .short("f")
+                                     .long("file")
+                                     .value_name("file")
+                                     .takes_value(true)
+                                     .help("CSV Or JSON File Of Mappings - e.g., Detection Rules Tagged With Technique IDs")
+                             )
+                            .arg(
+                                 Arg::with_name("output")
+                                     .short("o")
+                                     .long("output")
+                                     .value_name("file")
+                                     .takes_value(true)
+                                     .help("Migrated Mappings File, Defaults To `<file>.migrated.<ext>` | Must use with `-f`")
+                             )
+                            .arg(
+                                 Arg::with_name("field")
+                                     .long("field")
+                                     .value_name("column_or_key")
+                                     .takes_value(true)
+                                     .help("Only Migrate The IDs Of This Column Or Key, Defaults To All | Must use with `-f`")
+                             )
+                            .arg(
+                                 Arg::with_name("version")
+                                     .long("version")
+                                     .value_name("version")
+                                     .takes_value(true)
+                                     .help("Migrate To The Baseline Of An ATT&CK Release, Tag Or Commit - e.g., v8.0 | Must use with `-f`")
+                             )
+                        )
+                        .subcommand(
+                            SubCommand::with_name("coverage")
+                            .author(_AUTHOR)
+                            .version(_VERSION)
+                            .about("Measure The Coverage Of Your Detection Rules Against The Enterprise Baseline")
+                            .arg(
+                                 Arg::with_name("rules")
+                                     .short("r")
+                                     .long("rules")
+                                     .value_name("file")
+                                     .takes_value(true)
+                                     .help("CSV File Of Detection Rules Tagged With Technique IDs, Or An ATT&CK Navigator Layer")
+                             )
+                            .arg(
+                                 Arg::with_name("prioritize")
+                                     .long("prioritize")
+                                     .takes_value(false)
+                                     .help("Rank The Uncovered Techniques & Subtechniques By The Adversaries Using Them | Must use with `-r`")
+                             )
+                            .arg(
+                                 Arg::with_name("adversaries")
+                                     .long("adversaries")
+                                     .value_name("adversaries")
+                                     .takes_value(true)
+                                     .help("Comma Separated Adversary Names, Aliases Or Group IDs To Prioritize By | Default: All Adversaries")
+                             )
+                            .arg(
+                                 Arg::with_name("datasources")
+                                     .short("d")
+                                     .long("datasources")
+                                     .value_name("datasources")
+                                     .takes_value(true)
+                                     .conflicts_with("rules")
+                                     .help("Assess Visibility With The Datasources You Collect: A Comma Separated List, Or A File With One Per Line | Cannot use with `-r`")
+                             )
+                            .arg(
+                                 Arg::with_name("field")
+                                     .long("field")
+                                     .value_name("column")
+                                     .takes_value(true)
+                                     .help("Only Read The IDs Of This Column, Defaults To All | Must use with `-r`")
+                             )
+                            .arg(
+                                 Arg::with_name("gaps")
+                                     .long("gaps")
+                                     .takes_value(false)
+                                     .help("List The Uncovered, Or Not Fully Observable, Techniques & Subtechniques | Must use with `-r` or `-d`")
+                             )
+                            .arg(
+                                 Arg::with_name("export")
+                                     .short("e")
+                                     .long("export-to")
+                                     .value_name("export_type")
+                                     .takes_value(true)
+                                     .help("Export Table Results: (csv|json|navigator) | `csv` Must use with `-f`")
+                             )
+                            .arg(
+                                 Arg::with_name("file")
+                                     .short("f")
+                                     .long("file")
+                                     .value_name("file")
+                                     .takes_value(true)
+                                     .help("Output File | Must use with `-e`")
+                             )
+                            .arg(
+                                 Arg::with_name("version")
+                                     .long("version")
+                                     .value_name("version")
+                                     .takes_value(true)
+                                     .help("Use The Baseline Of An ATT&CK Release, Tag Or Commit - e.g., v7.2 | Must use with `-r`")
+                             )
+                        )
+                        .subcommand(
+                            SubCommand::with_name("heatmap")
+                            .author(_AUTHOR)
+                            .version(_VERSION)
+                            .about("Score The Techniques By The Groups, Malware And Tools Using Them")
+                            .arg(
+                                 Arg::with_name("tactic")
+                                     .short("t")
+                                     .long("tactic")
+                                     .value_name("tactic")
+                                     .takes_value(true)
+                                     .help("Show The Heatmap Of A Tactic - e.g., credential-access")
+                             )
+                            .arg(
+                                 Arg::with_name("subtechniques")
+                                     .short("s")
+                                     .long("subtechniques")
+                                     .takes_value(false)
+                                     .help("Score The Subtechniques With The Techniques")
+                             )
+                            .arg(
+                                 Arg::with_name("top")
+                                     .long("top")
+                                     .value_name("count")
+                                     .takes_value(true)
+                                     .help("Keep The Hottest Techniques Of Each Tactic - e.g., 10")
+                             )
+                            .arg(
+                                 Arg::with_name("export")
+                                     .short("e")
+                                     .long("export-to")
+                                     .value_name("export_format")
+                                     .takes_value(true)
+                                     .help("Export The Scores (csv|navigator)")
+                             )
+                            .arg(
+                                 Arg::with_name("file")
+                                     .short("f")
+                                     .long("file")
+                                     .value_name("file_path")
+                                     .takes_value(true)
+                                     .help("The File To Export To | Default: stdout")
+                             )
+                            .arg(
+                                 Arg::with_name("version")
+                                     .long("version")
+                                     .value_name("version")
+                                     .takes_value(true)
+                                     .help("Score The Baseline Of An ATT&CK Release, Tag Or Commit - e.g., v7.2")
+                             )
+                        )
+                        .subcommand(
+                            SubCommand::with_name("matrix")
+                            .author(_AUTHOR)
+                            .version(_VERSION)
+                            .about("View The Enterprise Matrix As A Grid Of Tactic Columns")
+                            .arg(
+                                 Arg::with_name("platform")
+                                     .short("p")
+                                     .long("platform")
+                                     .value_name("platform")
+                                     .takes_value(true)
+                                     .help("Show The Techniques Of A Platform - e.g., windows")
+                             )
+                            .arg(
+                                 Arg::with_name("adversary")
+                                     .short("a")
+                                     .long("adversary")
+                                     .value_name("adversaries")
+                                     .takes_value(true)
+                                     .help("Show The Techniques Used By Comma Separated Adversary Names Or Aliases")
+                             )
+                            .arg(
+                                 Arg::with_name("highlight")
+                                     .long("highlight")
+                                     .value_name("techniques")
+                                     .takes_value(true)
+                                     .help("Highlight Comma Separated Technique IDs, Or The Techniques Of A Navigator Layer Or A File")
+                             )
+                            .arg(
+                                 Arg::with_name("subtechniques")
+                                     .short("s")
+                                     .long("subtechniques")
+                                     .takes_value(false)
+                                     .help("Show The Subtechniques Under Their Technique")
+                             )
+                            .arg(
+                                 Arg::with_name("width")
+                                     .long("width")
+                                     .value_name("columns")
+                                     .takes_value(true)
+                                     .help("Wrap The Grid To This Width, At Least 22 Columns | Default: The Terminal Width")
+                             )
+                            .arg(
+                                 Arg::with_name("version")
+                                     .long("version")
+                                     .value_name("version")
+                                     .takes_value(true)
+                                     .help("View The Baseline Of An ATT&CK Release, Tag Or Commit - e.g., v7.2")
+                             )
+                        )
+                        .subcommand(
+                            SubCommand::with_name("report")
+                            .author(_AUTHOR)
+                            .version(_VERSION)
+                            .about("Create A Self-Contained HTML Report Of The Matrix For Threat Briefings")
+                            .arg(
+                                 Arg::with_name("matrix")
+                                     .short("m")
+                                     .long("matrix")
+                                     .value_name("matrix_name")
+                                     .takes_value(true)
+                                     .help("Report On A Matrix From ATT&CK: (Enterprise)")
+                             )
+                            .arg(
+                                 Arg::with_name("adversaries")
+                                     .long("adversaries")
+                                     .value_name("adversaries")
+                                     .takes_value(true)
+                                     .help("Comma Separated Adversary Names To Profile | Default: All Adversaries")
+                             )
+                            .arg(
+                                 Arg::with_name("file")
+                                     .short("f")
+                                     .long("file")
+                                     .value_name("file")
+                                     .takes_value(true)
+                                     .help("Output HTML File | Default: stdout")
+                             )
+                            .arg(
+                                 Arg::with_name("version")
+                                     .long("version")
+                                     .value_name("version")
+                                     .takes_value(true)
+                                     .help("Report On The Baseline Of An ATT&CK Release, Tag Or Commit - e.g., v7.2 | Must use with `-m`")
+                             )
+                        )
+                        .subcommand(
+                            SubCommand::with_name("layer")
+                            .author(_AUTHOR)
+                            .version(_VERSION)
+                            .about("Import An ATT&CK Navigator Layer As A Technique List")
+                            .arg(
+                                 Arg::with_name("input")
+                                     .short("i")
+                                     .long("input")
+                                     .value_name("layer_file")
+                                     .takes_value(true)
+                                     .help("Navigator Layer File To Import")
+                             )
+                            .arg(
+                                 Arg::with_name("min_score")
+                                     .long("min-score")
+                                     .value_name("score")
+                                     .takes_value(true)
+                                     .help("Keep Only The Techniques Scored At Least This Value | Must use with `-i`")
+                             )
+                            .arg(
+                                 Arg::with_name("file")
+                                     .short("f")
+                                     .long("file")
+                                     .value_name("file")
+                                     .takes_value(true)
+                                     .help("Save The Techniques As A CSV File For `coverage -r` Or `migrate -f` | Default: A Comma Separated List For `search -t`")
+                             )
+                        )
+                        .subcommand(
+                            SubCommand::with_name("interactive")
+                            .author(_AUTHOR)
+                            .version(_VERSION)
+                            .about("Query The Baseline From A Prompt, With History & Tab Completion")
+                            .arg(
+                                 Arg::with_name("matrix")
+                                     .short("m")
+                                     .long("matrix")
+                                     .value_name("matrix_name")
+                                     .takes_value(true)
+                                     .help("Load a Matrix From ATT&CK: (Enterprise|Mobile|Pre-Attack)")
+                             )
+                            .arg(
+                                 Arg::with_name("version")
+                                     .long("version")
+                                     .value_name("version")
+                                     .takes_value(true)
+                                     .help("Query The Baseline Of An ATT&CK Release, Tag Or Commit - e.g., v7.2 | Must use with `-m`")
+                             )
+                        )
+                        .subcommand(
+                            SubCommand::with_name("show")
+                            .author(_AUTHOR)
+                            .version(_VERSION)
+                            .about("Show Everything About A Technique, Tactic, Group, Software, Mitigation Or Datasource")
+                            .arg(
+                                 Arg::with_name("matrix")
+                                     .short("m")
+                                     .long("matrix")
+                                     .value_name("matrix_name")
+                                     .takes_value(true)
+                                     .help("Load a Matrix From ATT&CK: (Enterprise|Mobile|Pre-Attack) | Default: enterprise")
+                             )
+                            .arg(
+                                 Arg::with_name("object")
+                                     .index(1)
+                                     .value_name("ID_or_name")
+                                     .help("The ID Or The Name Of The Object - e.g., T1003.001, TA0006, \"APT29\", S0002, M1026, process-monitoring")
+                             )
+                            .arg(
+                                 Arg::with_name("export")
+                                     .short("e")
+                                     .long("export-to")
+                                     .value_name("export_type")
+                                     .takes_value(true)
+                                     .help("Export The Detail: (json|jsonl|markdown) | Default: Text")
+                             )
+                            .arg(
+                                 Arg::with_name("file")
+                                     .short("f")
+                                     .long("file")
+                                     .value_name("file")
+                                     .takes_value(true)
+                                     .help("Output File | Must use with `-e`")
+                             )
+                            .arg(
+                                 Arg::with_name("version")
+                                     .long("version")
+                                     .value_name("version")
+                                     .takes_value(true)
+                                     .help("Show The Baseline Of An ATT&CK Release, Tag Or Commit - e.g., v7.2 | Must use with `-m`")
+                             )
+                        )
+                        .get_matches()
+        }
+    }
+    /// # ArgumentsParser - Parse
+    /// This method collects user inputs and should be used as the entry point by the developer
+    /// to parse the inputs, execute logic matching the usage of the argument and returning values
+    /// to a renderer.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let _args = ArgumentsParser::new();
+    /// 
+    /// let _results = _args.parse();   // Returns results from function invoked
+    ///                                 // The function invoked is relevant to the args provided by the user
+    /// ```
+    pub fn parse(&self) -> Result<(), AssistantError>
+    {
+        if self.inputs.is_present("download") {
+            self.download()?;
+        } else if self.inputs.is_present("update") {
+            self.update()?;
+        } else if self.inputs.is_present("import") {
+            self.import()?;
+        } else if self.inputs.is_present("baseline") {
+            self.baseline()?;
+        } else if self.inputs.is_present("search") {
+            self.search()?;
+        } else if self.inputs.is_present("diff") {
+            self.diff()?;
+        } else if self.inputs.is_present("migrate") {
+            self.migrate()?;
+        } else if self.inputs.is_present("coverage") {
+            self.coverage()?;
+        } else if self.inputs.is_present("layer") {
+            self.layer()?;
+        } else if self.inputs.is_present("report") {
+            self.report()?;
+        } else if self.inputs.is_present("matrix") {
+            self.matrix()?;
+        } else if self.inputs.is_present("heatmap") {
+            self.heatmap()?;
+        } else if self.inputs.is_present("interactive") {
+            self.interactive()?;
+        } else if self.inputs.is_present("show") {
+            self.show()?;
+        }
+        Ok(())
+    }
+    pub fn download(&self) -> Result<(), AssistantError>
+    {
+        let _subcommand = self.inputs.subcommand_matches("download").unwrap();
+        let _matrix = match _subcommand.is_present("matrix") {
+            true => _subcommand.value_of("matrix").unwrap(),
+            false => "None"
+        };
+        let _version = match _subcommand.is_present("version") {
+            true => _subcommand.value_of("version").unwrap(),
+            false => "None"
+        };
+        let _sha256 = match _subcommand.is_present("sha256") {
+            true => _subcommand.value_of("sha256").unwrap(),
+            false => "None"
+        };
+        if _matrix != "None" {
+            self.install_matrix(_matrix, _version, _sha256)?;
+        }
+        Ok(())
+    }
+    pub fn update(&self) -> Result<(), AssistantError>
+    {
+        let _subcommand = self.inputs.subcommand_matches("update").unwrap();
+        let _matrix = match _subcommand.is_present("matrix") {
+            true => _subcommand.value_of("matrix").unwrap(),
+            false => "None"
+        };
+        let _version = match _subcommand.is_present("version") {
+            true => _subcommand.value_of("version").unwrap(),
+            false => "None"
+        };
+        let _sha256 = match _subcommand.is_present("sha256") {
+            true => _subcommand.value_of("sha256").unwrap(),
+            false => "None"
+        };
+        match _matrix {
+            "enterprise" | "pre-attack" | "mobile" => {
+                if _subcommand.is_present("check") {
+                    WebClient::load()?.check_update(_matrix, _version)?;
+                } else {
+                    self.install_matrix(_matrix, _version, _sha256)?;
+                }
+            },
+            _ => ()
+        }
+        Ok(())
+    }
+    /// # ArgumentsParser - Install Matrix (Private Method)
+    /// Downloads a matrix when it changed on the server, and refreshes
+    /// its baseline when one was already created.
+    fn install_matrix(&self, matrix: &str, version: &str, sha256: &str) -> Result<(), AssistantError>
+    {
+        let _wc = WebClient::load()?;
+        let _changed = match matrix {
+            "enterprise" | "pre-attack" | "mobile" => _wc.download(matrix, version, sha256)?,
+            _ => false
+        };
+        if _changed && matrix == "enterprise" && EnterpriseMatrixParser::has_baseline(version) {
+            let mut _emp = EnterpriseMatrixParser::new();
+            _emp.baseline(matrix, version)?;
+            _emp.save_baseline(version)?;
+        }
+        Ok(())
+    }
+    pub fn import(&self) -> Result<(), AssistantError>
+    {
+        let _subcommand = self.inputs.subcommand_matches("import").unwrap();
+        let _file = match _subcommand.is_present("file") {
+            true => _subcommand.value_of("file").unwrap(),
+            false => "-"
+        };
+        let _matrix = match _subcommand.is_present("matrix") {
+            true => _subcommand.value_of("matrix").unwrap(),
+            false => "None"
+        };
+        let _version = match _subcommand.is_present("version") {
+            true => _subcommand.value_of("version").unwrap(),
+            false => "None"
+        };
+        let _mi = MatrixImporter::from_file(_file)?;
+        if _matrix != "None" && _matrix.to_lowercase() != _mi.domain {
+            return Err(AssistantError::InvalidQuery(format!("Bundle Belongs To The `{}` Matrix, Expected `{}`", _mi.domain, _matrix)));
+        }
+        let _installed = _mi.install(_version)?;
+        println!("\n\t[ INFO ] Imported Matrix: {} | Baseline It With: `baseline -m {}{}`\n",
+                 _installed,
+                 _mi.domain,
+                 if _version == "None" { "".to_string() } else { format!(" --version {}", _version) });
+        Ok(())
+    }
+    pub fn baseline(&self) -> Result<(), AssistantError>
+    {
+        let _subcommand = self.inputs.subcommand_matches("baseline").unwrap();
+        let _matrix = match _subcommand.is_present("matrix") {
+            true => _subcommand.value_of("matrix").unwrap(),
+            false => "None"
+        };
+        let _version = match _subcommand.is_present("version") {
+            true => _subcommand.value_of("version").unwrap(),
+            false => "None"
+        };
+        if _matrix != "None" {
+            let mut _emp = EnterpriseMatrixParser::new();
+            _emp.baseline(_matrix, _version)?;
+            _emp.save_baseline(_version)?;
+        }
+        Ok(())
+    }
+    pub fn search(&self) -> Result<(), AssistantError>
+    {
+        let _subcommand = self.inputs.subcommand_matches("search").unwrap();
+        let _matrix = match _subcommand.is_present("matrix") {
+            true => _subcommand.value_of("matrix").unwrap(),
+            false => "None"
+        };
+        let _search_term = match _subcommand.is_present("term_search") {
+            true => _subcommand.value_of("term_search").unwrap(),
+            false => "None"
+        };
+        let _wants_subtechniques = _subcommand.is_present("subtechniques");
+        let _wants_export = match _subcommand.is_present("export") {
+            true => _subcommand.value_of("export").unwrap(),
+            false => "None"
+        };
+        let _wants_outfile = match _subcommand.is_present("file") {
+            true => _subcommand.value_of("file").unwrap(),
+            false => "None"
+        };
+        let _version = match _subcommand.is_present("version") {
+            true => _subcommand.value_of("version").unwrap(),
+            false => "None"
+        };
+        let _wants_fulltext = _subcommand.is_present("fulltext");
+        let _wants_regex = _subcommand.is_present("regex");
+        let mut _filters: Vec<(&str, &str)> = vec![];
+        for _field in ["platform", "tactic", "datasource", "adversary"].iter() {
+            let _values = match _subcommand.is_present(_field) {
+                true => _subcommand.value_of(_field).unwrap(),
+                false => "None"
+            };
+            _filters.push((_field, _values));
+        }
+        let _filter = SearchExpression::from_filters(&_filters)?;
+        // The filters alone search all of the techniques
+        let _search_term = match (_search_term, &_filter) {
+            ("None", Some(_)) => "techniques",
+            _ => _search_term
+        };
+        let _layer_terms = match _subcommand.is_present("layer") {
+            true => NavigatorLayer::load(_subcommand.value_of("layer").unwrap())?.technique_ids(None).join(","),
+            false => "None".to_string()
+        };
+        let _search_term = match _layer_terms.as_str() {
+            "None" => _search_term,
+            "" => return Err(AssistantError::InvalidQuery("The Layer Has No Enabled Techniques".to_string())),
+            _ => _layer_terms.as_str()
+        };
+        if _matrix != "None" && _search_term != "None" {
+            let _searcher = EnterpriseMatrixSearcher::new(_matrix, _version)?.with_filter(_filter)?;
+            if _wants_fulltext {
+                _searcher.search_text(_search_term, _wants_export, _wants_outfile)?;
+            } else if _wants_regex {
+                _searcher.search_regex(_search_term, _wants_export, _wants_outfile)?;
+            } else {
+                _searcher.search(_search_term,
+                                 _wants_subtechniques,
+                                 _wants_export,
+                                 _wants_outfile)?;
+            }
+        }        
+        Ok(())
+    }
+    pub fn diff(&self) -> Result<(), AssistantError>
+    {
+        let _subcommand = self.inputs.subcommand_matches("diff").unwrap();
+        let _from = match _subcommand.is_present("from") {
+            true => _subcommand.value_of("from").unwrap(),
+            false => "None"
+        };
+        let _to = match _subcommand.is_present("to") {
+            true => _subcommand.value_of("to").unwrap(),
+            false => "None"
+        };
+        let _wants_export = match _subcommand.is_present("export") {
+            true => _subcommand.value_of("export").unwrap(),
+            false => "None"
+        };
+        let _wants_outfile = match _subcommand.is_present("file") {
+            true => _subcommand.value_of("file").unwrap(),
+            false => "None"
+        };
+        if _wants_export == "csv" && _wants_outfile == "None" {
+            return Err(AssistantError::InvalidQuery("Exporting The Changes To CSV Requires An Output File With `-f`".to_string()));
+        }
+        if _from != "None" && _to != "None" {
+            let _differ = EnterpriseMatrixDiffer::new(_from, _to)?;
+            _differ.render(_wants_export, _wants_outfile)?;
+        }
+        Ok(())
+    }
+    pub fn migrate(&self) -> Result<(), AssistantError>
+    {
+        let _subcommand = self.inputs.subcommand_matches("migrate").unwrap();
+        let _file = match _subcommand.is_present("file") {
+            true => _subcommand.value_of("file").unwrap(),
+            false => "None"
+        };
+        let _field = match _subcommand.is_present("field") {
+            true => _subcommand.value_of("field").unwrap(),
+            false => "None"
+        };
+        let _version = match _subcommand.is_present("version") {
+            true => _subcommand.value_of("version").unwrap(),
+            false => "None"
+        };
+        if _file != "None" {
+            let _output = match _subcommand.value_of("output") {
+                Some(_o) => _o.to_string(),
+                None => {
+                    let _path = std::path::Path::new(_file);
+                    let _stem = _path.file_stem().unwrap_or_default().to_string_lossy();
+                    let _name = match _path.extension() {
+                        Some(_ext) => format!("{}.migrated.{}", _stem, _ext.to_string_lossy()),
+                        None => format!("{}.migrated", _stem)
+                    };
+                    _path.with_file_name(_name).display().to_string()
+                }
+            };
+            let mut _mm = MappingMigrator::new(_version)?;
+            _mm.migrate(_file, _output.as_str(), _field)?;
+            _mm.render();
+        }
+        Ok(())
+    }
+    pub fn coverage(&self) -> Result<(), AssistantError>
+    {
+        let _subcommand = self.inputs.subcommand_matches("coverage").unwrap();
+        let _rules = match _subcommand.is_present("rules") {
+            true => _subcommand.value_of("rules").unwrap(),
+            false => "None"
+        };
+        let _field = match _subcommand.is_present("field") {
+            true => _subcommand.value_of("field").unwrap(),
+            false => "None"
+        };
+        let _version = match _subcommand.is_present("version") {
+            true => _subcommand.value_of("version").unwrap(),
+            false => "None"
+        };
+        let _wants_export = match _subcommand.is_present("export") {
+            true => _subcommand.value_of("export").unwrap(),
+            false => "None"
+        };
+        let _wants_outfile = match _subcommand.is_present("file") {
+            true => _subcommand.value_of("file").unwrap(),
+            false => "None"
+        };
+        if _wants_export == "csv" && _wants_outfile == "None" {
+            return Err(AssistantError::InvalidQuery("Exporting To CSV Requires An Output File With `-f`".to_string()));
+        }
+        let _datasources = match _subcommand.is_present("datasources") {
+            true => _subcommand.value_of("datasources").unwrap(),
+            false => "None"
+        };
+        if _datasources != "None" {
+            let _ec = EnterpriseCoverage::new(_version)?;
+            let _collected = EnterpriseCoverage::read_datasources(_datasources)?;
+            _ec.render_visibility(&_collected, _wants_export, _wants_outfile, _subcommand.is_present("gaps"))?;
+        } else if _rules != "None" {
+            let mut _ec = EnterpriseCoverage::new(_version)?;
+            _ec.load_rules(_rules, _field)?;
+            if _subcommand.is_present("prioritize") {
+                let _adversaries = match _subcommand.is_present("adversaries") {
+                    true => _subcommand.value_of("adversaries").unwrap(),
+                    false => "None"
+                };
+                _ec.render_priorities(_adversaries, _wants_export, _wants_outfile)?;
+            } else {
+                _ec.render(_wants_export, _wants_outfile, _subcommand.is_present("gaps"))?;
+            }
+        } else {
+            return Err(AssistantError::InvalidQuery("Expected Rules Or Datasources | Use: `coverage -r rules.csv` or `coverage -d datasources.txt`".to_string()));
+        }
+        Ok(())
+    }
+    pub fn layer(&self) -> Result<(), AssistantError>
+    {
+        let _subcommand = self.inputs.subcommand_matches("layer").unwrap();
+        let _input = match _subcommand.is_present("input") {
+            true => _subcommand.value_of("input").unwrap(),
+            false => "None"
+        };
+        let _min_score = match _subcommand.is_present("min_score") {
+            true => Some(_subcommand.value_of("min_score").unwrap().parse::<f64>()?),
+            false => None
+        };
+        let _wants_outfile = match _subcommand.is_present("file") {
+            true => _subcommand.value_of("file").unwrap(),
+            false => "None"
+        };
+        if _input != "None" {
+            let _layer = NavigatorLayer::load(_input)?;
+            let _ids = _layer.technique_ids(_min_score);
+            if _wants_outfile == "None" {
+                println!("{}", _ids.join(","));
+            } else {
+                let mut _writer = prettytable::csv::Writer::from_path(_wants_outfile)?;
+                _writer.write_record(["technique_id", "tactic", "score", "comment"])?;
+                for _technique in _layer.techniques.iter().filter(|_t| _ids.contains(&_t.technique_id.to_uppercase())) {
+                    _writer.write_record(&[
+                        _technique.technique_id.to_uppercase(),
+                        _technique.tactic.clone().unwrap_or_default(),
+                        _technique.score.map_or("".to_string(), |_s| _s.to_string()),
+                        _technique.comment.clone()
+                    ])?;
+                }
+                _writer.flush()?;
+                println!("\n\t[ INFO ] Techniques Saved: {} | File: {}", _ids.len(), _wants_outfile);
+            }
+        }
+        Ok(())
+    }
+    pub fn report(&self) -> Result<(), AssistantError>
+    {
+        let _subcommand = self.inputs.subcommand_matches("report").unwrap();
+        let _matrix = match _subcommand.is_present("matrix") {
+            true => _subcommand.value_of("matrix").unwrap(),
+            false => "None"
+        };
+        let _adversaries = match _subcommand.is_present("adversaries") {
+            true => _subcommand.value_of("adversaries").unwrap(),
+            false => "None"
+        };
+        let _wants_outfile = match _subcommand.is_present("file") {
+            true => _subcommand.value_of("file").unwrap(),
+            false => "None"
+        };
+        let _version = match _subcommand.is_present("version") {
+            true => _subcommand.value_of("version").unwrap(),
+            false => "None"
+        };
+        if _matrix != "None" {
+            EnterpriseMatrixSearcher::new(_matrix, _version)?.report(_adversaries, _wants_outfile)?;
+        }
+        Ok(())
+    }
+    pub fn matrix(&self) -> Result<(), AssistantError>
+    {
+        let _subcommand = self.inputs.subcommand_matches("matrix").unwrap();
+        let _version = match _subcommand.is_present("version") {
+            true => _subcommand.value_of("version").unwrap(),
+            false => "None"
+        };
+        let _width = match _subcommand.is_present("width") {
+            true => _subcommand.value_of("width").unwrap().parse::<usize>()?,
+            false => 0
+        };
+        if _subcommand.is_present("width") && _width < MIN_WIDTH {
+            return Err(AssistantError::InvalidQuery(format!("Width Too Narrow: {} | Use At Least {} Columns", _width, MIN_WIDTH)));
+        }
+        let mut _grid = EnterpriseMatrixGrid::new(_version)?;
+        if let Some(_platform) = _subcommand.value_of("platform") {
+            _grid.filter_platform(_platform)?;
+        }
+        if let Some(_adversaries) = _subcommand.value_of("adversary") {
+            _grid.filter_adversaries(_adversaries)?;
+        }
+        if let Some(_highlight) = _subcommand.value_of("highlight") {
+            _grid.highlight(EnterpriseMatrixGrid::read_highlights(_highlight)?);
+        }
+        _grid.render(_subcommand.is_present("subtechniques"), _width);
+        Ok(())
+    }
+
+    pub fn heatmap(&self) -> Result<(), AssistantError>
+    {
+        let _subcommand = self.inputs.subcommand_matches("heatmap").unwrap();
+        let _version = match _subcommand.is_present("version") {
+            true => _subcommand.value_of("version").unwrap(),
+            false => "None"
+        };
+        let _tactic = match _subcommand.is_present("tactic") {
+            true => _subcommand.value_of("tactic").unwrap(),
+            false => "None"
+        };
+        let _export = match _subcommand.is_present("export") {
+            true => _subcommand.value_of("export").unwrap(),
+            false => "None"
+        };
+        let _outfile = match _subcommand.is_present("file") {
+            true => _subcommand.value_of("file").unwrap(),
+            false => "None"
+        };
+        let _top = match _subcommand.is_present("top") {
+            true => _subcommand.value_of("top").unwrap().parse::<usize>()?,
+            false => 0
+        };
+        if _export != "None" && _export != "csv" && _export != "navigator" {
+            return Err(AssistantError::InvalidQuery(format!("Export Format Not Supported: {} | Use: csv or navigator", _export)));
+        }
+        let _heatmap = EnterpriseHeatmap::new(_version, _subcommand.is_present("subtechniques"))?;
+        _heatmap.render(_tactic, _top, _export, _outfile)?;
+        Ok(())
+    }
+    pub fn interactive(&self) -> Result<(), AssistantError>
+    {
+        let _subcommand = self.inputs.subcommand_matches("interactive").unwrap();
+        let _matrix = match _subcommand.is_present("matrix") {
+            true => _subcommand.value_of("matrix").unwrap(),
+            false => "None"
+        };
+        let _version = match _subcommand.is_present("version") {
+            true => _subcommand.value_of("version").unwrap(),
+            false => "None"
+        };
+        if _matrix != "None" {
+            InteractiveShell::new(_matrix, _version)?.run()?;
+        }
+        Ok(())
+    }
+    pub fn show(&self) -> Result<(), AssistantError>
+    {
+        let _subcommand = self.inputs.subcommand_matches("show").unwrap();
+        let _matrix = match _subcommand.is_present("matrix") {
+            true => _subcommand.value_of("matrix").unwrap(),
+            false => "enterprise"
+        };
+        let _object = match _subcommand.is_present("object") {
+            true => _subcommand.value_of("object").unwrap(),
+            false => "None"
+        };
+        let _wants_export = match _subcommand.is_present("export") {
+            true => _subcommand.value_of("export").unwrap(),
+            false => "None"
+        };
+        let _wants_outfile = match _subcommand.is_present("file") {
+            true => _subcommand.value_of("file").unwrap(),
+            false => "None"
+        };
+        let _version = match _subcommand.is_present("version") {
+            true => _subcommand.value_of("version").unwrap(),
+            false => "None"
+        };
+        if _object == "None" {
+            return Err(AssistantError::InvalidQuery("Expected An ID Or A Name | e.g., show T1003.001".to_string()));
+        }
+        EnterpriseMatrixSearcher::new(_matrix, _version)?.show(_object, _wants_export, _wants_outfile)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+//! # Mitre-Assistant
+//! The library behind the `mitre-assistant` command line, to parse the ATT&CK
+//! matrixes into a baseline and query it from your own services.
+//!
+//! ## Example
+//! ```ignore
+//! use mitre_assistant::EnterpriseQuery;
+//!
+//! let _query = EnterpriseQuery::load("None")?;    // Created with `mitre-assistant baseline -m enterprise`
+//!
+//! for _technique in _query.techniques_by_tactic("credential-access") {
+//!     println!("{} {}", _technique.tid, _technique.name);
+//! }
+//! ```
+
+
+pub mod modules;
+pub mod structs;
+pub mod utils;
+
+
+pub use modules::detail::{ DetailAttribute, DetailLink, DetailRelation, ObjectDetail };
+pub use modules::expression::{ SearchExpression, SearchFilter };
+pub use modules::fuzzy::{ FuzzyMatch, FuzzyMatcher };
+pub use modules::fulltext::{ FullTextIndex, FullTextMatch };
+pub use modules::pattern::{ PatternMatch, PatternMatcher };
+pub use modules::parser::{ EnterpriseMatrixBreakdown, EnterpriseMatrixParser };
+pub use modules::query::{ EnterpriseQuery, Technique };
+pub use modules::shell::InteractiveShell;
+pub use structs::enterprise::EnterpriseAdversary;
+pub use structs::errors::AssistantError;
+pub use structs::types::{ Datasource, Platform, StixKind, Tactic };
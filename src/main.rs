@@ -1,16 +1,5 @@
-#![allow(dead_code)]
-#![allow(unused_imports)]
-#![allow(unused_must_use)]
-#[macro_use] extern crate prettytable;
 
 
-extern crate reqwest;
-extern crate regex;
-extern crate serde_json;
-extern crate serde_derive;
-extern crate dirs;
-
-#[path = "./utils/args.rs"]
 mod args;
 use args::ArgumentsParser;
 
@@ -20,6 +9,6 @@ fn main()
     let _args = ArgumentsParser::new();
     std::process::exit( match _args.parse() {
         Ok(_) => 0,
-        Err(err) => { eprintln!("Program Error: {:?}", err); 1 }
+        Err(err) => { eprintln!("Program Error: {}", err); err.exit_code() }
     });
-}
\ No newline at end of file
+}
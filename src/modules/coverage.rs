@@ -0,0 +1,801 @@
+use std::collections::{ BTreeMap, BTreeSet };
+use std::fmt;
+
+use prettytable::{ Table, Row, Cell };
+use prettytable::csv::ReaderBuilder;
+use serde_derive::{ Deserialize, Serialize };
+
+
+use crate::modules::query::EnterpriseQuery;
+use crate::structs::enterprise::{ EnterpriseAdversary, EnterpriseMatrixStatistics };
+use crate::utils::fshandler::FileHandler;
+use crate::modules::navigator::NavigatorLayer;
+use crate::structs::errors::AssistantError;
+use crate::structs::types::Datasource;
+use crate::utils::regexes::RegexPatternManager;
+
+
+/// # Coverage Item
+/// A technique or subtechnique of the baseline and the number of rules covering it.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CoverageItem {
+    pub tid:                String,
+    pub technique:          String,
+    pub is_subtechnique:    bool,
+    pub tactics:            Vec<String>,
+    pub platforms:          Vec<String>,
+    pub datasources:        Vec<String>,
+    pub rules:              usize,
+}
+
+
+/// # Coverage Rollup
+/// The covered techniques and subtechniques of a tactic or a platform.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CoverageRollup {
+    pub scope:                  String,
+    pub name:                   String,
+    pub techniques_total:       usize,
+    pub techniques_covered:     usize,
+    pub techniques_percent:     String,
+    pub subtechniques_total:    usize,
+    pub subtechniques_covered:  usize,
+    pub subtechniques_percent:  String,
+}
+
+
+/// # Visibility Rollup
+/// The techniques and subtechniques of a tactic or a platform that are observable,
+/// partially observable or blind with the collected datasources.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct VisibilityRollup {
+    pub scope:              String,
+    pub name:               String,
+    pub total:              usize,
+    pub observable:         usize,
+    pub observable_percent: String,
+    pub partial:            usize,
+    pub partial_percent:    String,
+    pub blind:              usize,
+    pub blind_percent:      String,
+}
+
+
+/// # Visibility
+/// How much of a technique or subtechnique the collected datasources observe.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Visibility {
+    /// Every datasource of the technique is collected
+    Observable,
+    /// Some of its datasources are collected
+    Partial,
+    /// None is collected, or the matrix lists none
+    Blind,
+}
+impl Visibility {
+    pub fn as_str(&self) -> &'static str
+    {
+        match self {
+            Visibility::Observable => "observable",
+            Visibility::Partial => "partial",
+            Visibility::Blind => "blind",
+        }
+    }
+    /// The color of the Navigator layer.
+    pub fn color(&self) -> &'static str
+    {
+        match self {
+            Visibility::Observable => "#8ec843",
+            Visibility::Partial => "#ffe766",
+            Visibility::Blind => "#ff6666",
+        }
+    }
+    /// The style of the table cells.
+    pub fn style(&self) -> &'static str
+    {
+        match self {
+            Visibility::Observable => "FG",
+            Visibility::Partial => "FY",
+            Visibility::Blind => "FR",
+        }
+    }
+}
+impl fmt::Display for Visibility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+
+/// # Coverage Priority
+/// An uncovered technique or subtechnique ranked by the adversaries using it.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CoveragePriority {
+    pub rank:               usize,
+    pub tid:                String,
+    pub technique:          String,
+    pub tactics:            Vec<String>,
+    pub platforms:          Vec<String>,
+    pub count_adversaries:  usize,
+    pub adversaries:        Vec<String>,
+}
+
+
+/// # Mitre-Assistant: Coverage Module
+/// Joins the technique IDs of a CSV of detection rules against the enterprise
+/// baseline, to find the techniques and subtechniques covered by at least one rule,
+/// and the gaps, per tactic and per platform.
+///
+/// A rule covers the IDs it is tagged with, a rule tagged with a subtechnique
+/// covers the subtechnique and not its parent technique.
+///
+/// The module also assesses the visibility given by the datasources collected by
+/// the analyst - i.e., which techniques are observable, partially observable or blind.
+///
+/// The gaps can be prioritized by the adversaries using them, all of them or only
+/// those relevant to the analyst - e.g., the adversaries targeting their sector.
+///
+/// ## Example
+/// ```ignore
+/// let mut _ec = EnterpriseCoverage::new("None")?;
+///
+/// _ec.load_rules("./rules.csv", "technique_id")?;
+/// _ec.render("None", "None", false)?;
+/// ```
+pub struct EnterpriseCoverage {
+    pub items:          Vec<CoverageItem>,
+    pub datasources:    Vec<String>,
+    pub adversaries:    Vec<EnterpriseAdversary>,
+    pub unknown:        BTreeSet<String>,
+    pub count_rules:    usize
+}
+impl EnterpriseCoverage {
+    /// # Coverage - Constructor
+    /// Loads the active techniques and subtechniques of the enterprise baseline
+    /// of the `version`, use `"None"` for the baseline created without a version.
+    pub fn new(version: &str) -> Result<Self, AssistantError>
+    {
+        let _query = EnterpriseQuery::load(version)?;
+        let _items = _query.techniques().iter().map(|_t| CoverageItem {
+            tid:                _t.tid.clone(),
+            technique:          _t.name.clone(),
+            is_subtechnique:    _t.is_subtechnique,
+            tactics:            _t.tactics.iter().map(|_v| _v.to_string()).collect(),
+            platforms:          _t.platforms.iter().map(|_v| _v.to_string()).collect(),
+            datasources:        _t.datasources.iter().map(|_v| _v.to_string()).collect(),
+            rules:              0
+        }).collect();
+        Ok(EnterpriseCoverage {
+            items:          _items,
+            datasources:    _query.baseline.datasources.iter().map(|_d| _d.to_string()).collect(),
+            adversaries:    _query.adversaries().into_iter().cloned().collect(),
+            unknown:        BTreeSet::new(),
+            count_rules:    0
+        })
+    }
+    /// # Coverage - Read Technique IDs
+    /// Reads the technique IDs of every row of a CSV file, from the `field` column,
+    /// or from every column when `field` is `"None"`.  IDs are uppercased.
+    ///
+    /// A Navigator layer is read as one rule per technique of the layer.
+    pub fn read_technique_ids(fp: &str, field: &str) -> Result<Vec<Vec<String>>, AssistantError>
+    {
+        if NavigatorLayer::is_layer(fp) {
+            let _layer = NavigatorLayer::load(fp)?;
+            return Ok(_layer.technique_ids(None).into_iter().map(|_id| vec![_id]).collect());
+        }
+        let _pattern = RegexPatternManager::load_technique_ids();
+        let mut _reader = ReaderBuilder::new().flexible(true).from_path(fp)?;
+        let _column = match field {
+            "None" => None,
+            _ => match _reader.headers()?.iter().position(|_h| _h == field) {
+                Some(_c) => Some(_c),
+                None => return Err(AssistantError::InvalidQuery(format!("Column Not Found In Rules: {}", field)))
+            }
+        };
+        let mut _rows: Vec<Vec<String>> = vec![];
+        for _record in _reader.records() {
+            let _record = _record?;
+            let mut _ids: Vec<String> = vec![];
+            for (_c, _value) in _record.iter().enumerate() {
+                if _column.is_some() && _column != Some(_c) {
+                    continue;
+                }
+                _ids.extend(_pattern.find_iter(_value).map(|_m| _m.as_str().to_uppercase()));
+            }
+            _ids.sort();
+            _ids.dedup();
+            _rows.push(_ids);
+        }
+        Ok(_rows)
+    }
+    /// # Coverage - Load Rules
+    /// Counts the rules covering each technique, the IDs unknown to the baseline
+    /// are kept apart to be reported.
+    pub fn load_rules(&mut self, fp: &str, field: &str) -> Result<(), AssistantError>
+    {
+        let _rules = EnterpriseCoverage::read_technique_ids(fp, field)?;
+        let mut _counts: BTreeMap<String, usize> = BTreeMap::new();
+        for _ids in _rules.iter() {
+            for _id in _ids.iter() {
+                *_counts.entry(_id.clone()).or_insert(0) += 1;
+            }
+        }
+        for _item in self.items.iter_mut() {
+            _item.rules = _counts.remove(&_item.tid).unwrap_or(0);
+        }
+        self.unknown = _counts.into_keys().collect();
+        self.count_rules = _rules.len();
+        Ok(())
+    }
+    /// # Coverage - Rollup
+    /// Returns the overall coverage, then the coverage of each tactic and of each
+    /// platform.  An item counts as covered when `is_covered` returns `true`.
+    pub fn rollup<F>(&self, is_covered: F) -> Vec<CoverageRollup>
+        where F: Fn(&CoverageItem) -> bool
+    {
+        // Totals and covered counts of techniques, then subtechniques, of each scope
+        let mut _scopes: BTreeMap<(u8, String, String), [usize; 4]> = BTreeMap::new();
+        for _item in self.items.iter() {
+            let _covered = is_covered(_item);
+            let mut _keys: Vec<(u8, String, String)> = vec![(0, "matrix".to_string(), "enterprise".to_string())];
+            _keys.extend(_item.tactics.iter().map(|_t| (1, "tactic".to_string(), _t.clone())));
+            _keys.extend(_item.platforms.iter().map(|_p| (2, "platform".to_string(), _p.clone())));
+            for _key in _keys {
+                let _entry = _scopes.entry(_key).or_default();
+                let _offset = if _item.is_subtechnique { 2 } else { 0 };
+                _entry[_offset] += 1;
+                if _covered {
+                    _entry[_offset + 1] += 1;
+                }
+            }
+        }
+        _scopes.into_iter().map(|((_, _scope, _name), [_t, _tc, _s, _sc])| CoverageRollup {
+            scope:                  _scope,
+            name:                   _name,
+            techniques_total:       _t,
+            techniques_covered:     _tc,
+            techniques_percent:     EnterpriseMatrixStatistics::get_percentage(_t, _tc),
+            subtechniques_total:    _s,
+            subtechniques_covered:  _sc,
+            subtechniques_percent:  EnterpriseMatrixStatistics::get_percentage(_s, _sc),
+        }).collect()
+    }
+    /// # Coverage - Save Export (Private Method)
+    /// Saves a table as CSV, or any serializable results as JSON - printed to
+    /// `stdout` when there is no output file.
+    fn save_export<T: serde::Serialize>(_wants_export: &str, _wants_outfile: &str, _table: &Table, _results: &T)
+        -> Result<(), AssistantError>
+    {
+        if _wants_export == "json" {
+            let _json = serde_json::to_string_pretty(_results)?;
+            match _wants_outfile {
+                "None" => println!("{}", _json),
+                _ => FileHandler::open(_wants_outfile, "crw")?.write(&_json)?
+            }
+        } else {
+            let _fp = FileHandler::open(_wants_outfile, "crw")?;
+            _table.to_csv(_fp.handle)?;
+        }
+        Ok(())
+    }
+    /// # Coverage - Render
+    /// Prints the coverage per tactic and per platform, or the uncovered techniques
+    /// and subtechniques when `_wants_gaps` is `true`.  Both can be exported with
+    /// `_wants_export` set to `csv` or `json`.
+    pub fn render(&self, _wants_export: &str, _wants_outfile: &str, _wants_gaps: bool) -> Result<(), AssistantError>
+    {
+        if _wants_gaps {
+            return self.render_gaps(_wants_export, _wants_outfile);
+        }
+        if _wants_export == "navigator" {
+            let mut _layer = NavigatorLayer::new("Coverage", "Techniques & Subtechniques Covered By The Rules");
+            for _item in self.items.iter() {
+                let _color = if _item.rules > 0 { "#8ec843" } else { "#ff6666" };
+                _layer.add(_item.tid.as_str(), None, Some(_item.rules as f64), _color, format!("Rules: {}", _item.rules).as_str());
+            }
+            _layer.add_legend("Covered", "#8ec843");
+            _layer.add_legend("Uncovered", "#ff6666");
+            return _layer.save(_wants_outfile);
+        }
+        let _rollup = self.rollup(|_item| _item.rules > 0);
+        let mut _table = Table::new();
+        _table.add_row(Row::new(vec![
+            Cell::new("SCOPE").style_spec("FW"),
+            Cell::new("NAME").style_spec("FW"),
+            Cell::new("TECHNIQUES").style_spec("FG"),
+            Cell::new("%").style_spec("FG"),
+            Cell::new("SUBTECHNIQUES").style_spec("FG"),
+            Cell::new("%").style_spec("FG"),
+        ]));
+        for _r in _rollup.iter() {
+            _table.add_row(Row::new(vec![
+                Cell::new(_r.scope.as_str()),
+                Cell::new(_r.name.as_str()).style_spec("FW"),
+                Cell::new(format!("{} / {}", _r.techniques_covered, _r.techniques_total).as_str()),
+                Cell::new(_r.techniques_percent.as_str()).style_spec("FG"),
+                Cell::new(format!("{} / {}", _r.subtechniques_covered, _r.subtechniques_total).as_str()),
+                Cell::new(_r.subtechniques_percent.as_str()).style_spec("FG"),
+            ]));
+        }
+        if _wants_export == "csv" || _wants_export == "json" {
+            return EnterpriseCoverage::save_export(_wants_export, _wants_outfile, &_table, &_rollup);
+        }
+        println!("\n\nRules: {} | Unknown IDs In Rules: {}\n", self.count_rules, self.unknown.len());
+        _table.printstd();
+        if !self.unknown.is_empty() {
+            println!("\n\t[ WARN ] IDs Not Found In The Baseline: {} | Run `migrate` On The Rules To Update Revoked IDs", self.unknown.iter().cloned().collect::<Vec<String>>().join(", "));
+        }
+        println!("\n\n");
+        Ok(())
+    }
+    /// # Coverage - Render Gaps (Private Method)
+    fn render_gaps(&self, _wants_export: &str, _wants_outfile: &str) -> Result<(), AssistantError>
+    {
+        let _gaps: Vec<&CoverageItem> = self.items.iter().filter(|_item| _item.rules == 0).collect();
+        if _wants_export == "navigator" {
+            let mut _layer = NavigatorLayer::new("Coverage Gaps", "Techniques & Subtechniques Not Covered By The Rules");
+            for _item in _gaps.iter() {
+                _layer.add(_item.tid.as_str(), None, None, "#ff6666", "Uncovered");
+            }
+            _layer.add_legend("Uncovered", "#ff6666");
+            return _layer.save(_wants_outfile);
+        }
+        let _separator = match _wants_export {
+            "csv" => "|",
+            _ => "\n"
+        };
+        let mut _table = Table::new();
+        _table.add_row(Row::new(vec![
+            Cell::new("INDEX").style_spec("FW"),
+            Cell::new("TID").style_spec("FR"),
+            Cell::new("TECHNIQUE").style_spec("FW"),
+            Cell::new("TACTICS").style_spec("FW"),
+            Cell::new("PLATFORMS").style_spec("FW"),
+        ]));
+        for (_idx, _item) in _gaps.iter().enumerate() {
+            _table.add_row(Row::new(vec![
+                Cell::new((_idx + 1).to_string().as_str()),
+                Cell::new(_item.tid.as_str()).style_spec("FR"),
+                Cell::new(_item.technique.as_str()).style_spec("FW"),
+                Cell::new(_item.tactics.join(_separator).as_str()),
+                Cell::new(_item.platforms.join(_separator).as_str()),
+            ]));
+        }
+        if _wants_export == "csv" || _wants_export == "json" {
+            return EnterpriseCoverage::save_export(_wants_export, _wants_outfile, &_table, &_gaps);
+        }
+        println!("\n\nUncovered Techniques & Subtechniques: {}\n", _gaps.len());
+        _table.printstd();
+        println!("\n\n");
+        Ok(())
+    }
+    /// # Coverage - Read Datasources
+    /// Reads the collected datasources from a comma separated list, or from a file
+    /// with one datasource per line when `input` is the path of a file.  The names
+    /// are normalized like the baseline - e.g., `Process Monitoring` is `process-monitoring`.
+    pub fn read_datasources(input: &str) -> Result<BTreeSet<String>, AssistantError>
+    {
+        let _content = match std::path::Path::new(input).is_file() {
+            true => FileHandler::read_to_string(input)?,
+            false => input.to_string()
+        };
+        Ok(_content.split([',', '\n'])
+                   .map(|_d| Datasource::parse(_d).to_string())
+                   .filter(|_d| !_d.is_empty())
+                   .collect())
+    }
+    /// # Coverage - Visibility
+    /// Returns `Observable` when every datasource of the item is collected, `Partial`
+    /// when some are, and `Blind` when none are or the matrix lists none.
+    pub fn visibility(item: &CoverageItem, collected: &BTreeSet<String>) -> Visibility
+    {
+        let _seen = item.datasources.iter().filter(|_d| collected.contains(*_d)).count();
+        if _seen == 0 {
+            Visibility::Blind
+        } else if _seen == item.datasources.len() {
+            Visibility::Observable
+        } else {
+            Visibility::Partial
+        }
+    }
+    /// # Coverage - Visibility Rollup
+    /// Returns the overall visibility, then the visibility of each tactic and of each
+    /// platform, counting techniques and subtechniques together.
+    pub fn visibility_rollup(&self, collected: &BTreeSet<String>) -> Vec<VisibilityRollup>
+    {
+        // Totals, observable, partial and blind counts of each scope
+        let mut _scopes: BTreeMap<(u8, String, String), [usize; 4]> = BTreeMap::new();
+        for _item in self.items.iter() {
+            let _status = match EnterpriseCoverage::visibility(_item, collected) {
+                Visibility::Observable => 1,
+                Visibility::Partial => 2,
+                Visibility::Blind => 3
+            };
+            let mut _keys: Vec<(u8, String, String)> = vec![(0, "matrix".to_string(), "enterprise".to_string())];
+            _keys.extend(_item.tactics.iter().map(|_t| (1, "tactic".to_string(), _t.clone())));
+            _keys.extend(_item.platforms.iter().map(|_p| (2, "platform".to_string(), _p.clone())));
+            for _key in _keys {
+                let _entry = _scopes.entry(_key).or_default();
+                _entry[0] += 1;
+                _entry[_status] += 1;
+            }
+        }
+        _scopes.into_iter().map(|((_, _scope, _name), [_t, _o, _p, _b])| VisibilityRollup {
+            scope:              _scope,
+            name:               _name,
+            total:              _t,
+            observable:         _o,
+            observable_percent: EnterpriseMatrixStatistics::get_percentage(_t, _o),
+            partial:            _p,
+            partial_percent:    EnterpriseMatrixStatistics::get_percentage(_t, _p),
+            blind:              _b,
+            blind_percent:      EnterpriseMatrixStatistics::get_percentage(_t, _b),
+        }).collect()
+    }
+    /// # Coverage - Render Visibility
+    /// Prints the visibility per tactic and per platform, or the techniques and
+    /// subtechniques that are not fully observable when `_wants_gaps` is `true`.
+    pub fn render_visibility(&self, collected: &BTreeSet<String>,
+        _wants_export: &str, _wants_outfile: &str, _wants_gaps: bool) -> Result<(), AssistantError>
+    {
+        let _unknown: Vec<&str> = collected.iter()
+                                           .filter(|_d| !self.datasources.contains(*_d))
+                                           .map(|_d| _d.as_str())
+                                           .collect();
+        if _wants_gaps {
+            return self.render_visibility_gaps(collected, _wants_export, _wants_outfile);
+        }
+        if _wants_export == "navigator" {
+            return self.render_visibility_layer(collected, _wants_outfile, false);
+        }
+        let _rollup = self.visibility_rollup(collected);
+        let mut _table = Table::new();
+        _table.add_row(Row::new(vec![
+            Cell::new("SCOPE").style_spec("FW"),
+            Cell::new("NAME").style_spec("FW"),
+            Cell::new("TOTAL").style_spec("FW"),
+            Cell::new("OBSERVABLE").style_spec("FG"),
+            Cell::new("PARTIAL").style_spec("FY"),
+            Cell::new("BLIND").style_spec("FR"),
+        ]));
+        for _r in _rollup.iter() {
+            _table.add_row(Row::new(vec![
+                Cell::new(_r.scope.as_str()),
+                Cell::new(_r.name.as_str()).style_spec("FW"),
+                Cell::new(_r.total.to_string().as_str()),
+                Cell::new(format!("{} ({})", _r.observable, _r.observable_percent).as_str()).style_spec("FG"),
+                Cell::new(format!("{} ({})", _r.partial, _r.partial_percent).as_str()).style_spec("FY"),
+                Cell::new(format!("{} ({})", _r.blind, _r.blind_percent).as_str()).style_spec("FR"),
+            ]));
+        }
+        if _wants_export == "csv" || _wants_export == "json" {
+            return EnterpriseCoverage::save_export(_wants_export, _wants_outfile, &_table, &_rollup);
+        }
+        println!("\n\nCollected Datasources: {} | Unknown Datasources: {}\n", collected.len(), _unknown.len());
+        _table.printstd();
+        if !_unknown.is_empty() {
+            println!("\n\t[ WARN ] Datasources Not Found In The Baseline: {} | List Them With: `search -m enterprise -t datasources`", _unknown.join(", "));
+        }
+        println!("\n\n");
+        Ok(())
+    }
+    /// # Coverage - Render Visibility Gaps (Private Method)
+    fn render_visibility_gaps(&self, collected: &BTreeSet<String>,
+        _wants_export: &str, _wants_outfile: &str) -> Result<(), AssistantError>
+    {
+        if _wants_export == "navigator" {
+            return self.render_visibility_layer(collected, _wants_outfile, true);
+        }
+        let _separator = match _wants_export {
+            "csv" => "|",
+            _ => "\n"
+        };
+        let mut _gaps: Vec<serde_json::Value> = vec![];
+        let mut _table = Table::new();
+        _table.add_row(Row::new(vec![
+            Cell::new("INDEX").style_spec("FW"),
+            Cell::new("VISIBILITY").style_spec("FW"),
+            Cell::new("TID").style_spec("FW"),
+            Cell::new("TECHNIQUE").style_spec("FW"),
+            Cell::new("TACTICS").style_spec("FW"),
+            Cell::new("MISSING DATASOURCES").style_spec("FR"),
+        ]));
+        for _item in self.items.iter() {
+            let _status = EnterpriseCoverage::visibility(_item, collected);
+            if _status == Visibility::Observable {
+                continue;
+            }
+            let _missing: Vec<&str> = _item.datasources.iter()
+                                                       .filter(|_d| !collected.contains(*_d))
+                                                       .map(|_d| _d.as_str())
+                                                       .collect();
+            let _style = _status.style();
+            _table.add_row(Row::new(vec![
+                Cell::new((_gaps.len() + 1).to_string().as_str()),
+                Cell::new(_status.as_str()).style_spec(_style),
+                Cell::new(_item.tid.as_str()).style_spec(_style),
+                Cell::new(_item.technique.as_str()).style_spec("FW"),
+                Cell::new(_item.tactics.join(_separator).as_str()),
+                Cell::new(_missing.join(_separator).as_str()),
+            ]));
+            _gaps.push(serde_json::json!({
+                "tid":          _item.tid,
+                "technique":    _item.technique,
+                "visibility":   _status,
+                "tactics":      _item.tactics,
+                "platforms":    _item.platforms,
+                "missing":      _missing
+            }));
+        }
+        if _wants_export == "csv" || _wants_export == "json" {
+            return EnterpriseCoverage::save_export(_wants_export, _wants_outfile, &_table, &_gaps);
+        }
+        println!("\n\nTechniques & Subtechniques Not Fully Observable: {}\n", _gaps.len());
+        _table.printstd();
+        println!("\n\n");
+        Ok(())
+    }
+    /// # Coverage - Select Adversaries
+    /// Returns the adversaries matching a comma separated list of names, aliases or
+    /// group IDs - e.g., `APT29, fin7, G0016` - or every adversary when `names` is `"None"`.
+    pub fn select_adversaries(&self, names: &str) -> Result<Vec<&EnterpriseAdversary>, AssistantError>
+    {
+        if names == "None" {
+            return Ok(self.adversaries.iter().collect());
+        }
+        let mut _selected: Vec<&EnterpriseAdversary> = vec![];
+        let mut _unknown: Vec<&str> = vec![];
+        for _name in names.split(',').filter(|_n| !_n.trim().is_empty()) {
+            let _matches: Vec<&EnterpriseAdversary> = self.adversaries.iter().filter(|_a| _a.is_named(_name)).collect();
+            if _matches.is_empty() {
+                _unknown.push(_name.trim());
+            }
+            for _adversary in _matches {
+                if !_selected.iter().any(|_s| _s.id == _adversary.id) {
+                    _selected.push(_adversary);
+                }
+            }
+        }
+        if !_unknown.is_empty() {
+            println!("\n\t[ WARN ] Adversaries Not Found In The Baseline: {} | List Them With: `search -m enterprise -t adversaries`", _unknown.join(", "));
+        }
+        if _selected.is_empty() {
+            return Err(AssistantError::InvalidQuery("No Adversary Selected | Use Names, Aliases Or Group IDs".to_string()));
+        }
+        Ok(_selected)
+    }
+    /// # Coverage - Prioritize
+    /// Ranks the uncovered techniques and subtechniques by the number of `adversaries`
+    /// using them.  The items used by none of the adversaries are left out.
+    pub fn prioritize(&self, adversaries: &[&EnterpriseAdversary]) -> Vec<CoveragePriority>
+    {
+        let mut _priorities: Vec<CoveragePriority> = vec![];
+        for _item in self.items.iter().filter(|_item| _item.rules == 0) {
+            let _users: Vec<String> = adversaries.iter().filter(|_a| _a.uses(_item.tid.as_str())).map(|_a| _a.name.clone()).collect();
+            if _users.is_empty() {
+                continue;
+            }
+            _priorities.push(CoveragePriority {
+                rank:               0,
+                tid:                _item.tid.clone(),
+                technique:          _item.technique.clone(),
+                tactics:            _item.tactics.clone(),
+                platforms:          _item.platforms.clone(),
+                count_adversaries:  _users.len(),
+                adversaries:        _users,
+            });
+        }
+        _priorities.sort_by(|_a, _b| _b.count_adversaries.cmp(&_a.count_adversaries).then(_a.tid.cmp(&_b.tid)));
+        for (_idx, _p) in _priorities.iter_mut().enumerate() {
+            _p.rank = _idx + 1;
+        }
+        _priorities
+    }
+    /// # Coverage - Render Priorities
+    /// Prints the uncovered techniques and subtechniques ranked by the adversaries
+    /// selected with `_wants_adversaries`, or by all the adversaries with `"None"`.
+    pub fn render_priorities(&self, _wants_adversaries: &str,
+        _wants_export: &str, _wants_outfile: &str) -> Result<(), AssistantError>
+    {
+        let _selected = self.select_adversaries(_wants_adversaries)?;
+        let _priorities = self.prioritize(&_selected);
+        if _wants_export == "navigator" {
+            let mut _layer = NavigatorLayer::new("Coverage Priorities", "Uncovered Techniques & Subtechniques Scored By The Adversaries Using Them");
+            for _p in _priorities.iter() {
+                _layer.add(_p.tid.as_str(), None, Some(_p.count_adversaries as f64), "",
+                    format!("Rank: {} | Used By: {}", _p.rank, _p.adversaries.join(", ")).as_str());
+            }
+            // The most used gaps are the most urgent, so they are red
+            _layer.add_gradient(0.0, _priorities.first().map_or(1.0, |_p| _p.count_adversaries as f64));
+            if let Some(_gradient) = _layer.gradient.as_mut() {
+                _gradient.colors.reverse();
+            }
+            return _layer.save(_wants_outfile);
+        }
+        let _separator = match _wants_export {
+            "csv" => "|",
+            _ => "\n"
+        };
+        let mut _table = Table::new();
+        _table.add_row(Row::new(vec![
+            Cell::new("RANK").style_spec("FW"),
+            Cell::new("TID").style_spec("FR"),
+            Cell::new("TECHNIQUE").style_spec("FW"),
+            Cell::new("TACTICS").style_spec("FW"),
+            Cell::new("ADVERSARIES").style_spec("FY"),
+            Cell::new("USED BY").style_spec("FW"),
+        ]));
+        for _p in _priorities.iter() {
+            // Listing every adversary of a popular technique floods the terminal
+            let _users = match _wants_export == "csv" || _p.adversaries.len() <= 5 {
+                true => _p.adversaries.join(_separator),
+                false => format!("{}{}(+{} more)", _p.adversaries[..5].join(_separator), _separator, _p.adversaries.len() - 5)
+            };
+            _table.add_row(Row::new(vec![
+                Cell::new(_p.rank.to_string().as_str()),
+                Cell::new(_p.tid.as_str()).style_spec("FR"),
+                Cell::new(_p.technique.as_str()).style_spec("FW"),
+                Cell::new(_p.tactics.join(_separator).as_str()),
+                Cell::new(_p.count_adversaries.to_string().as_str()).style_spec("FY"),
+                Cell::new(_users.as_str()),
+            ]));
+        }
+        if _wants_export == "csv" || _wants_export == "json" {
+            return EnterpriseCoverage::save_export(_wants_export, _wants_outfile, &_table, &_priorities);
+        }
+        println!("\n\nRules: {} | Adversaries: {} | Uncovered Techniques & Subtechniques Used By Them: {}\n",
+            self.count_rules, _selected.len(), _priorities.len());
+        _table.printstd();
+        println!("\n\n");
+        Ok(())
+    }
+    /// # Coverage - Render Visibility Layer (Private Method)
+    /// Colors the observable techniques green, the partial yellow and the blind red,
+    /// leaving out the observable ones when `_only_gaps` is `true`.
+    fn render_visibility_layer(&self, collected: &BTreeSet<String>,
+        _wants_outfile: &str, _only_gaps: bool) -> Result<(), AssistantError>
+    {
+        let mut _layer = NavigatorLayer::new("Datasource Visibility", "Techniques & Subtechniques Observable With The Collected Datasources");
+        for _item in self.items.iter() {
+            let _status = EnterpriseCoverage::visibility(_item, collected);
+            if _only_gaps && _status == Visibility::Observable {
+                continue;
+            }
+            let _missing: Vec<&str> = _item.datasources.iter()
+                                                       .filter(|_d| !collected.contains(*_d))
+                                                       .map(|_d| _d.as_str())
+                                                       .collect();
+            let _comment = match _missing.is_empty() {
+                true => _status.to_string(),
+                false => format!("{} | Missing: {}", _status, _missing.join(", "))
+            };
+            _layer.add(_item.tid.as_str(), None, None, _status.color(), _comment.as_str());
+        }
+        _layer.add_legend("Observable", Visibility::Observable.color());
+        _layer.add_legend("Partial", Visibility::Partial.color());
+        _layer.add_legend("Blind", Visibility::Blind.color());
+        _layer.save(_wants_outfile)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(tid: &str, tactics: &[&str], datasources: &[&str]) -> CoverageItem
+    {
+        let _strings = |_v: &[&str]| _v.iter().map(|_s| _s.to_string()).collect::<Vec<String>>();
+        CoverageItem {
+            tid:                tid.to_string(),
+            technique:          format!("Technique {}", tid),
+            is_subtechnique:    tid.contains('.'),
+            tactics:            _strings(tactics),
+            platforms:          _strings(&["windows"]),
+            datasources:        _strings(datasources),
+            rules:              0
+        }
+    }
+
+    fn coverage() -> EnterpriseCoverage
+    {
+        EnterpriseCoverage {
+            items:          vec![
+                item("T1003", &["credential-access"], &["process-monitoring", "api-monitoring"]),
+                item("T1003.001", &["credential-access"], &["process-monitoring"]),
+                item("T1059", &["execution"], &[]),
+            ],
+            datasources:    vec!["api-monitoring".to_string(), "process-monitoring".to_string()],
+            adversaries:    vec![],
+            unknown:        BTreeSet::new(),
+            count_rules:    0
+        }
+    }
+
+    fn rules(name: &str, content: &str) -> String
+    {
+        let _fp = std::env::temp_dir().join(format!("mitre-assistant-rules-{}-{}.csv", name, std::process::id()));
+        std::fs::write(&_fp, content).unwrap();
+        _fp.display().to_string()
+    }
+
+    #[test]
+    fn read_technique_ids_maps_each_rule_to_its_ids()
+    {
+        let _fp = rules("ids", "name,technique_id,notes\nlsass,t1003.001,see T1059\ndump,\"T1003, T1003\",none\nempty,,\n");
+        let _all = EnterpriseCoverage::read_technique_ids(_fp.as_str(), "None");
+        let _column = EnterpriseCoverage::read_technique_ids(_fp.as_str(), "technique_id");
+        let _missing = EnterpriseCoverage::read_technique_ids(_fp.as_str(), "tid");
+        std::fs::remove_file(&_fp).unwrap();
+        assert_eq!(_all.unwrap(), vec![vec!["T1003.001", "T1059"], vec!["T1003"], vec![]]);
+        assert_eq!(_column.unwrap(), vec![vec!["T1003.001"], vec!["T1003"], vec![]]);
+        assert!(matches!(_missing, Err(AssistantError::InvalidQuery(_))));
+    }
+
+    #[test]
+    fn load_rules_counts_rules_and_keeps_unknown_ids()
+    {
+        let _fp = rules("load", "technique_id\nT1003.001\nT1003.001\nT1099\n");
+        let mut _ec = coverage();
+        let _loaded = _ec.load_rules(_fp.as_str(), "technique_id");
+        std::fs::remove_file(&_fp).unwrap();
+        _loaded.unwrap();
+        assert_eq!(_ec.count_rules, 3);
+        assert_eq!(_ec.items.iter().map(|_i| _i.rules).collect::<Vec<usize>>(), vec![0, 2, 0]);
+        assert_eq!(_ec.unknown.iter().cloned().collect::<Vec<String>>(), vec!["T1099"]);
+        let _rollup = _ec.rollup(|_i| _i.rules > 0);
+        let _matrix = &_rollup[0];
+        assert_eq!((_matrix.scope.as_str(), _matrix.techniques_covered, _matrix.techniques_total), ("matrix", 0, 2));
+        assert_eq!((_matrix.subtechniques_covered, _matrix.subtechniques_total), (1, 1));
+    }
+
+    #[test]
+    fn visibility_depends_on_the_collected_datasources()
+    {
+        let _ec = coverage();
+        let _collected = EnterpriseCoverage::read_datasources("Process Monitoring, ,Unknown Source").unwrap();
+        assert_eq!(_collected.iter().cloned().collect::<Vec<String>>(), vec!["process-monitoring", "unknown-source"]);
+        let _visibility: Vec<Visibility> = _ec.items.iter().map(|_i| EnterpriseCoverage::visibility(_i, &_collected)).collect();
+        assert_eq!(_visibility, vec![Visibility::Partial, Visibility::Observable, Visibility::Blind]);
+        assert_eq!(serde_json::to_string(&Visibility::Partial).unwrap(), "\"partial\"");
+        let _rollup = _ec.visibility_rollup(&_collected);
+        let _matrix = &_rollup[0];
+        assert_eq!((_matrix.total, _matrix.observable, _matrix.partial, _matrix.blind), (3, 1, 1, 1));
+        let _execution = _rollup.iter().find(|_r| _r.name == "execution").unwrap();
+        assert_eq!((_execution.total, _execution.blind), (1, 1));
+    }
+
+    fn adversary(name: &str, aliases: &[&str], techniques: &[&str]) -> EnterpriseAdversary
+    {
+        let mut _adversary = EnterpriseAdversary::new();
+        _adversary.id = format!("intrusion-set--{}", name);
+        _adversary.name = name.to_string();
+        _adversary.aliases = aliases.iter().map(|_a| _a.to_string()).collect();
+        _adversary.profile.techniques.items = techniques.iter().map(|_t| _t.to_string()).collect();
+        _adversary
+    }
+
+    #[test]
+    fn prioritize_ranks_gaps_by_their_adversaries()
+    {
+        let mut _ec = coverage();
+        _ec.items[1].rules = 1;
+        _ec.adversaries = vec![
+            adversary("APT29", &["cozy-bear"], &["T1003", "T1059", "T1003.001"]),
+            adversary("FIN7", &[], &["T1059"]),
+        ];
+        let _all = _ec.select_adversaries("None").unwrap();
+        let _priorities = _ec.prioritize(&_all);
+        let _ranked: Vec<(usize, &str, usize)> = _priorities.iter().map(|_p| (_p.rank, _p.tid.as_str(), _p.count_adversaries)).collect();
+        assert_eq!(_ranked, vec![(1, "T1059", 2), (2, "T1003", 1)]);
+        assert_eq!(_priorities[0].adversaries, vec!["APT29", "FIN7"]);
+        let _selected = _ec.select_adversaries("Cozy Bear, unknown").unwrap();
+        assert_eq!(_selected.len(), 1);
+        let _ranked: Vec<String> = _ec.prioritize(&_selected).into_iter().map(|_p| _p.tid).collect();
+        assert_eq!(_ranked, vec!["T1003", "T1059"]);
+        assert!(matches!(_ec.select_adversaries("nobody"), Err(AssistantError::InvalidQuery(_))));
+    }
+}
@@ -0,0 +1,743 @@
+use serde_derive::{ Deserialize, Serialize };
+
+use std::collections::HashMap;
+
+
+use crate::modules::fuzzy::FuzzyMatcher;
+use crate::modules::parser::EnterpriseMatrixBreakdown;
+use crate::structs::enterprise::{ EnterpriseReference, EnterpriseTechnique };
+use crate::structs::errors::AssistantError;
+use crate::structs::types::{ join, Datasource, Tactic };
+
+
+/// The characters of a line of a description, in the text view.
+const WIDTH: usize = 80;
+/// The score a name with a typo must lead the next name by, to be the one shown.
+const MARGIN: usize = 10;
+
+
+/// # Detail Attribute
+/// A stored attribute of an object - e.g., its `platforms`.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct DetailAttribute {
+    pub field:          String,
+    pub value:          String,
+}
+
+
+/// # Detail Link
+/// An object related to the object shown.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct DetailLink {
+    pub kind:           String,
+    pub id:             String,
+    pub name:           String,
+}
+
+
+/// # Detail Relation
+/// The objects of one relationship type - e.g., the adversaries of `used-by`.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct DetailRelation {
+    pub relationship:   String,
+    pub objects:        Vec<DetailLink>,
+}
+
+
+/// # Mitre-Assistant: Detail Module
+/// Everything a baseline stores about one technique, subtechnique, tactic, adversary,
+/// malware, tool, mitigation or datasource: its attributes, its description, its
+/// parent and children, its related objects by relationship type and its references.
+///
+/// The object is found by its ATT&CK ID, its name or an alias, a name with a typo
+/// is matched when a single object is the closest.  A baseline written by an earlier
+/// release has no tactics, references or mitigated techniques.
+///
+/// ## Example
+/// ```ignore
+/// let _detail = ObjectDetail::find(&_json, "T1003.001")?;
+/// println!("{}", _detail.to_text());
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct ObjectDetail {
+    pub kind:           String,
+    pub id:             String,
+    pub name:           String,
+    pub attributes:     Vec<DetailAttribute>,
+    pub description:    String,
+    /// The `x_mitre_detection` of a technique, `none` otherwise
+    pub detection:      String,
+    pub parent:         Option<DetailLink>,
+    pub children:       Vec<DetailLink>,
+    pub relationships:  Vec<DetailRelation>,
+    pub references:     Vec<EnterpriseReference>,
+}
+impl ObjectDetail {
+    /// # Detail - Find
+    /// Returns the detail of the object of an ID or a name, an `InvalidQuery` when
+    /// no object or many objects are named so.
+    pub fn find(baseline: &EnterpriseMatrixBreakdown, term: &str) -> Result<Self, AssistantError>
+    {
+        let _json = baseline;
+        let _term = term.trim();
+        if _term.is_empty() {
+            return Err(AssistantError::InvalidQuery("Expected An ID Or A Name | e.g., T1003, TA0006, G0016, S0002, M1026".to_string()));
+        }
+        let (_kind, _id) = match ObjectDetail::locate(_json, _term) {
+            Some(_found) => _found,
+            None => ObjectDetail::closest(_json, _term)?
+        };
+        let mut _detail = ObjectDetail {
+            kind:           _kind.clone(),
+            id:             _id.clone(),
+            name:           "none".to_string(),
+            attributes:     vec![],
+            description:    "none".to_string(),
+            detection:      "none".to_string(),
+            parent:         None,
+            children:       vec![],
+            relationships:  vec![],
+            references:     _json.references.iter().filter(|_r| _r.id == _id).cloned().collect(),
+        };
+        if let Some(_text) = _json.descriptions.iter().find(|_d| _d.kind == _kind && _d.id == _id) {
+            _detail.description = _text.description.clone();
+            _detail.detection = _text.detection.clone();
+        }
+        let _names = Names::new(_json);
+        match _kind.as_str() {
+            "technique" | "subtechnique" => _detail.technique(_json, &_names),
+            "revoked" | "deprecated" => _detail.inactive(_json, &_names),
+            "tactic" => _detail.tactic(_json, &_names),
+            "adversary" => _detail.adversary(_json, &_names),
+            "malware" | "tool" => _detail.software(_json, &_names),
+            "mitigation" => _detail.mitigation(_json, &_names),
+            _ => _detail.datasource(_json, &_names)
+        }
+        Ok(_detail)
+    }
+    /// # Detail - To Text
+    /// Returns the detail as text for the terminal, the descriptions wrapped at
+    /// `WIDTH` characters.
+    pub fn to_text(&self) -> String
+    {
+        let mut _text = format!("\n[ {} ] {} - {}\n\n", self.kind, self.id, self.name);
+        for _attribute in self.attributes.iter() {
+            _text.push_str(format!("    {:<20}{}\n", _attribute.field, _attribute.value).as_str());
+        }
+        for (_title, _value) in [("Description", &self.description), ("Detection", &self.detection)].iter() {
+            if _value.as_str() != "none" {
+                _text.push_str(format!("\n  {}\n", _title).as_str());
+                for _line in wrap(_value, WIDTH) {
+                    _text.push_str(format!("    {}\n", _line).as_str());
+                }
+            }
+        }
+        if let Some(_parent) = &self.parent {
+            _text.push_str("\n  Parent\n");
+            _text.push_str(format!("    {:<14}{:<12}{}\n", _parent.kind, _parent.id, _parent.name).as_str());
+        }
+        if !self.children.is_empty() {
+            _text.push_str(format!("\n  Children ({})\n", self.children.len()).as_str());
+            for _child in self.children.iter() {
+                _text.push_str(format!("    {:<14}{:<12}{}\n", _child.kind, _child.id, _child.name).as_str());
+            }
+        }
+        for _relation in self.relationships.iter() {
+            _text.push_str(format!("\n  Relationship: {} ({})\n", _relation.relationship, _relation.objects.len()).as_str());
+            for _link in _relation.objects.iter() {
+                _text.push_str(format!("    {:<14}{:<12}{}\n", _link.kind, _link.id, _link.name).as_str());
+            }
+        }
+        if !self.references.is_empty() {
+            _text.push_str(format!("\n  References ({})\n", self.references.len()).as_str());
+            for (_idx, _reference) in self.references.iter().enumerate() {
+                _text.push_str(format!("    [{}] {}", _idx + 1, _reference.source).as_str());
+                for _value in [&_reference.external_id, &_reference.url, &_reference.description].iter() {
+                    if _value.as_str() != "none" {
+                        _text.push_str(format!(" | {}", _value).as_str());
+                    }
+                }
+                _text.push('\n');
+            }
+        }
+        _text.push('\n');
+        _text
+    }
+    /// # Detail - To Markdown
+    /// Returns the detail as a Markdown section, for wiki pages and tickets.
+    pub fn to_markdown(&self) -> String
+    {
+        let _escape = |_text: &str| _text.trim().replace("|", "\\|").replace("\n", "<br>");
+        let _item = |_link: &DetailLink| format!("- `{}` {} ({})\n", _link.id, _escape(_link.name.as_str()), _link.kind);
+        let mut _md = format!("## {}: {}\n\n| FIELD | VALUE |\n|---|---|\n| kind | {} |\n", self.id, _escape(self.name.as_str()), self.kind);
+        for _attribute in self.attributes.iter() {
+            _md.push_str(format!("| {} | {} |\n", _attribute.field, _escape(_attribute.value.as_str())).as_str());
+        }
+        for (_title, _value) in [("Description", &self.description), ("Detection", &self.detection)].iter() {
+            if _value.as_str() != "none" {
+                _md.push_str(format!("\n### {}\n\n{}\n", _title, _value).as_str());
+            }
+        }
+        if let Some(_parent) = &self.parent {
+            _md.push_str(format!("\n### Parent\n\n{}", _item(_parent)).as_str());
+        }
+        if !self.children.is_empty() {
+            _md.push_str("\n### Children\n\n");
+            for _child in self.children.iter() {
+                _md.push_str(_item(_child).as_str());
+            }
+        }
+        for _relation in self.relationships.iter() {
+            _md.push_str(format!("\n### Relationship: {}\n\n", _relation.relationship).as_str());
+            for _link in _relation.objects.iter() {
+                _md.push_str(_item(_link).as_str());
+            }
+        }
+        if !self.references.is_empty() {
+            _md.push_str("\n### References\n\n");
+            for (_idx, _reference) in self.references.iter().enumerate() {
+                let _source = match _reference.url.as_str() {
+                    "none" => _reference.source.clone(),
+                    _url => format!("[{}]({})", _reference.source, _url)
+                };
+                _md.push_str(format!("{}. {}", _idx + 1, _source).as_str());
+                for _value in [&_reference.external_id, &_reference.description].iter() {
+                    if _value.as_str() != "none" {
+                        _md.push_str(format!(" - {}", _value).as_str());
+                    }
+                }
+                _md.push('\n');
+            }
+        }
+        _md.push('\n');
+        _md
+    }
+    /// # Detail - Locate (Private Method)
+    /// Returns the kind and the ID of the object of an ID, a name or an alias.
+    fn locate(baseline: &EnterpriseMatrixBreakdown, term: &str) -> Option<(String, String)>
+    {
+        let _json = baseline;
+        let _wanted = term.to_lowercase();
+        let _is = |_value: &str| _value.to_lowercase() == _wanted;
+        let _found = |_kind: &str, _id: &str| Some((_kind.to_string(), _id.to_string()));
+        // By ID
+        for (_kind, _rows) in [("technique", &_json.breakdown_techniques.platforms),
+                               ("subtechnique", &_json.breakdown_subtechniques.platforms)].iter() {
+            if let Some(_row) = _rows.iter().find(|_r| _is(_r.tid.as_str())) {
+                return _found(_kind, _row.tid.as_str());
+            }
+        }
+        for (_kind, _inactive) in [("revoked", &_json.revoked_techniques), ("deprecated", &_json.deprecated_techniques)].iter() {
+            if let Some((_tid, _)) = _inactive.iter().find(|(_tid, _)| _is(_tid.as_str())) {
+                return _found(_kind, _tid.as_str());
+            }
+        }
+        if let Some(_tactic) = _json.breakdown_tactics.iter().find(|_t| _is(_t.tactic_id.as_str())) {
+            return _found("tactic", _tactic.tactic_id.as_str());
+        }
+        if let Some(_adversary) = _json.breakdown_adversaries.iter().find(|_a| _is(_a.group_id.as_str())) {
+            return _found("adversary", _adversary.group_id.as_str());
+        }
+        if let Some(_malware) = _json.breakdown_malware.iter().find(|_m| _is(_m.malware_id.as_str())) {
+            return _found("malware", _malware.malware_id.as_str());
+        }
+        if let Some(_tool) = _json.breakdown_tools.iter().find(|_t| _is(_t.tool_id.as_str())) {
+            return _found("tool", _tool.tool_id.as_str());
+        }
+        if let Some(_mitigation) = _json.breakdown_mitigations.iter().find(|_m| _is(_m.mitigation_id.as_str())) {
+            return _found("mitigation", _mitigation.mitigation_id.as_str());
+        }
+        // By name or alias, the active objects first
+        for (_kind, _rows) in [("technique", &_json.breakdown_techniques.platforms),
+                               ("subtechnique", &_json.breakdown_subtechniques.platforms)].iter() {
+            if let Some(_row) = _rows.iter().find(|_r| _is(_r.technique.as_str())) {
+                return _found(_kind, _row.tid.as_str());
+            }
+        }
+        let _tactic = Tactic::parse(term);
+        if let Some(_detail) = _json.breakdown_tactics.iter().find(|_t| _is(_t.name.as_str()) || _t.shortname == _tactic.as_str()) {
+            return _found("tactic", _detail.tactic_id.as_str());
+        }
+        // A baseline without the tactic objects still has the tactics of its techniques
+        if _json.tactics.contains(&_tactic) {
+            return _found("tactic", _tactic.as_str());
+        }
+        if let Some(_adversary) = _json.breakdown_adversaries.iter().find(|_a| !_a.is_revoked && _a.is_named(term)) {
+            return _found("adversary", _adversary.group_id.as_str());
+        }
+        if let Some(_malware) = _json.breakdown_malware.iter().find(|_m| !_m.is_revoked && _m.is_named(term)) {
+            return _found("malware", _malware.malware_id.as_str());
+        }
+        if let Some(_tool) = _json.breakdown_tools.iter().find(|_t| !_t.is_revoked && _t.is_named(term)) {
+            return _found("tool", _tool.tool_id.as_str());
+        }
+        if let Some(_mitigation) = _json.breakdown_mitigations.iter().find(|_m| !_m.is_deprecated && _is(_m.name.as_str())) {
+            return _found("mitigation", _mitigation.mitigation_id.as_str());
+        }
+        let _datasource = Datasource::parse(term);
+        if _json.datasources.contains(&_datasource) {
+            return _found("datasource", _datasource.as_str());
+        }
+        for (_kind, _inactive) in [("revoked", &_json.revoked_techniques), ("deprecated", &_json.deprecated_techniques)].iter() {
+            if let Some((_tid, _)) = _inactive.iter().find(|(_, _name)| _is(_name.as_str())) {
+                return _found(_kind, _tid.as_str());
+            }
+        }
+        None
+    }
+    /// # Detail - Closest (Private Method)
+    /// Returns the kind and the ID of the closest name of a term with a typo, when
+    /// it leads the next name by `MARGIN`, otherwise the names it could be.
+    fn closest(baseline: &EnterpriseMatrixBreakdown, term: &str) -> Result<(String, String), AssistantError>
+    {
+        let _matcher = FuzzyMatcher::new(baseline);
+        let _matches = _matcher.search(term);
+        match _matches.as_slice() {
+            [] => {
+                let _suggestions = _matcher.suggest(term, 5);
+                match _suggestions.is_empty() {
+                    true => Err(AssistantError::InvalidQuery(format!("Object Not Found: {} | Use: A Technique, Tactic, Group, Software, Mitigation Or Datasource ID Or Name", term))),
+                    false => Err(AssistantError::InvalidQuery(format!("Object Not Found: {} | Did You Mean: {}", term, _suggestions.join(", "))))
+                }
+            },
+            [_best] => Ok((_best.kind.clone(), _best.id.clone())),
+            [_best, _next, ..] if _best.score >= _next.score + MARGIN => Ok((_best.kind.clone(), _best.id.clone())),
+            _ => {
+                let _names: Vec<String> = _matches.iter()
+                    .take_while(|_m| _m.score + MARGIN > _matches[0].score)
+                    .take(5)
+                    .map(|_m| format!("{} {}", _m.id, _m.name))
+                    .collect();
+                Err(AssistantError::InvalidQuery(format!("Many Objects Are Named: {} | Did You Mean: {}", term, _names.join(", "))))
+            }
+        }
+    }
+    /// # Detail - Technique (Private Method)
+    fn technique(&mut self, baseline: &EnterpriseMatrixBreakdown, names: &Names)
+    {
+        let _json = baseline;
+        let _rows: Vec<&EnterpriseTechnique> = _json.breakdown_techniques.platforms.iter()
+            .chain(_json.breakdown_subtechniques.platforms.iter())
+            .filter(|_r| _r.tid == self.id)
+            .collect();
+        self.name = _rows[0].technique.clone();
+        let mut _tactics: Vec<String> = vec![];
+        let mut _platforms: Vec<String> = vec![];
+        let mut _datasources: Vec<String> = vec![];
+        for _row in _rows.iter() {
+            _tactics.push(_row.tactic.to_string());
+            _platforms.extend(_row.platform.iter().map(|_p| _p.to_string()));
+            _datasources.extend(_row.datasources.iter().map(|_d| _d.to_string()));
+        }
+        self.attribute("status", "active");
+        self.attribute("tactics", unique(_tactics).as_str());
+        self.attribute("platforms", unique(_platforms).as_str());
+        self.attribute("datasources", unique(_datasources).as_str());
+        if self.kind == "subtechnique" {
+            let _parent = self.id.split('.').next().unwrap_or("");
+            self.parent = Some(names.link("technique", _parent));
+        } else {
+            self.children = _rows[0].subtechniques.iter().map(|_s| names.link("subtechnique", _s)).collect();
+        }
+        let _tid = self.id.clone();
+        let mut _used_by: Vec<DetailLink> = _json.breakdown_adversaries.iter()
+            .filter(|_a| !_a.is_revoked && _a.uses(_tid.as_str()))
+            .map(|_a| names.link("adversary", _a.group_id.as_str()))
+            .collect();
+        _used_by.extend(_json.breakdown_malware.iter()
+            .filter(|_m| !_m.is_revoked && _m.uses(_tid.as_str()))
+            .map(|_m| names.link("malware", _m.malware_id.as_str())));
+        _used_by.extend(_json.breakdown_tools.iter()
+            .filter(|_t| !_t.is_revoked && _t.uses(_tid.as_str()))
+            .map(|_t| names.link("tool", _t.tool_id.as_str())));
+        self.relation("used-by", _used_by);
+        self.relation("mitigated-by", _json.breakdown_mitigations.iter()
+            .filter(|_m| !_m.is_deprecated && (_m.profile.techniques.items.contains(&_tid) || _m.profile.subtechniques.items.contains(&_tid)))
+            .map(|_m| names.link("mitigation", _m.mitigation_id.as_str()))
+            .collect());
+        self.relation("revokes", _json.revoked_by.iter()
+            .filter(|(_, _new)| *_new == _tid)
+            .map(|(_old, _)| names.link("revoked", _old.as_str()))
+            .collect());
+    }
+    /// # Detail - Inactive (Private Method)
+    /// A revoked or deprecated technique, only its name and its replacement are kept.
+    fn inactive(&mut self, baseline: &EnterpriseMatrixBreakdown, names: &Names)
+    {
+        let _json = baseline;
+        self.name = names.name(self.id.as_str());
+        let _status = self.kind.clone();
+        self.attribute("status", _status.as_str());
+        self.kind = match self.id.contains('.') {
+            true => "subtechnique".to_string(),
+            false => "technique".to_string()
+        };
+        let _tid = self.id.clone();
+        self.relation("revoked-by", _json.revoked_by.iter()
+            .filter(|(_old, _)| *_old == _tid)
+            .map(|(_, _new)| names.link(if _new.contains('.') { "subtechnique" } else { "technique" }, _new.as_str()))
+            .collect());
+    }
+    /// # Detail - Tactic (Private Method)
+    fn tactic(&mut self, baseline: &EnterpriseMatrixBreakdown, names: &Names)
+    {
+        let _json = baseline;
+        let _shortname = match _json.breakdown_tactics.iter().find(|_t| _t.tactic_id == self.id) {
+            Some(_detail) => {
+                self.name = _detail.name.clone();
+                _detail.shortname.clone()
+            },
+            None => {
+                self.name = self.id.clone();
+                self.id.clone()
+            }
+        };
+        let _of_tactic = |_rows: &[EnterpriseTechnique]| -> Vec<String> {
+            _rows.iter().filter(|_r| _r.tactic.as_str() == _shortname).map(|_r| _r.tid.clone()).collect()
+        };
+        let _techniques = _of_tactic(&_json.breakdown_techniques.platforms);
+        let _subtechniques = _of_tactic(&_json.breakdown_subtechniques.platforms);
+        self.attribute("shortname", _shortname.as_str());
+        self.attribute("techniques", _techniques.len().to_string().as_str());
+        self.attribute("subtechniques", _subtechniques.len().to_string().as_str());
+        self.children = _techniques.iter().map(|_t| names.link("technique", _t)).collect();
+        self.relation("used-by", _json.breakdown_adversaries.iter()
+            .filter(|_a| !_a.is_revoked && _a.profile.tactics.items.contains(&_shortname))
+            .map(|_a| names.link("adversary", _a.group_id.as_str()))
+            .collect());
+    }
+    /// # Detail - Adversary (Private Method)
+    fn adversary(&mut self, baseline: &EnterpriseMatrixBreakdown, names: &Names)
+    {
+        let _json = baseline;
+        let _adversary = match _json.breakdown_adversaries.iter().find(|_a| _a.group_id == self.id) {
+            Some(_adversary) => _adversary,
+            None => return
+        };
+        self.name = _adversary.name.clone();
+        self.attribute("status", if _adversary.is_revoked { "revoked" } else { "active" });
+        self.attribute("aliases", join(&_adversary.aliases, ", ").as_str());
+        self.attribute("tactics", _adversary.profile.tactics.items.join(", ").as_str());
+        let mut _uses: Vec<DetailLink> = vec![];
+        _uses.extend(_adversary.profile.techniques.items.iter().map(|_t| names.link("technique", _t)));
+        _uses.extend(_adversary.profile.subtechniques.items.iter().map(|_t| names.link("subtechnique", _t)));
+        _uses.extend(_adversary.profile.malware.items.iter().map(|_m| names.software("malware", _m)));
+        _uses.extend(_adversary.profile.tools.items.iter().map(|_t| names.software("tool", _t)));
+        self.relation("uses", _uses);
+    }
+    /// # Detail - Software (Private Method)
+    /// A malware or a tool.
+    fn software(&mut self, baseline: &EnterpriseMatrixBreakdown, names: &Names)
+    {
+        let _json = baseline;
+        let _found = match self.kind.as_str() {
+            "malware" => _json.breakdown_malware.iter().find(|_m| _m.malware_id == self.id)
+                            .map(|_m| (&_m.name, &_m.aliases, &_m.platforms, _m.is_revoked, &_m.profile.techniques, &_m.profile.subtechniques)),
+            _ => _json.breakdown_tools.iter().find(|_t| _t.tool_id == self.id)
+                            .map(|_t| (&_t.name, &_t.aliases, &_t.platforms, _t.is_revoked, &_t.profile.techniques, &_t.profile.subtechniques))
+        };
+        let (_name, _aliases, _platforms, _is_revoked, _techniques, _subtechniques) = match _found {
+            Some(_software) => _software,
+            None => return
+        };
+        self.name = _name.clone();
+        self.attribute("status", if _is_revoked { "revoked" } else { "active" });
+        self.attribute("aliases", join(_aliases, ", ").as_str());
+        self.attribute("platforms", join(_platforms, ", ").as_str());
+        let mut _uses: Vec<DetailLink> = vec![];
+        _uses.extend(_techniques.items.iter().map(|_t| names.link("technique", _t)));
+        _uses.extend(_subtechniques.items.iter().map(|_t| names.link("subtechnique", _t)));
+        self.relation("uses", _uses);
+        // The profile of an adversary keeps the names of its software
+        self.relation("used-by", _json.breakdown_adversaries.iter()
+            .filter(|_a| !_a.is_revoked)
+            .filter(|_a| match self.kind.as_str() {
+                "malware" => _a.profile.malware.items.contains(_name),
+                _ => _a.profile.tools.items.contains(_name)
+            })
+            .map(|_a| names.link("adversary", _a.group_id.as_str()))
+            .collect());
+    }
+    /// # Detail - Mitigation (Private Method)
+    fn mitigation(&mut self, baseline: &EnterpriseMatrixBreakdown, names: &Names)
+    {
+        let _json = baseline;
+        let _mitigation = match _json.breakdown_mitigations.iter().find(|_m| _m.mitigation_id == self.id) {
+            Some(_mitigation) => _mitigation,
+            None => return
+        };
+        self.name = _mitigation.name.clone();
+        self.attribute("status", if _mitigation.is_deprecated { "deprecated" } else { "active" });
+        let mut _mitigates: Vec<DetailLink> = vec![];
+        _mitigates.extend(_mitigation.profile.techniques.items.iter().map(|_t| names.link("technique", _t)));
+        _mitigates.extend(_mitigation.profile.subtechniques.items.iter().map(|_t| names.link("subtechnique", _t)));
+        self.relation("mitigates", _mitigates);
+    }
+    /// # Detail - Datasource (Private Method)
+    fn datasource(&mut self, baseline: &EnterpriseMatrixBreakdown, names: &Names)
+    {
+        let _json = baseline;
+        self.name = self.id.clone();
+        let _datasource = Datasource::parse(self.id.as_str());
+        let mut _detects: Vec<DetailLink> = vec![];
+        for (_kind, _rows) in [("technique", &_json.breakdown_techniques.platforms),
+                               ("subtechnique", &_json.breakdown_subtechniques.platforms)].iter() {
+            for _row in _rows.iter().filter(|_r| _r.datasources.contains(&_datasource)) {
+                if !_detects.iter().any(|_l| _l.id == _row.tid) {
+                    _detects.push(names.link(_kind, _row.tid.as_str()));
+                }
+            }
+        }
+        let _platforms: Vec<String> = _json.breakdown_techniques.platforms.iter()
+            .chain(_json.breakdown_subtechniques.platforms.iter())
+            .filter(|_r| _r.datasources.contains(&_datasource))
+            .flat_map(|_r| _r.platform.iter().map(|_p| _p.to_string()))
+            .collect();
+        self.attribute("techniques", _detects.iter().filter(|_l| _l.kind == "technique").count().to_string().as_str());
+        self.attribute("subtechniques", _detects.iter().filter(|_l| _l.kind == "subtechnique").count().to_string().as_str());
+        self.attribute("platforms", unique(_platforms).as_str());
+        self.relation("detects", _detects);
+    }
+    /// # Detail - Attribute (Private Method)
+    fn attribute(&mut self, field: &str, value: &str)
+    {
+        let _value = match value.is_empty() {
+            true => "none",
+            false => value
+        };
+        self.attributes.push(DetailAttribute { field: field.to_string(), value: _value.to_string() });
+    }
+    /// # Detail - Relation (Private Method)
+    /// Adds a relationship type, sorted by kind and ID, unless it has no objects.
+    fn relation(&mut self, relationship: &str, mut objects: Vec<DetailLink>)
+    {
+        if objects.is_empty() {
+            return;
+        }
+        objects.sort_by(|_a, _b| _a.kind.cmp(&_b.kind).then_with(|| _a.id.cmp(&_b.id)));
+        objects.dedup();
+        self.relationships.push(DetailRelation { relationship: relationship.to_string(), objects });
+    }
+}
+
+
+/// # Names (Private Struct)
+/// The names of the IDs of a baseline, and the IDs of the software names kept by
+/// the profiles of the adversaries.
+struct Names {
+    by_id:          HashMap<String, String>,
+    by_software:    HashMap<(String, String), String>,
+}
+impl Names {
+    fn new(baseline: &EnterpriseMatrixBreakdown) -> Self
+    {
+        let _json = baseline;
+        let mut _by_id: HashMap<String, String> = HashMap::new();
+        let mut _by_software: HashMap<(String, String), String> = HashMap::new();
+        for (_tid, _name) in _json.revoked_techniques.iter().chain(_json.deprecated_techniques.iter()) {
+            _by_id.insert(_tid.clone(), _name.clone());
+        }
+        for _row in _json.breakdown_techniques.platforms.iter().chain(_json.breakdown_subtechniques.platforms.iter()) {
+            _by_id.insert(_row.tid.clone(), _row.technique.clone());
+        }
+        for _adversary in _json.breakdown_adversaries.iter() {
+            _by_id.insert(_adversary.group_id.clone(), _adversary.name.clone());
+        }
+        for _malware in _json.breakdown_malware.iter() {
+            _by_id.insert(_malware.malware_id.clone(), _malware.name.clone());
+            _by_software.insert(("malware".to_string(), _malware.name.clone()), _malware.malware_id.clone());
+        }
+        for _tool in _json.breakdown_tools.iter() {
+            _by_id.insert(_tool.tool_id.clone(), _tool.name.clone());
+            _by_software.insert(("tool".to_string(), _tool.name.clone()), _tool.tool_id.clone());
+        }
+        for _mitigation in _json.breakdown_mitigations.iter() {
+            _by_id.insert(_mitigation.mitigation_id.clone(), _mitigation.name.clone());
+        }
+        Names { by_id: _by_id, by_software: _by_software }
+    }
+    fn name(&self, id: &str) -> String
+    {
+        self.by_id.get(id).cloned().unwrap_or_else(|| "none".to_string())
+    }
+    fn link(&self, kind: &str, id: &str) -> DetailLink
+    {
+        DetailLink { kind: kind.to_string(), id: id.to_string(), name: self.name(id) }
+    }
+    fn software(&self, kind: &str, name: &str) -> DetailLink
+    {
+        let _id = self.by_software.get(&(kind.to_string(), name.to_string())).cloned().unwrap_or_else(|| "none".to_string());
+        DetailLink { kind: kind.to_string(), id: _id, name: name.to_string() }
+    }
+}
+
+
+/// # Unique (Private Function)
+/// Joins the values without their duplicates, in the order they were found.
+fn unique(values: Vec<String>) -> String
+{
+    let mut _unique: Vec<String> = vec![];
+    for _value in values {
+        if !_unique.contains(&_value) {
+            _unique.push(_value);
+        }
+    }
+    _unique.join(", ")
+}
+
+
+/// # Wrap (Private Function)
+/// Splits a text into lines of about `width` characters.
+fn wrap(text: &str, width: usize) -> Vec<String>
+{
+    let mut _lines: Vec<String> = vec![];
+    let mut _line = String::new();
+    for _word in text.split_whitespace() {
+        if !_line.is_empty() && _line.len() + _word.len() >= width {
+            _lines.push(std::mem::take(&mut _line));
+        }
+        if !_line.is_empty() {
+            _line.push(' ');
+        }
+        _line.push_str(_word);
+    }
+    if !_line.is_empty() {
+        _lines.push(_line);
+    }
+    _lines
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::enterprise::{ EnterpriseAdversary, EnterpriseTool };
+    use crate::structs::types::Platform;
+
+    fn row(tid: &str, name: &str, subtechniques: &[&str]) -> EnterpriseTechnique
+    {
+        let mut _row = EnterpriseTechnique::new();
+        _row.tid = tid.to_string();
+        _row.technique = name.to_string();
+        _row.tactic = Tactic::CredentialAccess;
+        _row.platform = vec![Platform::Windows, Platform::Linux];
+        _row.datasources = vec![Datasource::parse("process-monitoring")];
+        _row.has_subtechniques = !subtechniques.is_empty();
+        _row.subtechniques = subtechniques.iter().map(|_s| _s.to_string()).collect();
+        _row
+    }
+
+    fn baseline() -> EnterpriseMatrixBreakdown
+    {
+        let mut _json = EnterpriseMatrixBreakdown::default();
+        _json.breakdown_techniques.platforms = vec![row("T1003", "OS Credential Dumping", &["T1003.001"]),
+                                                    row("T1110", "Brute Force", &[])];
+        _json.breakdown_subtechniques.platforms = vec![row("T1003.001", "LSASS Memory", &[])];
+        _json.revoked_techniques.insert(("T1002".to_string(), "Data Compressed".to_string()));
+        _json.revoked_by.insert(("T1002".to_string(), "T1560".to_string()));
+        _json.datasources = vec![Datasource::parse("process-monitoring")];
+        _json.tactics.insert(Tactic::CredentialAccess);
+        let mut _adversary = EnterpriseAdversary::new();
+        _adversary.group_id = "G0016".to_string();
+        _adversary.name = "APT29".to_string();
+        _adversary.aliases = vec!["apt29".to_string(), "cozy-bear".to_string()];
+        _adversary.profile.techniques.items = vec!["T1003".to_string()];
+        _adversary.profile.tools.items = vec!["Mimikatz".to_string()];
+        _json.breakdown_adversaries.push(_adversary);
+        let mut _tool = EnterpriseTool::new();
+        _tool.tool_id = "S0002".to_string();
+        _tool.name = "Mimikatz".to_string();
+        _tool.profile.subtechniques.items = vec!["T1003.001".to_string()];
+        _json.breakdown_tools.push(_tool);
+        _json
+    }
+
+    fn found(term: &str) -> (String, String)
+    {
+        let _detail = ObjectDetail::find(&baseline(), term).unwrap();
+        (_detail.kind, _detail.id)
+    }
+
+    fn reason(term: &str) -> String
+    {
+        match ObjectDetail::find(&baseline(), term) {
+            Err(AssistantError::InvalidQuery(_reason)) => _reason,
+            _other => panic!("Expected An Invalid Query For `{}`, Found {:?}", term, _other)
+        }
+    }
+
+    fn relation<'a>(detail: &'a ObjectDetail, relationship: &str) -> Vec<&'a str>
+    {
+        detail.relationships.iter()
+            .filter(|_r| _r.relationship == relationship)
+            .flat_map(|_r| _r.objects.iter().map(|_o| _o.id.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn find_locates_an_id_a_name_an_alias_or_a_typo()
+    {
+        let _pair = |_kind: &str, _id: &str| (_kind.to_string(), _id.to_string());
+        assert_eq!(found("t1003.001"), _pair("subtechnique", "T1003.001"));
+        assert_eq!(found("os credential dumping"), _pair("technique", "T1003"));
+        assert_eq!(found("Cozy Bear"), _pair("adversary", "G0016"));
+        assert_eq!(found("mimikatz"), _pair("tool", "S0002"));
+        assert_eq!(found("credential-access"), _pair("tactic", "credential-access"));
+        assert_eq!(found("Process Monitoring"), _pair("datasource", "process-monitoring"));
+        assert_eq!(found("brute forse"), _pair("technique", "T1110"));
+        assert_eq!(found("T1002"), _pair("technique", "T1002"));
+    }
+
+    #[test]
+    fn find_tells_why_nothing_was_shown()
+    {
+        assert!(reason(" ").starts_with("Expected An ID Or A Name |"));
+        assert!(reason("zzzz").starts_with("Object Not Found: zzzz | Use:"));
+        assert_eq!(reason("mimkat"), "Object Not Found: mimkat | Did You Mean: Mimikatz");
+    }
+
+    #[test]
+    fn find_relates_a_technique_to_its_users_and_children()
+    {
+        let _json = baseline();
+        let _technique = ObjectDetail::find(&_json, "T1003").unwrap();
+        assert_eq!(_technique.name, "OS Credential Dumping");
+        assert_eq!(_technique.attributes[1], DetailAttribute { field: "tactics".to_string(), value: "credential-access".to_string() });
+        assert_eq!(_technique.attributes[2].value, "windows, linux");
+        assert_eq!(_technique.children.iter().map(|_c| _c.name.as_str()).collect::<Vec<&str>>(), vec!["LSASS Memory"]);
+        assert_eq!(relation(&_technique, "used-by"), vec!["G0016"]);
+        let _subtechnique = ObjectDetail::find(&_json, "T1003.001").unwrap();
+        assert_eq!(_subtechnique.parent.as_ref().map(|_p| _p.id.as_str()), Some("T1003"));
+        assert_eq!(relation(&_subtechnique, "used-by"), vec!["S0002"]);
+        let _tool = ObjectDetail::find(&_json, "S0002").unwrap();
+        assert_eq!(relation(&_tool, "uses"), vec!["T1003.001"]);
+        assert_eq!(relation(&_tool, "used-by"), vec!["G0016"]);
+        let _revoked = ObjectDetail::find(&_json, "T1002").unwrap();
+        assert_eq!((_revoked.attributes[0].value.as_str(), _revoked.name.as_str()), ("revoked", "Data Compressed"));
+        assert_eq!(relation(&_revoked, "revoked-by"), vec!["T1560"]);
+        let _datasource = ObjectDetail::find(&_json, "process-monitoring").unwrap();
+        assert_eq!(relation(&_datasource, "detects"), vec!["T1003.001", "T1003", "T1110"]);
+        assert_eq!(_datasource.attributes[0].value, "2");
+    }
+
+    #[test]
+    fn to_markdown_escapes_the_tables()
+    {
+        let mut _detail = ObjectDetail::find(&baseline(), "G0016").unwrap();
+        _detail.attribute("note", "a | b\nc");
+        let _md = _detail.to_markdown();
+        assert!(_md.starts_with("## G0016: APT29\n\n| FIELD | VALUE |\n|---|---|\n| kind | adversary |\n"));
+        assert!(_md.contains("| note | a \\| b<br>c |\n"));
+        assert!(_md.contains("\n### Relationship: uses\n\n- `T1003` OS Credential Dumping (technique)\n- `S0002` Mimikatz (tool)\n"));
+        assert!(_detail.to_text().contains("\n  Relationship: uses (2)\n"));
+    }
+
+    #[test]
+    fn wrap_keeps_the_lines_under_the_width()
+    {
+        assert_eq!(wrap("one two three four", 10), vec!["one two", "three four"]);
+        assert_eq!(wrap("unbreakable-word", 5), vec!["unbreakable-word"]);
+        assert!(wrap("  ", 10).is_empty());
+        assert_eq!(unique(vec!["b".to_string(), "a".to_string(), "b".to_string()]), "b, a");
+    }
+}
@@ -0,0 +1,479 @@
+use std::collections::{ BTreeMap, BTreeSet };
+use std::path::Path;
+
+use prettytable::{ Table, Row, Cell };
+use serde_derive::{ Deserialize, Serialize };
+
+
+use crate::modules::parser::{ EnterpriseMatrixBreakdown, EnterpriseMatrixParser };
+use crate::utils::fshandler::FileHandler;
+use crate::structs::errors::AssistantError;
+use crate::structs::types::Tactic;
+
+
+/// # Matrix Change
+/// A single difference between two versions of the matrix.
+///
+/// For set values - e.g., tactics, platforms, or the techniques of a group -
+/// `before` holds the items only found in the old version, and `after` the items
+/// only found in the new version, each joined by `|`.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MatrixChange {
+    pub category:   String,
+    pub change:     String,
+    pub id:         String,
+    pub name:       String,
+    pub before:     String,
+    pub after:      String,
+}
+
+
+/// # Technique Snapshot (Private)
+/// The state of a technique merged from its rows of every tactic in the baseline.
+#[derive(Default)]
+struct TechniqueSnapshot {
+    name:           String,
+    category:       String,
+    tactics:        BTreeSet<String>,
+    platforms:      BTreeSet<String>,
+    datasources:    BTreeSet<String>,
+}
+
+
+/// # Profile Snapshot (Private)
+/// The state of a group or a software, keyed by its ATT&CK ID.
+#[derive(Default)]
+struct ProfileSnapshot {
+    name:           String,
+    category:       String,
+    is_revoked:     bool,
+    entries:        BTreeMap<&'static str, BTreeSet<String>>,
+}
+
+
+/// # Mitre-Assistant: Matrix Differ Module
+/// Compares two versions of the enterprise matrix and reports what changed between
+/// them: techniques and subtechniques added, removed, revoked, deprecated, renamed, moved
+/// between tactics, their platform and datasource changes, and the changes of the
+/// group and software profiles.
+///
+/// Each side is either the version of a baseline in the config folder, a baseline
+/// file, or an enterprise STIX bundle.
+///
+/// ## Example
+/// ```ignore
+/// let _differ = EnterpriseMatrixDiffer::new("v7.2", "./enterprise-attack.json")?;
+///
+/// _differ.render("None", "None")?;
+/// ```
+pub struct EnterpriseMatrixDiffer {
+    pub from:       String,
+    pub to:         String,
+    pub changes:    Vec<MatrixChange>
+}
+impl EnterpriseMatrixDiffer {
+    /// # Matrix Differ - Constructor
+    /// Loads both sides and computes their changes.
+    pub fn new(from: &str, to: &str) -> Result<Self, AssistantError>
+    {
+        let _old = EnterpriseMatrixDiffer::load_source(from)?;
+        let _new = EnterpriseMatrixDiffer::load_source(to)?;
+        let mut _differ = EnterpriseMatrixDiffer {
+            from:       from.to_string(),
+            to:         to.to_string(),
+            changes:    vec![]
+        };
+        _differ.compare_techniques(&_old, &_new);
+        _differ.compare_profiles(&_old, &_new);
+        Ok(_differ)
+    }
+    /// # Matrix Differ - Load Source
+    /// Resolves one side of the comparison.
+    ///
+    /// An existing file is read as a STIX bundle when it has `"type": "bundle"`,
+    /// otherwise as a baseline.  Any other value is the version of a baseline in the
+    /// config folder, use `current` for the baseline created without a version.
+    pub fn load_source(source: &str) -> Result<EnterpriseMatrixBreakdown, AssistantError>
+    {
+        if Path::new(source).is_file() {
+            let _content = FileHandler::read_to_string(source)?;
+            let _json: serde_json::Value = match serde_json::from_str(_content.as_str()) {
+                Ok(_v) => _v,
+                Err(_e) => return Err(AssistantError::Parse(format!("Invalid Diff Source: {} - {}", source, _e)))
+            };
+            if _json["type"].as_str() == Some("bundle") {
+                let mut _emp = EnterpriseMatrixParser::new();
+                _emp.baseline_from_str(_content.as_str())?;
+                return Ok(_emp.details);
+            }
+            return match serde_json::from_value::<EnterpriseMatrixBreakdown>(_json) {
+                Ok(_b) => Ok(_b),
+                Err(_e) => Err(AssistantError::Parse(format!("Invalid Baseline File: {} - {}", source, _e)))
+            };
+        }
+        let _version = match source {
+            "current" => "None",
+            _ => source
+        };
+        let _baseline = FileHandler::versioned_name("baseline-enterprise", _version);
+        match FileHandler::read_resource("baselines", _baseline.as_str()) {
+            Some(_content) => Ok(serde_json::from_str(_content.as_str())?),
+            None => Err(AssistantError::MissingResource(format!(
+                "Baseline Not Found: {} | Create It With: `baseline -m enterprise --version {}`",
+                _baseline, source
+            )))
+        }
+    }
+    /// # Matrix Differ - Add Change (Private Method)
+    fn add_change(&mut self, category: &str, change: &str, id: &str, name: &str, before: &str, after: &str)
+    {
+        self.changes.push(MatrixChange {
+            category:   category.to_string(),
+            change:     change.to_string(),
+            id:         id.to_string(),
+            name:       name.to_string(),
+            before:     before.to_string(),
+            after:      after.to_string()
+        });
+    }
+    /// # Matrix Differ - Add Set Change (Private Method)
+    /// Records the items only found on each side of a set, when they differ.
+    fn add_set_change(&mut self, category: &str, change: &str, id: &str, name: &str,
+        old: &BTreeSet<String>, new: &BTreeSet<String>)
+    {
+        if old != new {
+            let _before: Vec<&str> = old.difference(new).map(|_s| _s.as_str()).collect();
+            let _after: Vec<&str> = new.difference(old).map(|_s| _s.as_str()).collect();
+            self.add_change(category, change, id, name, _before.join("|").as_str(), _after.join("|").as_str());
+        }
+    }
+    /// # Matrix Differ - Technique Snapshots (Private Method)
+    fn technique_snapshots(breakdown: &EnterpriseMatrixBreakdown) -> BTreeMap<String, TechniqueSnapshot>
+    {
+        let mut _snapshots: BTreeMap<String, TechniqueSnapshot> = BTreeMap::new();
+        let _rows = breakdown.breakdown_techniques.platforms.iter().map(|_t| ("technique", _t))
+            .chain(breakdown.breakdown_subtechniques.platforms.iter().map(|_t| ("subtechnique", _t)));
+        for (_category, _row) in _rows {
+            let _snapshot = _snapshots.entry(_row.tid.clone()).or_default();
+            _snapshot.name = _row.technique.clone();
+            _snapshot.category = _category.to_string();
+            if _row.tactic != Tactic::None {
+                _snapshot.tactics.insert(_row.tactic.to_string());
+            }
+            _snapshot.platforms.extend(_row.platform.iter().map(|_p| _p.to_string()));
+            _snapshot.datasources.extend(_row.datasources.iter().map(|_d| _d.to_string()));
+        }
+        _snapshots
+    }
+    /// # Matrix Differ - Category (Private Method)
+    fn category(tid: &str) -> &'static str
+    {
+        match tid.contains('.') {
+            true => "subtechnique",
+            false => "technique"
+        }
+    }
+    /// # Matrix Differ - Compare Techniques (Private Method)
+    fn compare_techniques(&mut self, old: &EnterpriseMatrixBreakdown, new: &EnterpriseMatrixBreakdown)
+    {
+        let _old = EnterpriseMatrixDiffer::technique_snapshots(old);
+        let _new = EnterpriseMatrixDiffer::technique_snapshots(new);
+        let _old_revoked: BTreeMap<&String, &String> = old.revoked_techniques.iter().map(|(_t, _n)| (_t, _n)).collect();
+        let _new_revoked: BTreeMap<&String, &String> = new.revoked_techniques.iter().map(|(_t, _n)| (_t, _n)).collect();
+        let _old_deprecated: BTreeMap<&String, &String> = old.deprecated_techniques.iter().map(|(_t, _n)| (_t, _n)).collect();
+        let _new_deprecated: BTreeMap<&String, &String> = new.deprecated_techniques.iter().map(|(_t, _n)| (_t, _n)).collect();
+        for (_tid, _name) in _new_revoked.iter().filter(|(_t, _)| !_old_revoked.contains_key(*_t)) {
+            self.add_change(EnterpriseMatrixDiffer::category(_tid), "revoked", _tid, _name, "active", "revoked");
+        }
+        for (_tid, _name) in _new_deprecated.iter().filter(|(_t, _)| !_old_deprecated.contains_key(*_t)) {
+            self.add_change(EnterpriseMatrixDiffer::category(_tid), "deprecated", _tid, _name, "active", "deprecated");
+        }
+        for (_tid, _snapshot) in _new.iter().filter(|(_t, _)| !_old.contains_key(*_t)) {
+            self.add_change(_snapshot.category.as_str(), "added", _tid, _snapshot.name.as_str(),
+                "", _snapshot.tactics.iter().cloned().collect::<Vec<String>>().join("|").as_str());
+        }
+        for (_tid, _snapshot) in _old.iter().filter(|(_t, _)| !_new.contains_key(*_t)) {
+            if _new_revoked.contains_key(_tid) || _new_deprecated.contains_key(_tid) {
+                continue;
+            }
+            self.add_change(_snapshot.category.as_str(), "removed", _tid, _snapshot.name.as_str(),
+                _snapshot.tactics.iter().cloned().collect::<Vec<String>>().join("|").as_str(), "");
+        }
+        for (_tid, _after) in _new.iter() {
+            if let Some(_before) = _old.get(_tid) {
+                let _category = _after.category.as_str();
+                let _name = _after.name.as_str();
+                if _before.name != _after.name {
+                    self.add_change(_category, "renamed", _tid, _name, _before.name.as_str(), _name);
+                }
+                self.add_set_change(_category, "tactics", _tid, _name, &_before.tactics, &_after.tactics);
+                self.add_set_change(_category, "platforms", _tid, _name, &_before.platforms, &_after.platforms);
+                self.add_set_change(_category, "datasources", _tid, _name, &_before.datasources, &_after.datasources);
+            }
+        }
+    }
+    /// # Matrix Differ - Profile Snapshots (Private Method)
+    /// Collects the groups, malware and tools of a baseline by their ATT&CK ID.
+    fn profile_snapshots(breakdown: &EnterpriseMatrixBreakdown) -> BTreeMap<String, ProfileSnapshot>
+    {
+        let _set = |_items: &Vec<String>| _items.iter().cloned().collect::<BTreeSet<String>>();
+        let mut _snapshots: BTreeMap<String, ProfileSnapshot> = BTreeMap::new();
+        for _group in breakdown.breakdown_adversaries.iter() {
+            let mut _snapshot = ProfileSnapshot {
+                name:       _group.name.clone(),
+                category:   "group".to_string(),
+                is_revoked: _group.is_revoked,
+                entries:    BTreeMap::new()
+            };
+            _snapshot.entries.insert("techniques", _set(&_group.profile.techniques.items));
+            _snapshot.entries.insert("subtechniques", _set(&_group.profile.subtechniques.items));
+            _snapshot.entries.insert("malware", _set(&_group.profile.malware.items));
+            _snapshot.entries.insert("tools", _set(&_group.profile.tools.items));
+            _snapshots.insert(_group.group_id.clone(), _snapshot);
+        }
+        for _malware in breakdown.breakdown_malware.iter() {
+            let mut _snapshot = ProfileSnapshot {
+                name:       _malware.name.clone(),
+                category:   "software".to_string(),
+                is_revoked: _malware.is_revoked,
+                entries:    BTreeMap::new()
+            };
+            _snapshot.entries.insert("platforms", _malware.platforms.iter().map(|_p| _p.to_string()).collect());
+            _snapshot.entries.insert("techniques", _set(&_malware.profile.techniques.items));
+            _snapshot.entries.insert("subtechniques", _set(&_malware.profile.subtechniques.items));
+            _snapshots.insert(_malware.malware_id.clone(), _snapshot);
+        }
+        for _tool in breakdown.breakdown_tools.iter() {
+            let mut _snapshot = ProfileSnapshot {
+                name:       _tool.name.clone(),
+                category:   "software".to_string(),
+                is_revoked: _tool.is_revoked,
+                entries:    BTreeMap::new()
+            };
+            _snapshot.entries.insert("platforms", _tool.platforms.iter().map(|_p| _p.to_string()).collect());
+            _snapshot.entries.insert("techniques", _set(&_tool.profile.techniques.items));
+            _snapshot.entries.insert("subtechniques", _set(&_tool.profile.subtechniques.items));
+            _snapshots.insert(_tool.tool_id.clone(), _snapshot);
+        }
+        _snapshots
+    }
+    /// # Matrix Differ - Compare Profiles (Private Method)
+    fn compare_profiles(&mut self, old: &EnterpriseMatrixBreakdown, new: &EnterpriseMatrixBreakdown)
+    {
+        let _old = EnterpriseMatrixDiffer::profile_snapshots(old);
+        let _new = EnterpriseMatrixDiffer::profile_snapshots(new);
+        for (_id, _snapshot) in _new.iter().filter(|(_i, _)| !_old.contains_key(*_i)) {
+            self.add_change(_snapshot.category.as_str(), "added", _id, _snapshot.name.as_str(), "", "");
+        }
+        for (_id, _snapshot) in _old.iter().filter(|(_i, _)| !_new.contains_key(*_i)) {
+            self.add_change(_snapshot.category.as_str(), "removed", _id, _snapshot.name.as_str(), "", "");
+        }
+        for (_id, _after) in _new.iter() {
+            if let Some(_before) = _old.get(_id) {
+                let _category = _after.category.as_str();
+                let _name = _after.name.as_str();
+                if !_before.is_revoked && _after.is_revoked {
+                    self.add_change(_category, "revoked", _id, _name, "active", "revoked");
+                }
+                if _before.name != _after.name {
+                    self.add_change(_category, "renamed", _id, _name, _before.name.as_str(), _name);
+                }
+                let _empty = BTreeSet::new();
+                for (_entry, _items) in _after.entries.iter() {
+                    let _previous = _before.entries.get(_entry).unwrap_or(&_empty);
+                    self.add_set_change(_category, _entry, _id, _name, _previous, _items);
+                }
+            }
+        }
+    }
+    /// # Matrix Differ - Render
+    /// Prints the changes as a table, or exports them when `_wants_export` is
+    /// `csv` or `json`.  A JSON export without an output file is printed to `stdout`.
+    pub fn render(&self, _wants_export: &str, _wants_outfile: &str) -> Result<(), AssistantError>
+    {
+        if _wants_export == "json" {
+            let _json = serde_json::to_string_pretty(&self.changes)?;
+            if _wants_outfile == "None" {
+                println!("{}", _json);
+            } else {
+                let mut _fp = FileHandler::open(_wants_outfile, "crw")?;
+                _fp.write(&_json)?;
+            }
+            return Ok(());
+        }
+        let mut _table = Table::new();
+        _table.add_row(Row::new(vec![
+            Cell::new("INDEX").style_spec("FW"),
+            Cell::new("CATEGORY").style_spec("FW"),
+            Cell::new("CHANGE").style_spec("FW"),
+            Cell::new("ID").style_spec("FW"),
+            Cell::new("NAME").style_spec("FW"),
+            Cell::new("BEFORE").style_spec("FR"),
+            Cell::new("AFTER").style_spec("FG"),
+        ]));
+        let _separator = match _wants_export {
+            "csv" => "|",
+            _ => "\n"
+        };
+        for (_idx, _change) in self.changes.iter().enumerate() {
+            let _style = match _change.change.as_str() {
+                "added" => "FG",
+                "removed" | "revoked" => "FR",
+                "deprecated" => "FY",
+                _ => "FC"
+            };
+            _table.add_row(Row::new(vec![
+                Cell::new((_idx + 1).to_string().as_str()),
+                Cell::new(_change.category.as_str()),
+                Cell::new(_change.change.as_str()).style_spec(_style),
+                Cell::new(_change.id.as_str()).style_spec(_style),
+                Cell::new(_change.name.as_str()),
+                Cell::new(_change.before.replace("|", _separator).as_str()),
+                Cell::new(_change.after.replace("|", _separator).as_str()),
+            ]));
+        }
+        if _wants_export == "csv" {
+            let _fp = FileHandler::open(_wants_outfile, "crw")?;
+            _table.to_csv(_fp.handle)?;
+        } else {
+            println!("\n\nChanges From: {} | To: {} | Total: {}\n", self.from, self.to, self.changes.len());
+            _table.printstd();
+            println!("\n\n");
+        }
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::enterprise::{ EnterpriseAdversary, EnterpriseTechnique, EnterpriseTool };
+    use crate::structs::types::{ Datasource, Platform };
+
+    fn technique(tid: &str, name: &str, tactic: Tactic, platforms: &[Platform], datasources: &[&str]) -> EnterpriseTechnique
+    {
+        let mut _technique = EnterpriseTechnique::new();
+        _technique.tid = tid.to_string();
+        _technique.technique = name.to_string();
+        _technique.tactic = tactic;
+        _technique.platform = platforms.to_vec();
+        _technique.datasources = datasources.iter().map(|_d| Datasource::parse(_d)).collect();
+        _technique
+    }
+
+    fn group(techniques: &[&str]) -> EnterpriseAdversary
+    {
+        let mut _group = EnterpriseAdversary::new();
+        _group.group_id = "G0007".to_string();
+        _group.name = "APT28".to_string();
+        _group.profile.techniques.items = techniques.iter().map(|_t| _t.to_string()).collect();
+        _group
+    }
+
+    fn baselines() -> (EnterpriseMatrixBreakdown, EnterpriseMatrixBreakdown)
+    {
+        let mut _old = EnterpriseMatrixBreakdown::default();
+        _old.breakdown_techniques.platforms = vec![
+            technique("T1003", "Credential Dumping", Tactic::CredentialAccess, &[Platform::Windows, Platform::Linux], &["process-monitoring"]),
+            technique("T1086", "PowerShell", Tactic::Execution, &[Platform::Windows], &[]),
+            technique("T1500", "Compile After Delivery", Tactic::DefenseEvasion, &[Platform::Windows], &[]),
+        ];
+        _old.breakdown_adversaries.push(group(&["T1003"]));
+        let mut _new = EnterpriseMatrixBreakdown::default();
+        _new.breakdown_techniques.platforms = vec![
+            technique("T1003", "OS Credential Dumping", Tactic::CredentialAccess, &[Platform::Windows], &["process-monitoring", "windows-registry"]),
+        ];
+        _new.breakdown_subtechniques.platforms = vec![
+            technique("T1059.001", "PowerShell", Tactic::Execution, &[Platform::Windows], &[]),
+        ];
+        _new.revoked_techniques.insert(("T1086".to_string(), "PowerShell".to_string()));
+        _new.breakdown_adversaries.push(group(&["T1003", "T1059"]));
+        let mut _tool = EnterpriseTool::new();
+        _tool.tool_id = "S0002".to_string();
+        _tool.name = "Mimikatz".to_string();
+        _new.breakdown_tools.push(_tool);
+        (_old, _new)
+    }
+
+    fn differ() -> EnterpriseMatrixDiffer
+    {
+        let (_old, _new) = baselines();
+        let mut _differ = EnterpriseMatrixDiffer { from: "old".to_string(), to: "new".to_string(), changes: vec![] };
+        _differ.compare_techniques(&_old, &_new);
+        _differ.compare_profiles(&_old, &_new);
+        _differ
+    }
+
+    fn changes(differ: &EnterpriseMatrixDiffer) -> Vec<(&str, &str, &str, &str, &str)>
+    {
+        differ.changes.iter()
+            .map(|_c| (_c.category.as_str(), _c.change.as_str(), _c.id.as_str(), _c.before.as_str(), _c.after.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn compare_reports_technique_changes()
+    {
+        let _differ = differ();
+        let _changes = changes(&_differ);
+        assert_eq!(&_changes[..6], &[
+            ("technique", "revoked", "T1086", "active", "revoked"),
+            ("subtechnique", "added", "T1059.001", "", "execution"),
+            ("technique", "removed", "T1500", "defense-evasion", ""),
+            ("technique", "renamed", "T1003", "Credential Dumping", "OS Credential Dumping"),
+            ("technique", "platforms", "T1003", "linux", ""),
+            ("technique", "datasources", "T1003", "", "windows-registry"),
+        ]);
+    }
+
+    #[test]
+    fn compare_reports_profile_changes()
+    {
+        let _differ = differ();
+        let _changes = changes(&_differ);
+        assert_eq!(&_changes[6..], &[
+            ("software", "added", "S0002", "", ""),
+            ("group", "techniques", "G0007", "", "T1059"),
+        ]);
+    }
+
+    #[test]
+    fn compare_same_baselines_is_empty()
+    {
+        let (_old, _) = baselines();
+        let mut _differ = EnterpriseMatrixDiffer { from: "old".to_string(), to: "old".to_string(), changes: vec![] };
+        _differ.compare_techniques(&_old, &_old);
+        _differ.compare_profiles(&_old, &_old);
+        assert!(_differ.changes.is_empty());
+    }
+
+    #[test]
+    fn technique_snapshots_ignore_the_placeholder_tactic()
+    {
+        let mut _breakdown = EnterpriseMatrixBreakdown::default();
+        _breakdown.breakdown_techniques.platforms = vec![
+            technique("T1086", "PowerShell", Tactic::Execution, &[Platform::Windows], &[]),
+            technique("T1086", "PowerShell", Tactic::None, &[Platform::Windows], &[]),
+        ];
+        let _snapshots = EnterpriseMatrixDiffer::technique_snapshots(&_breakdown);
+        assert_eq!(_snapshots["T1086"].tactics.iter().collect::<Vec<&String>>(), vec!["execution"]);
+    }
+
+    #[test]
+    fn load_source_reads_a_baseline_file()
+    {
+        let (_old, _) = baselines();
+        let _path = std::env::temp_dir().join(format!("mitre-assistant-differ-{}.json", std::process::id()));
+        std::fs::write(&_path, serde_json::to_string(&_old).unwrap()).unwrap();
+        let _loaded = EnterpriseMatrixDiffer::load_source(_path.to_str().unwrap());
+        std::fs::write(&_path, "{ not json").unwrap();
+        let _invalid = EnterpriseMatrixDiffer::load_source(_path.to_str().unwrap());
+        std::fs::remove_file(&_path).unwrap();
+        assert_eq!(_loaded.unwrap().breakdown_techniques.platforms.len(), 3);
+        match _invalid {
+            Err(AssistantError::Parse(_reason)) => assert!(_reason.starts_with("Invalid Diff Source:")),
+            _other => panic!("Expected A Parse Error, Found {:?}", _other.map(|_| ()))
+        }
+    }
+}
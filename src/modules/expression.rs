@@ -0,0 +1,691 @@
+use crate::modules::parser::EnterpriseMatrixBreakdown;
+use crate::structs::enterprise::EnterpriseTechnique;
+use crate::structs::errors::AssistantError;
+use crate::structs::types::{ Datasource, Platform, Tactic };
+use crate::utils::regexes::RegexPatternManager;
+
+
+/// The fields of a filter, as written before the `:`.
+const FIELDS: [&str; 10] = ["tactic", "platform", "datasource", "id", "name",
+                            "adversary", "malware", "tool", "is", "has"];
+
+
+/// # Search Filter
+/// A `field:value` of a search expression - e.g., `platform:linux`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SearchFilter {
+    Tactic(Tactic),
+    Platform(Platform),
+    Datasource(Datasource),
+    /// A technique ID, which matches its subtechniques too
+    Id(String),
+    /// A part of the technique name
+    Name(String),
+    Adversary(String),
+    Malware(String),
+    Tool(String),
+    /// `is:subtechnique` is `true`, `is:technique` is `false`
+    IsSubtechnique(bool),
+    HasSubtechniques,
+    HasDatasources,
+}
+
+
+/// # Mitre-Assistant: Expression Module
+/// The query language of the `search` subcommand, to filter the techniques by their
+/// fields with boolean logic.
+///
+/// ```text
+/// expression  :=  term ( OR term )*
+/// term        :=  factor ( AND factor )*
+/// factor      :=  NOT factor | ( expression ) | field:value
+/// ```
+///
+/// Operators ignore the case and `NOT` binds tighter than `AND`, which binds tighter
+/// than `OR`.  A value with spaces is quoted - e.g., `name:"credential dumping"`.
+/// A tactic, a platform or a technique ID can be written without its field.
+///
+/// ## Example
+/// ```ignore
+/// let _expression = SearchExpression::parse("tactic:persistence AND platform:linux AND NOT datasource:process-monitoring")?;
+///
+/// let _rows: Vec<_> = _json.breakdown_techniques.platforms.iter()
+///                          .filter(|_row| _expression.matches(_row, &_json))
+///                          .collect();
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum SearchExpression {
+    Filter(SearchFilter),
+    Not(Box<SearchExpression>),
+    And(Box<SearchExpression>, Box<SearchExpression>),
+    Or(Box<SearchExpression>, Box<SearchExpression>),
+}
+impl SearchExpression {
+    /// # Expression - Is Expression
+    /// Returns `true` when a search term is written in the query language: it has
+    /// parentheses, a `field:value` filter, or operators between filters.  The keywords
+    /// of the `search` subcommand are not expressions - e.g., `windows:process-monitoring`
+    /// or `xref:datasources:tactics`, nor the names with `and`, `or` or `not` - e.g.,
+    /// `command and scripting interpreter`.
+    pub fn is_expression(text: &str) -> bool
+    {
+        let _tokens = match tokenize(text) {
+            Ok(_tokens) => _tokens,
+            Err(_) => return text.contains('"')
+        };
+        let _has_filters = _tokens.iter().any(|(_, _token)| match _token {
+            Token::Open | Token::Close => true,
+            Token::Word(_word) => match _word.find(':') {
+                Some(_idx) => FIELDS.contains(&_word[.._idx].to_lowercase().as_str()),
+                None => false
+            },
+            _ => false
+        });
+        let _has_operators = _tokens.iter().any(|(_, _token)| matches!(_token, Token::And | Token::Or | Token::Not));
+        _has_filters || (_has_operators && SearchExpression::parse(text).is_ok())
+    }
+    /// # Expression - Parse
+    /// Parses a search expression, a syntax error tells the column where it was found.
+    pub fn parse(text: &str) -> Result<Self, AssistantError>
+    {
+        let _tokens = tokenize(text)?;
+        if _tokens.is_empty() {
+            return Err(syntax("The Expression Is Empty", 1));
+        }
+        let mut _parser = ExpressionParser { tokens: _tokens, position: 0, end: text.chars().count() + 1 };
+        let _expression = _parser.expression()?;
+        match _parser.peek() {
+            None => Ok(_expression),
+            Some((_column, Token::Close)) => Err(syntax("Unexpected `)` Without A Matching `(`", _column)),
+            Some((_column, _)) => Err(syntax("Expected `AND` Or `OR` Between Two Filters", _column))
+        }
+    }
+    /// # Expression - From Filters
+    /// Builds the expression of the filter flags of `search` - e.g., `--platform linux,macos`
+    /// and `--tactic persistence` is `(platform:linux OR platform:macos) AND tactic:persistence`.
+    /// Returns `None` when no flag has a value.
+    pub fn from_filters(filters: &[(&str, &str)]) -> Result<Option<Self>, AssistantError>
+    {
+        let mut _expression: Option<SearchExpression> = None;
+        for (_field, _values) in filters.iter().filter(|(_, _v)| *_v != "None") {
+            let mut _any: Option<SearchExpression> = None;
+            for _value in _values.split(',').map(|_v| _v.trim()).filter(|_v| !_v.is_empty()) {
+                let _filter = SearchExpression::Filter(SearchFilter::new(_field, _value, 1)?);
+                _any = Some(match _any {
+                    Some(_left) => SearchExpression::Or(Box::new(_left), Box::new(_filter)),
+                    None => _filter
+                });
+            }
+            let _any = _any.ok_or_else(|| AssistantError::InvalidQuery(format!("Expected A Value For `--{}`", _field)))?;
+            _expression = Some(match _expression {
+                Some(_left) => SearchExpression::And(Box::new(_left), Box::new(_any)),
+                None => _any
+            });
+        }
+        Ok(_expression)
+    }
+    /// # Expression - Validate
+    /// Checks that the datasources, adversaries, malware and tools of the expression
+    /// exist in the baseline, a typo would silently find nothing.
+    pub fn validate(&self, baseline: &EnterpriseMatrixBreakdown) -> Result<(), AssistantError>
+    {
+        match self {
+            SearchExpression::Not(_e) => _e.validate(baseline),
+            SearchExpression::And(_a, _b) | SearchExpression::Or(_a, _b) => {
+                _a.validate(baseline)?;
+                _b.validate(baseline)
+            },
+            SearchExpression::Filter(_filter) => {
+                let (_found, _kind, _keyword) = match _filter {
+                    SearchFilter::Datasource(_d) => (baseline.datasources.contains(_d), "Datasource", "datasources"),
+                    SearchFilter::Adversary(_a) => (baseline.breakdown_adversaries.iter().any(|_x| _x.is_named(_a)), "Adversary", "adversaries"),
+                    SearchFilter::Malware(_m) => (baseline.breakdown_malware.iter().any(|_x| _x.is_named(_m)), "Malware", "malware"),
+                    SearchFilter::Tool(_t) => (baseline.breakdown_tools.iter().any(|_x| _x.is_named(_t)), "Tool", "tools"),
+                    _ => return Ok(())
+                };
+                match _found {
+                    true => Ok(()),
+                    false => Err(AssistantError::InvalidQuery(format!("{} Not Found In The Baseline: {} | List Them With: `search -m enterprise -t {}`",
+                                                                      _kind, _filter.value(), _keyword)))
+                }
+            }
+        }
+    }
+    /// # Expression - Matches
+    /// Returns `true` when a technique row of the baseline matches the expression.
+    pub fn matches(&self, row: &EnterpriseTechnique, baseline: &EnterpriseMatrixBreakdown) -> bool
+    {
+        match self {
+            SearchExpression::Not(_e) => !_e.matches(row, baseline),
+            SearchExpression::And(_a, _b) => _a.matches(row, baseline) && _b.matches(row, baseline),
+            SearchExpression::Or(_a, _b) => _a.matches(row, baseline) || _b.matches(row, baseline),
+            SearchExpression::Filter(_filter) => _filter.matches(row, baseline),
+        }
+    }
+}
+
+
+/// # Search Term
+/// A search term of the `search` subcommand, read once before it is searched.  A comma
+/// list is a search term by item - e.g., `t1003,t1059` or `apt29,apt28`.
+///
+/// ## Example
+/// ```ignore
+/// for _term in SearchTerm::read("tactic:persistence AND platform:linux", &_json)? {
+///     match _term {
+///         SearchTerm::Expression(_expression) => { ... },
+///         ...
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum SearchTerm {
+    Expression(SearchExpression),
+    Id(String),
+    SubtechniqueId(String),
+    /// A part of the name of a technique, a subtechnique, an adversary, a malware, a tool or a mitigation
+    Name(String),
+    Tactic(Tactic),
+    Platform(Platform),
+    /// A datasource, of a platform when written `platform:datasource`
+    Datasource(String),
+    Adversary(String),
+    Malware(String),
+    Tool(String),
+    Revoked,
+    Deprecated,
+    Stats,
+    Overlap,
+    NoSubtechniques,
+    NoDatasources,
+    AllTechniques,
+    AllSubtechniques,
+    AllTactics,
+    AllPlatforms,
+    AllDatasources,
+    AllAdversaries,
+    AllMalware,
+    AllTools,
+    XrefDatasourcesPlatforms,
+    XrefDatasourcesTactics,
+}
+impl SearchTerm {
+    /// # Term - Read
+    /// Reads a search term, an expression is parsed and checked against the baseline.
+    /// Returns no term when nothing is searchable - e.g., `t1`.
+    pub fn read(text: &str, baseline: &EnterpriseMatrixBreakdown) -> Result<Vec<Self>, AssistantError>
+    {
+        let _st = text.trim().to_lowercase();
+        let _st = _st.as_str();
+        if SearchExpression::is_expression(_st) {
+            let _expression = SearchExpression::parse(text.trim())?;
+            _expression.validate(baseline)?;
+            return Ok(vec![SearchTerm::Expression(_expression)]);
+        }
+        let _keyword = match _st {
+            "revoked" => Some(SearchTerm::Revoked),
+            "deprecated" => Some(SearchTerm::Deprecated),
+            "stats" => Some(SearchTerm::Stats),
+            "overlap" => Some(SearchTerm::Overlap),
+            "nosub" => Some(SearchTerm::NoSubtechniques),
+            "nodatasources" => Some(SearchTerm::NoDatasources),
+            "techniques" => Some(SearchTerm::AllTechniques),
+            "subtechniques" => Some(SearchTerm::AllSubtechniques),
+            "tactics" => Some(SearchTerm::AllTactics),
+            "platforms" => Some(SearchTerm::AllPlatforms),
+            "datasources" => Some(SearchTerm::AllDatasources),
+            "adversaries" => Some(SearchTerm::AllAdversaries),
+            "malware" => Some(SearchTerm::AllMalware),
+            "tools" => Some(SearchTerm::AllTools),
+            "xref:datasources:platforms" => Some(SearchTerm::XrefDatasourcesPlatforms),
+            "xref:datasources:tactics" => Some(SearchTerm::XrefDatasourcesTactics),
+            _ => None
+        };
+        if let Some(_keyword) = _keyword {
+            return Ok(vec![_keyword]);
+        }
+        let _tactic = Tactic::parse(_st);
+        if !_tactic.is_other() {
+            return Ok(vec![SearchTerm::Tactic(_tactic)]);
+        }
+        let _platform = Platform::parse(_st);
+        if !_platform.is_other() {
+            return Ok(vec![SearchTerm::Platform(_platform)]);
+        }
+        // The names of the baseline are searched as a whole or as a comma list
+        let _items = _st.split(',').map(|_i| _i.trim()).filter(|_i| !_i.is_empty());
+        let _scanner_ds = RegexPatternManager::load_search_datasources(&baseline.datasources, &baseline.platforms);
+        let _scanner_ad = RegexPatternManager::load_search_adversaries(&baseline.adversaries);
+        let _scanner_mw = RegexPatternManager::load_search_malware(&baseline.malware);
+        let _scanner_to = RegexPatternManager::load_search_tools(&baseline.tools);
+        let _terms = if _scanner_ds.pattern.is_match(_st) {
+            _items.map(|_i| SearchTerm::Datasource(_i.to_string())).collect()
+        } else if _scanner_ad.pattern.is_match(_st) {
+            _items.map(|_i| SearchTerm::Adversary(_i.to_string())).collect()
+        } else if _scanner_mw.pattern.is_match(_st) {
+            _items.map(|_i| SearchTerm::Malware(_i.to_string())).collect()
+        } else if _scanner_to.pattern.is_match(_st) {
+            _items.map(|_i| SearchTerm::Tool(_i.to_string())).collect()
+        } else {
+            let _scanner = RegexPatternManager::load_search_term_patterns();
+            _items.filter_map(|_i| match _scanner.pattern.matches(_i).into_iter().next() {
+                Some(0) => Some(SearchTerm::Id(_i.to_string())),
+                Some(1) => Some(SearchTerm::SubtechniqueId(_i.to_string())),
+                Some(_) => Some(SearchTerm::Name(_i.to_string())),
+                None => None
+            }).collect()
+        };
+        Ok(_terms)
+    }
+    /// # Term - Kind
+    /// Returns the kind of the results of the term, which tells how they are rendered
+    /// and the `kind` of their JSON export.
+    pub fn kind(&self) -> &'static str
+    {
+        match self {
+            SearchTerm::Adversary(_) | SearchTerm::AllAdversaries => "adversaries",
+            SearchTerm::Malware(_) | SearchTerm::AllMalware => "malware",
+            SearchTerm::Tool(_) | SearchTerm::AllTools => "tools",
+            SearchTerm::Revoked => "revoked",
+            SearchTerm::Deprecated => "deprecated",
+            SearchTerm::Stats => "stats",
+            SearchTerm::AllTactics => "tactics",
+            SearchTerm::AllPlatforms => "platforms",
+            SearchTerm::AllDatasources => "datasources",
+            SearchTerm::XrefDatasourcesPlatforms => "xref:platforms",
+            SearchTerm::XrefDatasourcesTactics => "xref:tactics",
+            SearchTerm::Name(_) => "names",
+            _ => "techniques"
+        }
+    }
+}
+
+
+impl SearchFilter {
+    /// # Filter - New (Private Method)
+    /// Reads the value of a field, the tactics and the platforms must be known.
+    fn new(field: &str, value: &str, column: usize) -> Result<Self, AssistantError>
+    {
+        let _value = value.trim();
+        if _value.is_empty() {
+            return Err(syntax(format!("Expected A Value After `{}:`", field).as_str(), column));
+        }
+        let _filter = match field {
+            "tactic" => SearchFilter::Tactic(_value.parse()?),
+            "platform" => SearchFilter::Platform(_value.parse()?),
+            "datasource" => SearchFilter::Datasource(_value.parse()?),
+            "id" => SearchFilter::Id(_value.to_uppercase()),
+            "name" => SearchFilter::Name(_value.to_lowercase()),
+            "adversary" => SearchFilter::Adversary(_value.to_string()),
+            "malware" => SearchFilter::Malware(_value.to_string()),
+            "tool" => SearchFilter::Tool(_value.to_string()),
+            "is" => match _value.to_lowercase().as_str() {
+                "subtechnique" => SearchFilter::IsSubtechnique(true),
+                "technique" => SearchFilter::IsSubtechnique(false),
+                _ => return Err(hint(format!("Unknown Value `is:{}`", _value).as_str(), column, "Use: is:technique, is:subtechnique"))
+            },
+            "has" => match _value.to_lowercase().as_str() {
+                "subtechniques" => SearchFilter::HasSubtechniques,
+                "datasources" => SearchFilter::HasDatasources,
+                _ => return Err(hint(format!("Unknown Value `has:{}`", _value).as_str(), column, "Use: has:subtechniques, has:datasources"))
+            },
+            _ => return Err(hint(format!("Unknown Field `{}`", field).as_str(), column, format!("Use: {}", FIELDS.join(", ")).as_str()))
+        };
+        Ok(_filter)
+    }
+    /// # Filter - Bare (Private Method)
+    /// Reads a value written without its field: a tactic, a platform or a technique ID.
+    fn bare(value: &str, column: usize) -> Result<Self, AssistantError>
+    {
+        let _tactic = Tactic::parse(value);
+        let _platform = Platform::parse(value);
+        let _is_tid = value.len() > 1
+            && matches!(value.chars().next(), Some('t') | Some('T'))
+            && value[1..].chars().all(|c| c.is_ascii_digit() || c == '.');
+        if !_tactic.is_other() {
+            Ok(SearchFilter::Tactic(_tactic))
+        } else if !_platform.is_other() {
+            Ok(SearchFilter::Platform(_platform))
+        } else if _is_tid {
+            Ok(SearchFilter::Id(value.to_uppercase()))
+        } else {
+            Err(hint(format!("Expected A Filter Like `field:value`, Found `{}`", value).as_str(), column, format!("Use: {}", FIELDS.join(", ")).as_str()))
+        }
+    }
+    /// # Filter - Value (Private Method)
+    fn value(&self) -> String
+    {
+        match self {
+            SearchFilter::Tactic(_v) => _v.to_string(),
+            SearchFilter::Platform(_v) => _v.to_string(),
+            SearchFilter::Datasource(_v) => _v.to_string(),
+            SearchFilter::Id(_v) | SearchFilter::Name(_v) | SearchFilter::Adversary(_v)
+                | SearchFilter::Malware(_v) | SearchFilter::Tool(_v) => _v.clone(),
+            SearchFilter::IsSubtechnique(true) => "subtechnique".to_string(),
+            SearchFilter::IsSubtechnique(false) => "technique".to_string(),
+            SearchFilter::HasSubtechniques => "subtechniques".to_string(),
+            SearchFilter::HasDatasources => "datasources".to_string(),
+        }
+    }
+    /// # Filter - Matches (Private Method)
+    fn matches(&self, row: &EnterpriseTechnique, baseline: &EnterpriseMatrixBreakdown) -> bool
+    {
+        match self {
+            SearchFilter::Tactic(_tactic) => &row.tactic == _tactic,
+            SearchFilter::Platform(_platform) => row.platform.contains(_platform),
+            SearchFilter::Datasource(_datasource) => row.datasources.contains(_datasource),
+            SearchFilter::Id(_tid) => row.tid == *_tid || row.tid.starts_with(format!("{}.", _tid).as_str()),
+            SearchFilter::Name(_name) => row.technique.to_lowercase().contains(_name.as_str()),
+            SearchFilter::Adversary(_name) => baseline.breakdown_adversaries.iter()
+                .any(|_a| !_a.is_revoked && _a.is_named(_name) && _a.uses(row.tid.as_str())),
+            SearchFilter::Malware(_name) => baseline.breakdown_malware.iter()
+                .any(|_m| !_m.is_revoked && _m.is_named(_name) && _m.uses(row.tid.as_str())),
+            SearchFilter::Tool(_name) => baseline.breakdown_tools.iter()
+                .any(|_t| !_t.is_revoked && _t.is_named(_name) && _t.uses(row.tid.as_str())),
+            SearchFilter::IsSubtechnique(_wanted) => row.tid.contains('.') == *_wanted,
+            SearchFilter::HasSubtechniques => row.has_subtechniques,
+            SearchFilter::HasDatasources => !row.datasources.is_empty(),
+        }
+    }
+}
+
+
+/// # Token (Private Enum)
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Open,
+    Close,
+    And,
+    Or,
+    Not,
+    Word(String),
+}
+
+
+/// # Tokenize (Private Function)
+/// Splits an expression into its tokens and their column, a quoted part of a word
+/// keeps its spaces and parentheses.
+fn tokenize(text: &str) -> Result<Vec<(usize, Token)>, AssistantError>
+{
+    let mut _tokens: Vec<(usize, Token)> = vec![];
+    let mut _chars = text.chars().enumerate().peekable();
+    while let Some((_idx, c)) = _chars.next() {
+        let _column = _idx + 1;
+        if c.is_whitespace() {
+            continue;
+        }
+        if c == '(' {
+            _tokens.push((_column, Token::Open));
+            continue;
+        }
+        if c == ')' {
+            _tokens.push((_column, Token::Close));
+            continue;
+        }
+        let mut _word = String::new();
+        let mut _quoted = c == '"';
+        if !_quoted {
+            _word.push(c);
+        }
+        while let Some(&(_, _next)) = _chars.peek() {
+            if _quoted {
+                _chars.next();
+                if _next == '"' {
+                    _quoted = false;
+                } else {
+                    _word.push(_next);
+                }
+            } else if _next.is_whitespace() || _next == '(' || _next == ')' {
+                break;
+            } else {
+                _chars.next();
+                if _next == '"' {
+                    _quoted = true;
+                } else {
+                    _word.push(_next);
+                }
+            }
+        }
+        if _quoted {
+            return Err(syntax("Missing The Closing Quote Of A Value", _column));
+        }
+        let _token = match _word.to_uppercase().as_str() {
+            "AND" => Token::And,
+            "OR" => Token::Or,
+            "NOT" => Token::Not,
+            _ => Token::Word(_word)
+        };
+        _tokens.push((_column, _token));
+    }
+    Ok(_tokens)
+}
+
+
+/// # Expression Parser (Private Struct)
+/// A recursive descent parser over the tokens of an expression.
+struct ExpressionParser {
+    tokens:     Vec<(usize, Token)>,
+    position:   usize,
+    end:        usize,
+}
+impl ExpressionParser {
+    fn peek(&self) -> Option<(usize, Token)>
+    {
+        self.tokens.get(self.position).cloned()
+    }
+    fn next(&mut self) -> Option<(usize, Token)>
+    {
+        let _token = self.peek();
+        self.position += 1;
+        _token
+    }
+    fn expression(&mut self) -> Result<SearchExpression, AssistantError>
+    {
+        let mut _left = self.term()?;
+        while let Some((_, Token::Or)) = self.peek() {
+            self.next();
+            _left = SearchExpression::Or(Box::new(_left), Box::new(self.term()?));
+        }
+        Ok(_left)
+    }
+    fn term(&mut self) -> Result<SearchExpression, AssistantError>
+    {
+        let mut _left = self.factor()?;
+        while let Some((_, Token::And)) = self.peek() {
+            self.next();
+            _left = SearchExpression::And(Box::new(_left), Box::new(self.factor()?));
+        }
+        Ok(_left)
+    }
+    fn factor(&mut self) -> Result<SearchExpression, AssistantError>
+    {
+        match self.next() {
+            Some((_, Token::Not)) => Ok(SearchExpression::Not(Box::new(self.factor()?))),
+            Some((_column, Token::Open)) => {
+                let _expression = self.expression()?;
+                match self.next() {
+                    Some((_, Token::Close)) => Ok(_expression),
+                    _ => Err(syntax("Missing The Closing `)` Of The `(`", _column))
+                }
+            },
+            Some((_column, Token::Word(_word))) => {
+                let _filter = match _word.find(':') {
+                    Some(_idx) => SearchFilter::new(_word[.._idx].to_lowercase().as_str(), &_word[_idx + 1..], _column)?,
+                    None => SearchFilter::bare(_word.as_str(), _column)?
+                };
+                Ok(SearchExpression::Filter(_filter))
+            },
+            Some((_column, Token::Close)) => Err(syntax("Expected A Filter, Found `)`", _column)),
+            Some((_column, _)) => Err(syntax("Expected A Filter, Found An Operator", _column)),
+            None => Err(syntax("Expected A Filter At The End Of The Expression", self.end))
+        }
+    }
+}
+
+
+/// # Syntax (Private Function)
+fn syntax(reason: &str, column: usize) -> AssistantError
+{
+    hint(reason, column, "e.g., tactic:persistence AND NOT platform:windows")
+}
+
+
+/// # Hint (Private Function)
+fn hint(reason: &str, column: usize, hint: &str) -> AssistantError
+{
+    AssistantError::InvalidQuery(format!("{} At Column {} | {}", reason, column, hint))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(field: &str, value: &str) -> SearchExpression
+    {
+        SearchExpression::Filter(SearchFilter::new(field, value, 1).unwrap())
+    }
+
+    fn error(text: &str) -> String
+    {
+        match SearchExpression::parse(text) {
+            Err(AssistantError::InvalidQuery(_reason)) => _reason,
+            _other => panic!("Expected An Invalid Query For `{}`, Found {:?}", text, _other)
+        }
+    }
+
+    #[test]
+    fn parse_binds_not_before_and_before_or()
+    {
+        let _expression = SearchExpression::parse("tactic:persistence OR platform:linux and not is:subtechnique").unwrap();
+        let _wanted = SearchExpression::Or(
+            Box::new(filter("tactic", "persistence")),
+            Box::new(SearchExpression::And(
+                Box::new(filter("platform", "linux")),
+                Box::new(SearchExpression::Not(Box::new(filter("is", "subtechnique"))))
+            ))
+        );
+        assert_eq!(_expression, _wanted);
+    }
+
+    #[test]
+    fn parse_groups_with_parentheses()
+    {
+        let _expression = SearchExpression::parse("(tactic:persistence OR platform:linux) AND has:datasources").unwrap();
+        let _wanted = SearchExpression::And(
+            Box::new(SearchExpression::Or(
+                Box::new(filter("tactic", "persistence")),
+                Box::new(filter("platform", "linux"))
+            )),
+            Box::new(SearchExpression::Filter(SearchFilter::HasDatasources))
+        );
+        assert_eq!(_expression, _wanted);
+    }
+
+    #[test]
+    fn parse_reads_quoted_and_bare_values()
+    {
+        assert_eq!(SearchExpression::parse("name:\"Credential (OS) Dumping\"").unwrap(),
+                   SearchExpression::Filter(SearchFilter::Name("credential (os) dumping".to_string())));
+        assert_eq!(SearchExpression::parse("Persistence").unwrap(),
+                   SearchExpression::Filter(SearchFilter::Tactic(Tactic::Persistence)));
+        assert_eq!(SearchExpression::parse("windows").unwrap(),
+                   SearchExpression::Filter(SearchFilter::Platform(Platform::Windows)));
+        assert_eq!(SearchExpression::parse("t1003.001").unwrap(),
+                   SearchExpression::Filter(SearchFilter::Id("T1003.001".to_string())));
+        assert_eq!(SearchExpression::parse("datasource:\"Windows Registry\"").unwrap(),
+                   SearchExpression::Filter(SearchFilter::Datasource(Datasource::parse("windows-registry"))));
+    }
+
+    #[test]
+    fn parse_tells_the_column_of_an_error()
+    {
+        assert!(error("").starts_with("The Expression Is Empty At Column 1 |"));
+        assert!(error("tactic:persistence AND color:red").starts_with("Unknown Field `color` At Column 24 |"));
+        assert!(error("(tactic:persistence OR windows").starts_with("Missing The Closing `)` Of The `(` At Column 1 |"));
+        assert!(error("windows)").starts_with("Unexpected `)` Without A Matching `(` At Column 8 |"));
+        assert!(error("windows linux").starts_with("Expected `AND` Or `OR` Between Two Filters At Column 9 |"));
+        assert!(error("windows AND").starts_with("Expected A Filter At The End Of The Expression At Column 12 |"));
+        assert!(error("windows AND OR linux").starts_with("Expected A Filter, Found An Operator At Column 13 |"));
+        assert!(error("name:\"credential").starts_with("Missing The Closing Quote Of A Value At Column 1 |"));
+        assert!(error("is:tactic").starts_with("Unknown Value `is:tactic` At Column 1 |"));
+        assert!(error("tactic:").starts_with("Expected A Value After `tactic:` At Column 1 |"));
+        assert!(error("linux OR banana").starts_with("Expected A Filter Like `field:value`, Found `banana` At Column 10 |"));
+    }
+
+    #[test]
+    fn is_expression_skips_the_search_keywords()
+    {
+        assert!(SearchExpression::is_expression("tactic:persistence"));
+        assert!(SearchExpression::is_expression("windows AND linux"));
+        assert!(SearchExpression::is_expression("NOT windows"));
+        assert!(!SearchExpression::is_expression("windows:process-monitoring"));
+        assert!(!SearchExpression::is_expression("xref:datasources:tactics"));
+        assert!(!SearchExpression::is_expression("T1003"));
+        assert!(!SearchExpression::is_expression("stats:datasources:platforms"));
+    }
+
+    #[test]
+    fn is_expression_leaves_the_names_with_operators_to_the_name_search()
+    {
+        assert!(!SearchExpression::is_expression("command and scripting interpreter"));
+        assert!(!SearchExpression::is_expression("obfuscated files or information"));
+        assert!(!SearchExpression::is_expression("do not disturb"));
+        assert!(SearchExpression::is_expression("command AND name:scripting"));
+        assert!(SearchExpression::is_expression("(persistence)"));
+    }
+
+    #[test]
+    fn bare_reads_a_multibyte_first_character()
+    {
+        assert!(!SearchExpression::is_expression("é AND linux"));
+        assert!(error("é AND linux").starts_with("Expected A Filter Like `field:value`, Found `é` At Column 1 |"));
+        assert!(error("linux AND ü1003").starts_with("Expected A Filter Like `field:value`, Found `ü1003` At Column 11 |"));
+    }
+
+    #[test]
+    fn read_classifies_the_search_terms()
+    {
+        let _baseline = EnterpriseMatrixBreakdown {
+            adversaries:    vec!["apt29".to_string(), "apt28".to_string()],
+            tools:          vec!["mimikatz".to_string()],
+            datasources:    vec![Datasource::parse("process-monitoring")],
+            platforms:      vec![Platform::Windows].into_iter().collect(),
+            ..Default::default()
+        };
+        let _read = |_text: &str| SearchTerm::read(_text, &_baseline).unwrap();
+        assert_eq!(_read("Revoked"), vec![SearchTerm::Revoked]);
+        assert_eq!(_read("xref:datasources:tactics"), vec![SearchTerm::XrefDatasourcesTactics]);
+        assert_eq!(_read("credential-access"), vec![SearchTerm::Tactic(Tactic::CredentialAccess)]);
+        assert_eq!(_read("windows:process-monitoring"), vec![SearchTerm::Datasource("windows:process-monitoring".to_string())]);
+        assert_eq!(_read("APT29, apt28"), vec![SearchTerm::Adversary("apt29".to_string()), SearchTerm::Adversary("apt28".to_string())]);
+        assert_eq!(_read("mimikatz"), vec![SearchTerm::Tool("mimikatz".to_string())]);
+        assert_eq!(_read("t1003,t1003.001,credential dumping,t1"),
+                   vec![SearchTerm::Id("t1003".to_string()),
+                        SearchTerm::SubtechniqueId("t1003.001".to_string()),
+                        SearchTerm::Name("credential dumping".to_string())]);
+        assert_eq!(_read("command and scripting interpreter"), vec![SearchTerm::Name("command and scripting interpreter".to_string())]);
+        assert_eq!(_read("windows AND NOT persistence"),
+                   vec![SearchTerm::Expression(SearchExpression::parse("platform:windows AND NOT tactic:persistence").unwrap())]);
+        assert!(SearchTerm::read("datasource:dns", &_baseline).is_err());
+        assert_eq!(_read("APT29")[0].kind(), "adversaries");
+        assert_eq!(_read("t1003")[0].kind(), "techniques");
+        assert_eq!(_read("powershel")[0].kind(), "names");
+    }
+
+    #[test]
+    fn from_filters_joins_values_with_or_and_flags_with_and()
+    {
+        let _expression = SearchExpression::from_filters(&[("platform", "linux,macos"), ("tactic", "persistence"), ("datasource", "None")]).unwrap();
+        let _wanted = SearchExpression::And(
+            Box::new(SearchExpression::Or(
+                Box::new(filter("platform", "linux")),
+                Box::new(filter("platform", "macos"))
+            )),
+            Box::new(filter("tactic", "persistence"))
+        );
+        assert_eq!(_expression, Some(_wanted));
+        assert_eq!(SearchExpression::from_filters(&[("tactic", "None")]).unwrap(), None);
+        assert!(SearchExpression::from_filters(&[("platform", " , ")]).is_err());
+    }
+}
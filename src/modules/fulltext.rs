@@ -0,0 +1,364 @@
+use serde_derive::{ Deserialize, Serialize };
+
+use std::collections::{ HashMap, HashSet };
+
+
+use crate::modules::parser::EnterpriseMatrixBreakdown;
+use crate::structs::enterprise::EnterpriseDescription;
+
+
+/// The most results of a full-text search.
+pub const MAX_RESULTS: usize = 25;
+/// The words of a snippet, around its best match.
+const SNIPPET_WORDS: usize = 24;
+/// The words too common to rank anything.
+const STOPWORDS: [&str; 32] = ["a", "an", "and", "are", "as", "at", "be", "by", "can", "for",
+                               "from", "has", "have", "if", "in", "into", "is", "it", "its", "may",
+                               "of", "on", "or", "such", "that", "the", "their", "this", "to", "when",
+                               "which", "with"];
+
+
+/// # Full-Text Match
+/// A technique, subtechnique, adversary, malware, tool or mitigation found by the
+/// words of its description or its detection.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct FullTextMatch {
+    pub score:      f64,
+    pub kind:       String,
+    pub id:         String,
+    pub name:       String,
+    /// The text of the snippet: `description` or `detection`
+    pub field:      String,
+    /// The words around the best match, each match between `**`
+    pub snippet:    String,
+}
+
+
+/// # Mitre-Assistant: Full-Text Module
+/// A local index of the descriptions and the detections of a baseline, without an
+/// external search service.
+///
+/// The words are lowercased, the stopwords dropped and the rest stemmed - e.g.,
+/// `scheduled` and `schedules` are `schedul`.  The results are ranked by BM25, a
+/// name counts twice, and a result with every word of the search term ranks first.
+///
+/// ## Example
+/// ```ignore
+/// let _index = FullTextIndex::new(&_json);
+/// let _matches = _index.search("lsass memory");
+/// ```
+pub struct FullTextIndex<'a> {
+    documents:  &'a [EnterpriseDescription],
+    postings:   HashMap<String, Vec<(usize, usize)>>,   // The stem to its documents and their counts
+    lengths:    Vec<usize>,
+    average:    f64,
+}
+impl<'a> FullTextIndex<'a> {
+    pub fn new(baseline: &'a EnterpriseMatrixBreakdown) -> Self
+    {
+        let _documents = baseline.descriptions.as_slice();
+        let mut _postings: HashMap<String, Vec<(usize, usize)>> = HashMap::new();
+        let mut _lengths: Vec<usize> = vec![];
+        for (_idx, _document) in _documents.iter().enumerate() {
+            let mut _stems = tokenize(_document.name.as_str());
+            _stems.extend(_stems.clone());
+            _stems.extend(tokenize(text(&_document.description)));
+            _stems.extend(tokenize(text(&_document.detection)));
+            let mut _counts: HashMap<String, usize> = HashMap::new();
+            for _stem in _stems.iter() {
+                *_counts.entry(_stem.clone()).or_insert(0) += 1;
+            }
+            for (_stem, _count) in _counts {
+                _postings.entry(_stem).or_default().push((_idx, _count));
+            }
+            _lengths.push(_stems.len());
+        }
+        let _average = match _lengths.is_empty() {
+            true => 1.0,
+            false => _lengths.iter().sum::<usize>() as f64 / _lengths.len() as f64
+        };
+        FullTextIndex {
+            documents:  _documents,
+            postings:   _postings,
+            lengths:    _lengths,
+            average:    _average,
+        }
+    }
+    /// # Full-Text - Is Empty
+    /// Returns `true` when the baseline has no descriptions, it was written by an
+    /// earlier release.
+    pub fn is_empty(&self) -> bool
+    {
+        self.documents.is_empty()
+    }
+    /// # Full-Text - Search
+    /// Returns the best `MAX_RESULTS` documents of a search term, with a snippet.
+    pub fn search(&self, term: &str) -> Vec<FullTextMatch>
+    {
+        let mut _stems = tokenize(term);
+        let mut _seen: HashSet<String> = HashSet::new();
+        _stems.retain(|_s| _seen.insert(_s.clone()));
+        if _stems.is_empty() {
+            return vec![];
+        }
+        let (_k1, _b) = (1.2, 0.75);
+        let _total = self.documents.len() as f64;
+        let mut _scores: HashMap<usize, (f64, usize)> = HashMap::new();
+        for _stem in _stems.iter() {
+            let _postings = match self.postings.get(_stem) {
+                Some(_p) => _p,
+                None => continue
+            };
+            let _found = _postings.len() as f64;
+            let _idf = (1.0 + (_total - _found + 0.5) / (_found + 0.5)).ln();
+            for (_idx, _count) in _postings.iter() {
+                let _tf = *_count as f64;
+                let _norm = 1.0 - _b + _b * self.lengths[*_idx] as f64 / self.average;
+                let _entry = _scores.entry(*_idx).or_insert((0.0, 0));
+                _entry.0 += _idf * _tf * (_k1 + 1.0) / (_tf + _k1 * _norm);
+                _entry.1 += 1;
+            }
+        }
+        let mut _ranked: Vec<(usize, f64)> = _scores.into_iter()
+            .map(|(_idx, (_score, _words))| (_idx, _score * _words as f64 / _stems.len() as f64))
+            .collect();
+        _ranked.sort_by(|_a, _b| _b.1.partial_cmp(&_a.1).unwrap_or(std::cmp::Ordering::Equal)
+                                      .then_with(|| self.documents[_a.0].id.cmp(&self.documents[_b.0].id)));
+        _ranked.into_iter()
+            .take(MAX_RESULTS)
+            .map(|(_idx, _score)| {
+                let _document = &self.documents[_idx];
+                let (_field, _snippet) = best_snippet(_document, &_stems);
+                FullTextMatch {
+                    score:      (_score * 100.0).round() / 100.0,
+                    kind:       _document.kind.clone(),
+                    id:         _document.id.clone(),
+                    name:       _document.name.clone(),
+                    field:      _field.to_string(),
+                    snippet:    _snippet,
+                }
+            })
+            .collect()
+    }
+}
+
+
+/// # Tokenize
+/// Splits a text into its stemmed words, without the stopwords.
+///
+/// ```ignore
+/// assert_eq!(tokenize("Scheduled Task XML"), vec!["schedul", "task", "xml"]);
+/// ```
+pub fn tokenize(text: &str) -> Vec<String>
+{
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|_word| !_word.is_empty())
+        .map(|_word| _word.to_lowercase())
+        .filter(|_word| !STOPWORDS.contains(&_word.as_str()))
+        .map(|_word| stem(_word.as_str()))
+        .collect()
+}
+
+
+/// # Stem
+/// A light English stemmer, the plurals and the `-ing`, `-ed`, `-ly` and `-e`
+/// endings are removed - e.g., `dumping` is `dump` and `memories` is `memory`.
+pub fn stem(word: &str) -> String
+{
+    let mut _word = word.to_lowercase();
+    if _word.len() <= 3 || !_word.chars().all(|c| c.is_ascii_alphabetic()) {
+        return _word;
+    }
+    if _word.ends_with("sses") {
+        _word.truncate(_word.len() - 2);
+    } else if _word.ends_with("ies") {
+        _word.truncate(_word.len() - 3);
+        _word.push('y');
+    } else if _word.ends_with('s') && !_word.ends_with("ss") && !_word.ends_with("us") && !_word.ends_with("is") {
+        _word.pop();
+    }
+    for _suffix in ["ing", "ed", "ly"].iter() {
+        let _rest = _word.len().saturating_sub(_suffix.len());
+        if _word.ends_with(_suffix) && _rest >= 3 && _word[.._rest].chars().any(is_vowel) {
+            _word.truncate(_rest);
+            let _last: Vec<char> = _word.chars().rev().take(2).collect();
+            if _last.len() == 2 && _last[0] == _last[1] && !is_vowel(_last[0]) && !"lsz".contains(_last[0]) {
+                _word.pop();
+            }
+            break;
+        }
+    }
+    if _word.len() > 4 && _word.ends_with('e') {
+        _word.pop();
+    }
+    _word
+}
+
+
+/// # Best Snippet (Private Function)
+/// Returns the field and the words around the most matches of the stems, the
+/// description when neither text matches.
+fn best_snippet(document: &EnterpriseDescription, stems: &[String]) -> (&'static str, String)
+{
+    let mut _best = ("description", 0usize, String::new());
+    for (_field, _text) in [("description", text(&document.description)), ("detection", text(&document.detection))].iter() {
+        let (_hits, _snippet) = snippet(_text, stems);
+        if _hits > _best.1 || (_best.2.is_empty() && !_snippet.is_empty()) {
+            _best = (_field, _hits, _snippet);
+        }
+    }
+    (_best.0, _best.2)
+}
+
+
+/// # Snippet (Private Function)
+/// Returns the count of matches and the `SNIPPET_WORDS` words around them, each
+/// match between `**`.
+fn snippet(text: &str, stems: &[String]) -> (usize, String)
+{
+    let _words: Vec<&str> = text.split_whitespace().collect();
+    let _matches: Vec<bool> = _words.iter()
+        .map(|_w| tokenize(_w).iter().any(|_s| stems.contains(_s)))
+        .collect();
+    if _words.is_empty() {
+        return (0, String::new());
+    }
+    let _window = SNIPPET_WORDS.min(_words.len());
+    let mut _start = 0;
+    let mut _hits = 0;
+    for _idx in 0..=(_words.len() - _window) {
+        let _count = _matches[_idx.._idx + _window].iter().filter(|_m| **_m).count();
+        if _count > _hits {
+            _hits = _count;
+            _start = _idx;
+        }
+    }
+    // Begin a few words before the first match, to read it in context
+    if let Some(_first) = _matches[_start.._start + _window].iter().position(|_m| *_m) {
+        _start = (_start + _first).saturating_sub(4).min(_words.len() - _window);
+    }
+    let _end = _start + _window;
+    let mut _snippet: Vec<String> = _words[_start.._end].iter().zip(_matches[_start.._end].iter())
+        .map(|(_w, _m)| match _m {
+            true => format!("**{}**", _w),
+            false => _w.to_string()
+        })
+        .collect();
+    if _start > 0 {
+        _snippet.insert(0, "...".to_string());
+    }
+    if _end < _words.len() {
+        _snippet.push("...".to_string());
+    }
+    (_hits, _snippet.join(" "))
+}
+
+
+/// # Text (Private Function)
+/// A description or a detection, `none` is no text.
+fn text(value: &str) -> &str
+{
+    match value {
+        "none" => "",
+        _ => value
+    }
+}
+
+
+/// # Is Vowel (Private Function)
+fn is_vowel(c: char) -> bool
+{
+    "aeiouy".contains(c)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document(id: &str, name: &str, description: &str, detection: &str) -> EnterpriseDescription
+    {
+        EnterpriseDescription {
+            kind:           "technique".to_string(),
+            id:             id.to_string(),
+            name:           name.to_string(),
+            description:    description.to_string(),
+            detection:      detection.to_string(),
+        }
+    }
+
+    fn stems(text: &str) -> Vec<String>
+    {
+        tokenize(text)
+    }
+
+    #[test]
+    fn tokenize_drops_stopwords_and_stems()
+    {
+        assert_eq!(tokenize("Scheduled Task XML"), vec!["schedul", "task", "xml"]);
+        assert_eq!(tokenize("The schedules of a T1053.005 task"), vec!["schedul", "t1053", "005", "task"]);
+        assert_eq!(tokenize("dumping LSASS memories, running processes"), vec!["dump", "lsass", "memory", "run", "process"]);
+        assert!(tokenize("it is on the, of -- and").is_empty());
+    }
+
+    #[test]
+    fn stem_keeps_short_and_mixed_words()
+    {
+        assert_eq!(stem("ssh"), "ssh");
+        assert_eq!(stem("Win32"), "win32");
+        assert_eq!(stem("accesses"), "access");
+        assert_eq!(stem("status"), "status");
+        assert_eq!(stem("quickly"), "quick");
+    }
+
+    #[test]
+    fn snippet_marks_the_matches()
+    {
+        let (_hits, _snippet) = snippet("Adversaries may dump the LSASS memory of a host.", &stems("lsass memory"));
+        assert_eq!(_hits, 2);
+        assert_eq!(_snippet, "Adversaries may dump the **LSASS** **memory** of a host.");
+        assert_eq!(snippet("", &stems("lsass")), (0, String::new()));
+    }
+
+    #[test]
+    fn snippet_begins_before_the_first_match()
+    {
+        let mut _words: Vec<String> = (0..60).map(|_i| format!("w{}", _i)).collect();
+        _words[30] = "lsass".to_string();
+        let (_hits, _snippet) = snippet(_words.join(" ").as_str(), &stems("lsass"));
+        assert_eq!(_hits, 1);
+        assert!(_snippet.starts_with("... w26 w27 w28 w29 **lsass** w31"));
+        assert!(_snippet.ends_with("w49 ..."));
+        assert_eq!(_snippet.split(' ').count(), SNIPPET_WORDS + 2);
+    }
+
+    #[test]
+    fn best_snippet_prefers_the_field_with_most_matches()
+    {
+        let _document = document("T1003", "OS Credential Dumping", "Adversaries may dump credentials.", "Monitor access to the LSASS memory.");
+        assert_eq!(best_snippet(&_document, &stems("lsass memory")).0, "detection");
+        let _document = document("M1026", "Privileged Account Management", "Manage accounts.", "none");
+        assert_eq!(best_snippet(&_document, &stems("lsass")), ("description", "Manage accounts.".to_string()));
+    }
+
+    #[test]
+    fn search_ranks_documents_with_every_word_first()
+    {
+        let _json = EnterpriseMatrixBreakdown {
+            descriptions: vec![
+                document("T1003", "OS Credential Dumping", "Adversaries may dump the LSASS memory.", "Monitor process access to LSASS."),
+                document("T1055", "Process Injection", "Adversaries may inject code into process memory.", "none"),
+                document("T1110", "Brute Force", "Adversaries may guess passwords.", "none"),
+            ],
+            ..EnterpriseMatrixBreakdown::default()
+        };
+        let _index = FullTextIndex::new(&_json);
+        assert!(!_index.is_empty());
+        let _matches = _index.search("lsass memory");
+        assert_eq!(_matches.iter().map(|_m| _m.id.as_str()).collect::<Vec<&str>>(), vec!["T1003", "T1055"]);
+        assert!(_matches[0].score > _matches[1].score);
+        assert_eq!(_matches[1].snippet, "Adversaries may inject code into process **memory.**");
+        assert!(_index.search("the of").is_empty());
+        assert!(FullTextIndex::new(&EnterpriseMatrixBreakdown::default()).is_empty());
+    }
+}
@@ -0,0 +1,320 @@
+use serde_derive::{ Deserialize, Serialize };
+
+use std::collections::HashSet;
+
+
+use crate::modules::parser::EnterpriseMatrixBreakdown;
+
+
+/// The lowest score of a result, below it a name is only a suggestion.
+pub const MATCH_SCORE: usize = 60;
+/// The lowest score of a `did you mean` suggestion.
+const SUGGESTION_SCORE: usize = 35;
+
+
+/// # Fuzzy Match
+/// A name of the matrix ranked against a search term - e.g., `credental dumping`
+/// is `T1003 OS Credential Dumping` with a score of `71`.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct FuzzyMatch {
+    pub score:      usize,
+    pub kind:       String,
+    pub id:         String,
+    pub name:       String,
+    /// The alias which matched, `none` when the name did
+    pub alias:      String,
+}
+
+
+/// # Mitre-Assistant: Fuzzy Module
+/// Typo tolerant matching of the names and aliases of the techniques, subtechniques,
+/// adversaries, malware, tools and mitigations of a baseline.
+///
+/// A name scores `100` when it or its ID is the search term, from `80` when it contains
+/// the search term and below when the words of the search term are close to its words.
+///
+/// ## Example
+/// ```ignore
+/// let _matches = FuzzyMatcher::new(&_json).search("powershel");
+/// let _suggestions = FuzzyMatcher::new(&_json).suggest("mimkatz", 5);
+/// ```
+pub struct FuzzyMatcher<'a> {
+    baseline:   &'a EnterpriseMatrixBreakdown,
+}
+impl<'a> FuzzyMatcher<'a> {
+    pub fn new(baseline: &'a EnterpriseMatrixBreakdown) -> Self
+    {
+        FuzzyMatcher { baseline }
+    }
+    /// # Fuzzy - Search
+    /// Returns the names scoring at least `MATCH_SCORE`, the best first.
+    pub fn search(&self, term: &str) -> Vec<FuzzyMatch>
+    {
+        self.rank(term, MATCH_SCORE)
+    }
+    /// # Fuzzy - Search All
+    /// Returns the names scoring at least `MATCH_SCORE` for any term of a list, each
+    /// name with its best score, the best first.
+    pub fn search_all(&self, terms: &[&str]) -> Vec<FuzzyMatch>
+    {
+        let mut _matches: Vec<FuzzyMatch> = terms.iter().flat_map(|_term| self.rank(_term, MATCH_SCORE)).collect();
+        _matches.sort_by(|_a, _b| _b.score.cmp(&_a.score).then_with(|| _a.id.cmp(&_b.id)));
+        let mut _seen: HashSet<(String, String)> = HashSet::new();
+        _matches.retain(|_m| _seen.insert((_m.kind.clone(), _m.id.clone())));
+        _matches
+    }
+    /// # Fuzzy - Suggest
+    /// Returns the closest names of a search term which found nothing, for a
+    /// `did you mean` hint.
+    pub fn suggest(&self, term: &str, limit: usize) -> Vec<String>
+    {
+        let mut _seen: HashSet<String> = HashSet::new();
+        self.rank(term, SUGGESTION_SCORE).into_iter()
+            .map(|_m| _m.name)
+            .filter(|_name| _seen.insert(_name.to_lowercase()))
+            .take(limit)
+            .collect()
+    }
+    /// # Fuzzy - Rank (Private Method)
+    fn rank(&self, term: &str, minimum: usize) -> Vec<FuzzyMatch>
+    {
+        let _json = self.baseline;
+        let mut _seen: HashSet<(&str, &str)> = HashSet::new();
+        let mut _matches: Vec<FuzzyMatch> = vec![];
+        let mut _add = |_kind: &'static str, _id: &'a str, _name: &str, _aliases: &[String]| {
+            if !_seen.insert((_kind, _id)) {
+                return;
+            }
+            let _score = match _id.eq_ignore_ascii_case(term.trim()) {
+                true => 100,
+                false => similarity(term, _name)
+            };
+            let mut _best = (_score, "none".to_string());
+            for _alias in _aliases.iter() {
+                let _score = similarity(term, _alias);
+                if _score > _best.0 {
+                    _best = (_score, _alias.to_string());
+                }
+            }
+            if _best.0 >= minimum {
+                _matches.push(FuzzyMatch {
+                    score:  _best.0,
+                    kind:   _kind.to_string(),
+                    id:     _id.to_string(),
+                    name:   _name.to_string(),
+                    alias:  _best.1,
+                });
+            }
+        };
+        for _item in _json.breakdown_techniques.platforms.iter() {
+            _add("technique", _item.tid.as_str(), _item.technique.as_str(), &[]);
+        }
+        for _item in _json.breakdown_subtechniques.platforms.iter() {
+            _add("subtechnique", _item.tid.as_str(), _item.technique.as_str(), &[]);
+        }
+        for _item in _json.breakdown_adversaries.iter().filter(|_a| !_a.is_revoked) {
+            _add("adversary", _item.group_id.as_str(), _item.name.as_str(), &_item.aliases);
+        }
+        for _item in _json.breakdown_malware.iter().filter(|_m| !_m.is_revoked) {
+            _add("malware", _item.malware_id.as_str(), _item.name.as_str(), &_item.aliases);
+        }
+        for _item in _json.breakdown_tools.iter().filter(|_t| !_t.is_revoked) {
+            _add("tool", _item.tool_id.as_str(), _item.name.as_str(), &_item.aliases);
+        }
+        for _item in _json.breakdown_mitigations.iter().filter(|_m| !_m.is_deprecated) {
+            _add("mitigation", _item.mitigation_id.as_str(), _item.name.as_str(), &[]);
+        }
+        _matches.sort_by(|_a, _b| _b.score.cmp(&_a.score).then_with(|| _a.id.cmp(&_b.id)));
+        _matches
+    }
+}
+
+
+/// # Similarity
+/// Scores a name against a search term from `0` to `100`, ignoring the case and
+/// the punctuation.
+///
+/// ```ignore
+/// assert_eq!(similarity("os credential dumping", "OS Credential Dumping"), 100);
+/// assert!(similarity("credental dumping", "OS Credential Dumping") >= MATCH_SCORE);
+/// ```
+pub fn similarity(term: &str, name: &str) -> usize
+{
+    let _term = normalize(term);
+    let _name = normalize(name);
+    if _term.is_empty() || _name.is_empty() {
+        return 0;
+    }
+    if _term == _name {
+        return 100;
+    }
+    if _term.replace(' ', "") == _name.replace(' ', "") {
+        return 95;
+    }
+    if _name.contains(_term.as_str()) {
+        return 80 + 19 * _term.len() / _name.len();
+    }
+    // Each word of the term is scored by the closest word of the name
+    let _words: Vec<&str> = _name.split(' ').collect();
+    let _closest: Vec<(f64, usize)> = _term.split(' ')
+        .map(|_w| {
+            let _best = _words.iter().map(|_n| closeness(_w, _n)).fold(0.0, f64::max);
+            (_best, _w.len())
+        })
+        .collect();
+    let _length: usize = _closest.iter().map(|(_, _l)| _l).sum();
+    let _by_words = _closest.iter().map(|(_c, _l)| _c * *_l as f64).sum::<f64>() / _length as f64;
+    let _by_text = closeness(_term.as_str(), _name.as_str());
+    (75.0 * _by_words.max(_by_text)).round() as usize
+}
+
+
+/// # Normalize (Private Function)
+/// Lowercases a name and keeps its words separated by a single space.
+fn normalize(text: &str) -> String
+{
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|_w| !_w.is_empty())
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+
+/// # Closeness (Private Function)
+/// `1.0` for the same words, `0.9` for a prefix of at least 3 letters, otherwise
+/// the share of the letters which don't need an edit.
+fn closeness(term: &str, word: &str) -> f64
+{
+    if term == word {
+        return 1.0;
+    }
+    if term.len() >= 3 && word.starts_with(term) {
+        return 0.9;
+    }
+    let _longest = term.chars().count().max(word.chars().count());
+    1.0 - distance(term, word) as f64 / _longest as f64
+}
+
+
+/// # Distance (Private Function)
+/// The Damerau-Levenshtein distance of two words, a swap of two letters is one edit.
+fn distance(a: &str, b: &str) -> usize
+{
+    let _a: Vec<char> = a.chars().collect();
+    let _b: Vec<char> = b.chars().collect();
+    let mut _rows: Vec<Vec<usize>> = vec![vec![0; _b.len() + 1]; _a.len() + 1];
+    for (_i, _row) in _rows.iter_mut().enumerate() {
+        _row[0] = _i;
+    }
+    for (_j, _cell) in _rows[0].iter_mut().enumerate() {
+        *_cell = _j;
+    }
+    for _i in 1..=_a.len() {
+        for _j in 1..=_b.len() {
+            let _cost = if _a[_i - 1] == _b[_j - 1] { 0 } else { 1 };
+            _rows[_i][_j] = (_rows[_i - 1][_j] + 1)
+                                .min(_rows[_i][_j - 1] + 1)
+                                .min(_rows[_i - 1][_j - 1] + _cost);
+            if _i > 1 && _j > 1 && _a[_i - 1] == _b[_j - 2] && _a[_i - 2] == _b[_j - 1] {
+                _rows[_i][_j] = _rows[_i][_j].min(_rows[_i - 2][_j - 2] + 1);
+            }
+        }
+    }
+    _rows[_a.len()][_b.len()]
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::enterprise::{ EnterpriseAdversary, EnterpriseTechnique, EnterpriseTool };
+
+    fn baseline() -> EnterpriseMatrixBreakdown
+    {
+        let mut _json = EnterpriseMatrixBreakdown::default();
+        for (_tid, _name) in [("T1003", "OS Credential Dumping"), ("T1110", "Brute Force"), ("T1555", "Credentials from Password Stores")].iter() {
+            let mut _technique = EnterpriseTechnique::new();
+            _technique.tid = _tid.to_string();
+            _technique.technique = _name.to_string();
+            _json.breakdown_techniques.platforms.push(_technique);
+        }
+        let mut _adversary = EnterpriseAdversary::new();
+        _adversary.group_id = "G0007".to_string();
+        _adversary.name = "APT28".to_string();
+        _adversary.aliases = vec!["fancy-bear".to_string(), "sofacy".to_string()];
+        _json.breakdown_adversaries.push(_adversary);
+        for (_id, _name, _revoked) in [("S0002", "Mimikatz", false), ("S0001", "Mimikatz", true)].iter() {
+            let mut _tool = EnterpriseTool::new();
+            _tool.tool_id = _id.to_string();
+            _tool.name = _name.to_string();
+            _tool.is_revoked = *_revoked;
+            _json.breakdown_tools.push(_tool);
+        }
+        _json
+    }
+
+    #[test]
+    fn similarity_ranks_exact_joined_and_contained_names()
+    {
+        assert_eq!(similarity("os-credential dumping", "OS Credential Dumping"), 100);
+        assert_eq!(similarity("oscredential dumping", "OS Credential Dumping"), 95);
+        assert_eq!(similarity("credential", "OS Credential Dumping"), 89);
+        assert_eq!(similarity("", "OS Credential Dumping"), 0);
+        assert_eq!(similarity("--", "OS Credential Dumping"), 0);
+    }
+
+    #[test]
+    fn similarity_tolerates_typos()
+    {
+        assert!(similarity("credental dumping", "OS Credential Dumping") >= MATCH_SCORE);
+        assert!(similarity("mimkatz", "Mimikatz") >= MATCH_SCORE);
+        assert!(similarity("powershel", "PowerShell") >= MATCH_SCORE);
+        assert!(similarity("mimkatz", "Brute Force") < SUGGESTION_SCORE);
+        assert_eq!(distance("abcd", "abdc"), 1);
+        assert_eq!(distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn search_ranks_the_best_first()
+    {
+        let _json = baseline();
+        let _matches = FuzzyMatcher::new(&_json).search("credential");
+        let _ids: Vec<&str> = _matches.iter().map(|_m| _m.id.as_str()).collect();
+        assert_eq!(_ids, vec!["T1003", "T1555"]);
+        assert!(_matches[0].score > _matches[1].score);
+        assert!(_matches.iter().all(|_m| _m.kind == "technique" && _m.alias == "none"));
+    }
+
+    #[test]
+    fn search_matches_aliases_and_skips_revoked()
+    {
+        let _json = baseline();
+        let _matches = FuzzyMatcher::new(&_json).search("fancy bear");
+        assert_eq!(_matches.len(), 1);
+        assert_eq!((_matches[0].id.as_str(), _matches[0].alias.as_str(), _matches[0].score), ("G0007", "fancy-bear", 100));
+        let _tools = FuzzyMatcher::new(&_json).search("mimikatz");
+        assert_eq!(_tools.iter().map(|_m| _m.id.as_str()).collect::<Vec<&str>>(), vec!["S0002"]);
+    }
+
+    #[test]
+    fn search_all_keeps_the_best_score_of_each_name()
+    {
+        let _json = baseline();
+        let _matches = FuzzyMatcher::new(&_json).search_all(&["credental dumping", "t1555", "os credential"]);
+        let _ranked: Vec<(&str, usize)> = _matches.iter().map(|_m| (_m.id.as_str(), _m.score)).collect();
+        assert_eq!(_ranked[0], ("T1555", 100));
+        assert_eq!(_ranked[1].0, "T1003");
+        assert_eq!(_ranked.iter().filter(|(_id, _)| *_id == "T1003").count(), 1);
+        assert_eq!(_ranked[1].1, similarity("os credential", "OS Credential Dumping"));
+        assert_eq!(FuzzyMatcher::new(&_json).search_all(&["credential"]), FuzzyMatcher::new(&_json).search("credential"));
+    }
+
+    #[test]
+    fn suggest_returns_distinct_names()
+    {
+        let _json = baseline();
+        assert_eq!(FuzzyMatcher::new(&_json).suggest("mimkatz", 5), vec!["Mimikatz".to_string()]);
+        assert!(FuzzyMatcher::new(&_json).suggest("zzzz", 5).is_empty());
+    }
+}
@@ -0,0 +1,355 @@
+use std::collections::{ BTreeMap, BTreeSet };
+
+use prettytable::{ Table, Row, Cell };
+use prettytable::format;
+use terminal_size::{ terminal_size, Width };
+
+
+use crate::modules::query::EnterpriseQuery;
+use crate::structs::enterprise::EnterpriseAdversary;
+use crate::modules::navigator::NavigatorLayer;
+use crate::utils::fshandler::FileHandler;
+use crate::structs::errors::AssistantError;
+use crate::structs::types::{ Platform, Tactic };
+use crate::utils::regexes::RegexPatternManager;
+
+
+/// The narrowest column, in characters.
+const MIN_COLUMN: usize = 18;
+/// The widest column, in characters.
+const MAX_COLUMN: usize = 36;
+/// The narrowest grid, one column with its borders and padding.
+pub const MIN_WIDTH: usize = MIN_COLUMN + 4;
+
+
+/// # Grid Technique
+/// A technique of a tactic column with the subtechniques shown under it.
+#[derive(Debug, Clone)]
+pub struct GridTechnique {
+    pub tid:            String,
+    pub technique:      String,
+    pub platforms:      Vec<Platform>,
+    pub subtechniques:  Vec<(String, String)>,
+}
+
+
+/// # Mitre-Assistant: Matrix Grid View
+/// Renders the techniques of the enterprise matrix in tactic columns, like the
+/// matrix of the ATT&CK website, instead of a row per technique.
+///
+/// The columns are laid out in the order of the kill chain and wrapped in bands
+/// that fit the width of the terminal.  The techniques can be filtered by platform
+/// or by the adversaries using them, and a set of techniques can be highlighted.
+///
+/// ## Example
+/// ```ignore
+/// let mut _grid = EnterpriseMatrixGrid::new("None")?;
+///
+/// _grid.filter_platform("windows")?;
+/// _grid.filter_adversaries("apt29")?;
+/// _grid.highlight(EnterpriseMatrixGrid::read_highlights("T1003,T1059")?);
+/// _grid.render(false, 0);
+/// ```
+pub struct EnterpriseMatrixGrid {
+    pub columns:        Vec<(Tactic, Vec<GridTechnique>)>,
+    pub highlights:     BTreeSet<String>,
+    pub used_by:        Option<BTreeSet<String>>,
+    adversaries:        Vec<EnterpriseAdversary>,
+    platforms:          BTreeMap<String, Vec<Platform>>,
+}
+impl EnterpriseMatrixGrid {
+    /// # Grid - Constructor
+    /// Loads the active techniques of the enterprise baseline of the `version`, use
+    /// `"None"` for the baseline created without a version.
+    pub fn new(version: &str) -> Result<Self, AssistantError>
+    {
+        let _query = EnterpriseQuery::load(version)?;
+        Ok(EnterpriseMatrixGrid::from_query(&_query))
+    }
+    /// # Grid - From Query
+    /// Lays out the techniques of a baseline already loaded.
+    pub fn from_query(_query: &EnterpriseQuery) -> Self
+    {
+        let _json = &_query.baseline;
+        let _names: BTreeMap<&str, &str> = _json.breakdown_subtechniques.platforms.iter()
+                                                .map(|_s| (_s.tid.as_str(), _s.technique.as_str()))
+                                                .collect();
+        let _platforms: BTreeMap<String, Vec<Platform>> = _json.breakdown_subtechniques.platforms.iter()
+                                                .map(|_s| (_s.tid.clone(), _s.platform.clone()))
+                                                .collect();
+        // The tactics of the baseline, in the order of the kill chain
+        let mut _tactics: BTreeMap<Tactic, BTreeMap<String, GridTechnique>> = BTreeMap::new();
+        for _row in _json.breakdown_techniques.platforms.iter() {
+            if _row.tactic != Tactic::None {
+                let mut _subtechniques: Vec<(String, String)> = _row.subtechniques.iter()
+                    .filter_map(|_s| _names.get(_s.as_str()).map(|_n| (_s.clone(), _n.to_string())))
+                    .collect();
+                _subtechniques.sort();
+                _tactics.entry(_row.tactic.clone()).or_default().insert(_row.tid.clone(), GridTechnique {
+                    tid:            _row.tid.clone(),
+                    technique:      _row.technique.clone(),
+                    platforms:      _row.platform.clone(),
+                    subtechniques:  _subtechniques,
+                });
+            }
+        }
+        let _columns: Vec<(Tactic, Vec<GridTechnique>)> = _tactics.into_iter()
+                                                .map(|(_tactic, _techniques)| (_tactic, EnterpriseMatrixGrid::sorted(_techniques)))
+                                                .collect();
+        let _adversaries = _query.adversaries().into_iter().cloned().collect();
+        EnterpriseMatrixGrid {
+            columns:        _columns,
+            highlights:     BTreeSet::new(),
+            used_by:        None,
+            adversaries:    _adversaries,
+            platforms:      _platforms,
+        }
+    }
+    /// # Grid - Sorted (Private Method)
+    /// Sorts the techniques of a column by name, like the ATT&CK website.
+    fn sorted(techniques: BTreeMap<String, GridTechnique>) -> Vec<GridTechnique>
+    {
+        let mut _techniques: Vec<GridTechnique> = techniques.into_values().collect();
+        _techniques.sort_by_key(|_t| _t.technique.to_lowercase());
+        _techniques
+    }
+    /// # Grid - Filter Platform
+    /// Keeps the techniques and subtechniques of a platform - e.g., `windows` or
+    /// `Azure AD`, an unknown platform is an error.
+    pub fn filter_platform(&mut self, platform: &str) -> Result<(), AssistantError>
+    {
+        let _platform = platform.parse::<Platform>()?;
+        let _subplatforms = &self.platforms;
+        for (_, _techniques) in self.columns.iter_mut() {
+            _techniques.retain(|_t| _t.platforms.contains(&_platform));
+            for _technique in _techniques.iter_mut() {
+                _technique.subtechniques.retain(|(_s, _)| _subplatforms.get(_s).is_some_and(|_p| _p.contains(&_platform)));
+            }
+        }
+        Ok(())
+    }
+    /// # Grid - Filter Adversaries
+    /// Keeps the techniques used by the adversaries of a comma separated list of names
+    /// or aliases, and the subtechniques they use.  A technique is kept when one of its
+    /// subtechniques is used.
+    pub fn filter_adversaries(&mut self, names: &str) -> Result<(), AssistantError>
+    {
+        let mut _used: BTreeSet<String> = BTreeSet::new();
+        for _name in names.split(',').map(|_n| _n.trim()).filter(|_n| !_n.is_empty()) {
+            match self.adversaries.iter().find(|_a| _a.is_named(_name)) {
+                Some(_adversary) => {
+                    _used.extend(_adversary.profile.techniques.items.iter().cloned());
+                    _used.extend(_adversary.profile.subtechniques.items.iter().cloned());
+                },
+                None => return Err(AssistantError::InvalidQuery(format!("Adversary Not Found In The Baseline: {} | List Them With: `search -m enterprise -t adversaries`", _name)))
+            }
+        }
+        for (_, _techniques) in self.columns.iter_mut() {
+            for _technique in _techniques.iter_mut() {
+                _technique.subtechniques.retain(|(_s, _)| _used.contains(_s));
+            }
+            _techniques.retain(|_t| _used.contains(&_t.tid) || !_t.subtechniques.is_empty());
+        }
+        self.used_by = Some(_used);
+        Ok(())
+    }
+    /// # Grid - Read Highlights
+    /// Reads a set of technique IDs from a comma separated list, a Navigator layer, or
+    /// any other file with technique IDs - e.g., a CSV of detection rules.
+    pub fn read_highlights(input: &str) -> Result<BTreeSet<String>, AssistantError>
+    {
+        if NavigatorLayer::is_layer(input) {
+            return Ok(NavigatorLayer::load(input)?.technique_ids(None).into_iter().collect());
+        }
+        let _content = match std::path::Path::new(input).is_file() {
+            true => FileHandler::read_to_string(input)?,
+            false => input.to_string()
+        };
+        let _pattern = RegexPatternManager::load_technique_ids();
+        Ok(_pattern.find_iter(_content.as_str()).map(|_m| _m.as_str().to_uppercase()).collect())
+    }
+    /// # Grid - Highlight
+    pub fn highlight(&mut self, tids: BTreeSet<String>)
+    {
+        self.highlights = tids;
+    }
+    /// # Grid - Terminal Width
+    /// Returns the width of the terminal, the `COLUMNS` variable when it is not a
+    /// terminal, or 120 columns.
+    pub fn terminal_width() -> usize
+    {
+        if let Some((Width(_w), _)) = terminal_size() {
+            return _w as usize;
+        }
+        std::env::var("COLUMNS").ok().and_then(|_c| _c.parse().ok()).unwrap_or(120)
+    }
+    /// # Grid - Truncate (Private Method)
+    fn truncate(text: &str, width: usize) -> String
+    {
+        match text.chars().count() > width {
+            true => format!("{}~", text.chars().take(width.saturating_sub(1)).collect::<String>()),
+            false => text.to_string()
+        }
+    }
+    /// # Grid - Render
+    /// Prints the tactic columns in bands that fit `width`, or the terminal width when
+    /// `width` is `0`.  The subtechniques are listed under their technique when
+    /// `_wants_subtechniques` is `true`, and always when filtering by adversary.
+    pub fn render(&self, _wants_subtechniques: bool, width: usize)
+    {
+        let _width = match width {
+            0 => EnterpriseMatrixGrid::terminal_width(),
+            _ => width
+        };
+        // Each column takes its width plus 3 characters of borders and padding
+        let _per_band = std::cmp::max(1, _width.saturating_sub(1) / (MIN_COLUMN + 3));
+        let _per_band = std::cmp::min(_per_band, std::cmp::max(1, self.columns.len()));
+        let _column = (_width.saturating_sub(1) / _per_band).saturating_sub(3).clamp(MIN_COLUMN, MAX_COLUMN);
+        let _wants_subtechniques = _wants_subtechniques || self.used_by.is_some();
+        let mut _shown: BTreeSet<&str> = BTreeSet::new();
+        let mut _highlighted: BTreeSet<&str> = BTreeSet::new();
+        for _band in self.columns.chunks(_per_band) {
+            let mut _table = Table::new();
+            _table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+            _table.set_titles(Row::new(_band.iter().map(|(_tactic, _techniques)| {
+                let _title = format!("{} ({})", _tactic.as_str().to_uppercase(), _techniques.len());
+                Cell::new(EnterpriseMatrixGrid::truncate(_title.as_str(), _column).as_str()).style_spec("bFY")
+            }).collect()));
+            // Each column is a list of lines, a technique then its subtechniques
+            let _lines: Vec<Vec<(String, bool)>> = _band.iter().map(|(_, _techniques)| {
+                let mut _column_lines: Vec<(String, bool)> = vec![];
+                for _t in _techniques.iter() {
+                    // A hidden subtechnique lights up its technique
+                    let _lit = self.highlights.contains(&_t.tid)
+                        || (!_wants_subtechniques && _t.subtechniques.iter().any(|(_s, _)| self.highlights.contains(_s)));
+                    _column_lines.push((format!("{} {}", _t.tid, _t.technique), _lit));
+                    if _wants_subtechniques {
+                        for (_sid, _name) in _t.subtechniques.iter() {
+                            let _lit = self.highlights.contains(_sid);
+                            let _suffix = _sid.split_once('.').map(|(_, _s)| _s).unwrap_or(_sid.as_str());
+                            _column_lines.push((format!("  .{} {}", _suffix, _name), _lit));
+                        }
+                    }
+                }
+                _column_lines
+            }).collect();
+            for (_, _techniques) in _band.iter() {
+                for _t in _techniques.iter() {
+                    _shown.insert(_t.tid.as_str());
+                    let _subtechniques = _t.subtechniques.iter().map(|(_s, _)| _s.as_str());
+                    for _tid in std::iter::once(_t.tid.as_str()).chain(_subtechniques) {
+                        if self.highlights.contains(_tid) {
+                            _highlighted.insert(_tid);
+                        }
+                    }
+                }
+            }
+            let _rows = _lines.iter().map(|_l| _l.len()).max().unwrap_or(0);
+            for _idx in 0.._rows {
+                _table.add_row(Row::new(_lines.iter().map(|_l| match _l.get(_idx) {
+                    Some((_text, true)) => Cell::new(EnterpriseMatrixGrid::truncate(_text, _column).as_str()).style_spec("bFG"),
+                    Some((_text, false)) => Cell::new(EnterpriseMatrixGrid::truncate(_text, _column).as_str()),
+                    None => Cell::new("")
+                }).collect()));
+            }
+            println!();
+            _table.printstd();
+        }
+        println!("\nTechniques: {}", _shown.len());
+        if !self.highlights.is_empty() {
+            let _missing: Vec<&str> = self.highlights.iter()
+                                                     .map(|_h| _h.as_str())
+                                                     .filter(|_h| !_highlighted.contains(_h))
+                                                     .collect();
+            println!("Highlighted: {} of {}", _highlighted.len(), self.highlights.len());
+            if !_missing.is_empty() {
+                println!("\n\t[ WARN ] Not In The Grid: {}", _missing.join(", "));
+            }
+        }
+        println!("\n");
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::parser::EnterpriseMatrixBreakdown;
+    use crate::structs::enterprise::EnterpriseTechnique;
+
+    fn row(tid: &str, name: &str, tactic: Tactic, platforms: &[Platform], subtechniques: &[&str]) -> EnterpriseTechnique
+    {
+        let mut _row = EnterpriseTechnique::new();
+        _row.tid = tid.to_string();
+        _row.technique = name.to_string();
+        _row.tactic = tactic;
+        _row.platform = platforms.to_vec();
+        _row.subtechniques = subtechniques.iter().map(|_s| _s.to_string()).collect();
+        _row
+    }
+
+    fn grid() -> EnterpriseMatrixGrid
+    {
+        let mut _baseline = EnterpriseMatrixBreakdown::default();
+        _baseline.breakdown_techniques.platforms = vec![
+            row("T1003", "OS Credential Dumping", Tactic::CredentialAccess, &[Platform::Windows, Platform::Linux], &["T1003.001", "T1003.008"]),
+            row("T1078", "Valid Accounts", Tactic::Persistence, &[Platform::Windows, Platform::AzureAd], &[]),
+            row("T1059", "Command and Scripting Interpreter", Tactic::Execution, &[Platform::Linux], &[]),
+        ];
+        _baseline.breakdown_subtechniques.platforms = vec![
+            row("T1003.001", "LSASS Memory", Tactic::CredentialAccess, &[Platform::Windows], &[]),
+            row("T1003.008", "/etc/passwd and /etc/shadow", Tactic::CredentialAccess, &[Platform::Linux], &[]),
+        ];
+        let mut _adversary = EnterpriseAdversary::new();
+        _adversary.name = "APT29".to_string();
+        _adversary.profile.subtechniques.items = vec!["T1003.001".to_string()];
+        _baseline.breakdown_adversaries = vec![_adversary];
+        EnterpriseMatrixGrid::from_query(&EnterpriseQuery::from_baseline(_baseline))
+    }
+
+    fn tids(grid: &EnterpriseMatrixGrid) -> Vec<&str>
+    {
+        grid.columns.iter().flat_map(|(_, _t)| _t.iter().map(|_t| _t.tid.as_str())).collect()
+    }
+
+    #[test]
+    fn from_query_lays_the_columns_in_the_kill_chain()
+    {
+        let _grid = grid();
+        let _tactics: Vec<&Tactic> = _grid.columns.iter().map(|(_t, _)| _t).collect();
+        assert_eq!(_tactics, vec![&Tactic::Execution, &Tactic::Persistence, &Tactic::CredentialAccess]);
+        assert_eq!(_grid.columns[2].1[0].subtechniques.len(), 2);
+    }
+
+    #[test]
+    fn filter_platform_parses_the_platform()
+    {
+        let mut _grid = grid();
+        _grid.filter_platform("Azure AD").unwrap();
+        assert_eq!(tids(&_grid), vec!["T1078"]);
+        let mut _grid = grid();
+        _grid.filter_platform("linux").unwrap();
+        assert_eq!(tids(&_grid), vec!["T1059", "T1003"]);
+        assert_eq!(_grid.columns[2].1[0].subtechniques, vec![("T1003.008".to_string(), "/etc/passwd and /etc/shadow".to_string())]);
+        assert!(grid().filter_platform("windoze").is_err());
+    }
+
+    #[test]
+    fn filter_adversaries_keeps_the_technique_of_a_used_subtechnique()
+    {
+        let mut _grid = grid();
+        _grid.filter_adversaries("apt29").unwrap();
+        assert_eq!(tids(&_grid), vec!["T1003"]);
+        assert_eq!(_grid.columns[2].1[0].subtechniques.len(), 1);
+        assert!(grid().filter_adversaries("apt29, unknown").is_err());
+    }
+
+    #[test]
+    fn read_highlights_finds_the_technique_ids()
+    {
+        let _tids = EnterpriseMatrixGrid::read_highlights("t1003.001, T1059,nothing").unwrap();
+        assert_eq!(_tids.into_iter().collect::<Vec<String>>(), vec!["T1003.001", "T1059"]);
+        assert_eq!(EnterpriseMatrixGrid::truncate("Valid Accounts", 6), "Valid~");
+        assert_eq!(EnterpriseMatrixGrid::truncate("Valid", 6), "Valid");
+    }
+}
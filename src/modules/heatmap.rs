@@ -0,0 +1,310 @@
+use std::collections::BTreeMap;
+
+use prettytable::{ Table, Row, Cell };
+use serde_derive::{ Deserialize, Serialize };
+
+
+use crate::modules::query::EnterpriseQuery;
+use crate::utils::fshandler::FileHandler;
+use crate::modules::navigator::NavigatorLayer;
+use crate::structs::errors::AssistantError;
+use crate::structs::types::Tactic;
+
+
+/// # Heatmap Score
+/// The number of groups, malware and tools using a technique or subtechnique.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HeatmapScore {
+    pub tactic:     Tactic,
+    pub tid:        String,
+    pub technique:  String,
+    pub groups:     usize,
+    pub malware:    usize,
+    pub tools:      usize,
+    pub score:      usize,
+}
+
+
+/// # Mitre-Assistant: Heatmap Module
+/// Scores each technique by how many groups, malware and tools use it, from the
+/// profiles correlated when the baseline is created, and renders a heatmap table
+/// per tactic - the hottest techniques first.
+///
+/// A technique is scored by the profiles that use the technique or any of its
+/// subtechniques, a subtechnique is scored by the profiles that use it.
+///
+/// ## Example
+/// ```ignore
+/// let _heatmap = EnterpriseHeatmap::new("None", false)?;
+///
+/// _heatmap.render("credential-access", 10, "None", "None")?;
+/// ```
+pub struct EnterpriseHeatmap {
+    pub scores:     Vec<HeatmapScore>,
+    pub max_score:  usize,
+}
+impl EnterpriseHeatmap {
+    /// # Heatmap - Constructor
+    /// Scores the active techniques of the enterprise baseline of the `version`, and
+    /// the subtechniques when `_wants_subtechniques` is `true`.
+    pub fn new(version: &str, _wants_subtechniques: bool) -> Result<Self, AssistantError>
+    {
+        let _query = EnterpriseQuery::load(version)?;
+        Ok(EnterpriseHeatmap::from_query(&_query, _wants_subtechniques))
+    }
+    /// # Heatmap - From Query
+    /// Scores the techniques of a baseline already loaded.
+    pub fn from_query(_query: &EnterpriseQuery, _wants_subtechniques: bool) -> Self
+    {
+        let _json = &_query.baseline;
+        // Counts of groups, malware and tools by technique ID, a profile using a
+        // subtechnique uses its technique too, but it is counted once
+        let mut _counts: BTreeMap<String, [usize; 3]> = BTreeMap::new();
+        let mut _count = |_kind: usize, _techniques: &[String], _subtechniques: &[String]| {
+            let mut _ids: Vec<&str> = _techniques.iter().chain(_subtechniques.iter()).map(|_t| _t.as_str()).collect();
+            _ids.extend(_subtechniques.iter().filter_map(|_t| _t.split('.').next()));
+            _ids.sort_unstable();
+            _ids.dedup();
+            for _tid in _ids {
+                _counts.entry(_tid.to_string()).or_default()[_kind] += 1;
+            }
+        };
+        for _adversary in _query.adversaries() {
+            _count(0, &_adversary.profile.techniques.items, &_adversary.profile.subtechniques.items);
+        }
+        for _malware in _json.breakdown_malware.iter().filter(|_m| !_m.is_revoked) {
+            _count(1, &_malware.profile.techniques.items, &_malware.profile.subtechniques.items);
+        }
+        for _tool in _json.breakdown_tools.iter().filter(|_t| !_t.is_revoked) {
+            _count(2, &_tool.profile.techniques.items, &_tool.profile.subtechniques.items);
+        }
+        let mut _rows: Vec<_> = _json.breakdown_techniques.platforms.iter().collect();
+        if _wants_subtechniques {
+            _rows.extend(_json.breakdown_subtechniques.platforms.iter());
+        }
+        let mut _scores: BTreeMap<(Tactic, String), HeatmapScore> = BTreeMap::new();
+        for _row in _rows {
+            let [_groups, _malware, _tools] = _counts.get(_row.tid.as_str()).cloned().unwrap_or_default();
+            if _row.tactic != Tactic::None {
+                _scores.insert((_row.tactic.clone(), _row.tid.clone()), HeatmapScore {
+                    tactic:     _row.tactic.clone(),
+                    tid:        _row.tid.clone(),
+                    technique:  _row.technique.clone(),
+                    groups:     _groups,
+                    malware:    _malware,
+                    tools:      _tools,
+                    score:      _groups + _malware + _tools,
+                });
+            }
+        }
+        // The tactics follow the order of the kill chain
+        let mut _scores: Vec<HeatmapScore> = _scores.into_values().collect();
+        _scores.sort_by(|_a, _b| _a.tactic.cmp(&_b.tactic).then(_b.score.cmp(&_a.score)).then(_a.tid.cmp(&_b.tid)));
+        let _max = _scores.iter().map(|_s| _s.score).max().unwrap_or(0);
+        EnterpriseHeatmap {
+            scores:     _scores,
+            max_score:  _max,
+        }
+    }
+    /// # Heatmap - Heat (Private Method)
+    /// Returns the style and the bar of a score, graded against the highest score
+    /// of the matrix so the tables of all tactics share the same scale.
+    fn heat(&self, score: usize) -> (&'static str, String)
+    {
+        let _ratio = match self.max_score {
+            0 => 0.0,
+            _ => score as f64 / self.max_score as f64
+        };
+        let _style = if _ratio >= 0.66 {
+            "bFR"
+        } else if _ratio >= 0.33 {
+            "FY"
+        } else if score > 0 {
+            "FG"
+        } else {
+            "FW"
+        };
+        let _bar = "#".repeat((_ratio * 20.0).ceil() as usize);
+        (_style, _bar)
+    }
+    /// # Heatmap - Select
+    /// Returns the scores of each tactic in the order of the kill chain, only the
+    /// `_wants_tactic` one when it is not `"None"` - e.g., `Credential Access` or
+    /// `credential-access`, and the `_wants_top` hottest when it is not `0`.
+    pub fn select(&self, _wants_tactic: &str, _wants_top: usize) -> Result<Vec<(&Tactic, Vec<&HeatmapScore>)>, AssistantError>
+    {
+        let _tactic = match _wants_tactic {
+            "None" => None,
+            _ => Some(_wants_tactic.parse::<Tactic>()?)
+        };
+        let mut _tactics: BTreeMap<&Tactic, Vec<&HeatmapScore>> = BTreeMap::new();
+        for _s in self.scores.iter().filter(|_s| _tactic.is_none() || _tactic.as_ref() == Some(&_s.tactic)) {
+            let _entry = _tactics.entry(&_s.tactic).or_default();
+            if _wants_top == 0 || _entry.len() < _wants_top {
+                _entry.push(_s);
+            }
+        }
+        if _tactics.is_empty() {
+            return Err(AssistantError::InvalidQuery(format!("Tactic Not Found In The Baseline: {} | List Them With: `search -m enterprise -t tactics`", _wants_tactic)));
+        }
+        Ok(_tactics.into_iter().collect())
+    }
+    /// # Heatmap - Render
+    /// Prints a heatmap table per tactic, or only the `_wants_tactic` one when it is
+    /// not `"None"`, keeping the `_wants_top` hottest techniques when it is not `0`.
+    /// The scores can be exported with `_wants_export` set to `csv` or `navigator`.
+    pub fn render(&self, _wants_tactic: &str, _wants_top: usize,
+        _wants_export: &str, _wants_outfile: &str) -> Result<(), AssistantError>
+    {
+        let _tactics = self.select(_wants_tactic, _wants_top)?;
+        if _wants_export == "navigator" {
+            return self.render_layer(&_tactics, _wants_outfile);
+        }
+        if _wants_export == "csv" {
+            let mut _table = Table::new();
+            _table.add_row(Row::new(vec![
+                Cell::new("TACTIC"), Cell::new("TID"), Cell::new("TECHNIQUE"),
+                Cell::new("GROUPS"), Cell::new("MALWARE"), Cell::new("TOOLS"), Cell::new("SCORE"),
+            ]));
+            for _s in _tactics.iter().flat_map(|(_, _s)| _s.iter()) {
+                _table.add_row(Row::new(vec![
+                    Cell::new(_s.tactic.as_str()),
+                    Cell::new(_s.tid.as_str()),
+                    Cell::new(_s.technique.as_str()),
+                    Cell::new(_s.groups.to_string().as_str()),
+                    Cell::new(_s.malware.to_string().as_str()),
+                    Cell::new(_s.tools.to_string().as_str()),
+                    Cell::new(_s.score.to_string().as_str()),
+                ]));
+            }
+            match _wants_outfile {
+                "None" => { _table.to_csv(std::io::stdout())?; },
+                _ => { _table.to_csv(FileHandler::open(_wants_outfile, "crw")?.handle)?; }
+            }
+            return Ok(());
+        }
+        for (_name, _scores) in _tactics.iter() {
+            let mut _table = Table::new();
+            _table.set_titles(Row::new(vec![
+                Cell::new(_name.as_str().to_uppercase().as_str()).style_spec("bFY"),
+                Cell::new("TID").style_spec("FW"),
+                Cell::new("TECHNIQUE").style_spec("FW"),
+                Cell::new("GROUPS").style_spec("FW"),
+                Cell::new("MALWARE").style_spec("FW"),
+                Cell::new("TOOLS").style_spec("FW"),
+                Cell::new("SCORE").style_spec("FW"),
+            ]));
+            for _s in _scores.iter() {
+                let (_style, _bar) = self.heat(_s.score);
+                _table.add_row(Row::new(vec![
+                    Cell::new(_bar.as_str()).style_spec(_style),
+                    Cell::new(_s.tid.as_str()).style_spec(_style),
+                    Cell::new(_s.technique.as_str()),
+                    Cell::new(_s.groups.to_string().as_str()),
+                    Cell::new(_s.malware.to_string().as_str()),
+                    Cell::new(_s.tools.to_string().as_str()),
+                    Cell::new(_s.score.to_string().as_str()).style_spec(_style),
+                ]));
+            }
+            println!("\n");
+            _table.printstd();
+        }
+        println!("\nHighest Score: {} | Score = Groups + Malware + Tools Using The Technique\n\n", self.max_score);
+        Ok(())
+    }
+    /// # Heatmap - Render Layer (Private Method)
+    /// A technique scored in many tactics is added once, its score is the same.
+    fn render_layer(&self, tactics: &[(&Tactic, Vec<&HeatmapScore>)], _wants_outfile: &str) -> Result<(), AssistantError>
+    {
+        let mut _layer = NavigatorLayer::new("Heatmap", "Techniques Scored By The Groups, Malware And Tools Using Them");
+        let mut _added: Vec<&str> = vec![];
+        for _s in tactics.iter().flat_map(|(_, _s)| _s.iter()) {
+            if _added.contains(&_s.tid.as_str()) {
+                continue;
+            }
+            _added.push(_s.tid.as_str());
+            _layer.add(_s.tid.as_str(), None, Some(_s.score as f64), "",
+                format!("Groups: {} | Malware: {} | Tools: {}", _s.groups, _s.malware, _s.tools).as_str());
+        }
+        _layer.add_gradient(0.0, self.max_score as f64);
+        if let Some(_gradient) = _layer.gradient.as_mut() {
+            _gradient.colors = vec!["#ffffff".to_string(), "#ffe766".to_string(), "#ff6666".to_string()];
+        }
+        _layer.save(_wants_outfile)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::parser::EnterpriseMatrixBreakdown;
+    use crate::structs::enterprise::{ EnterpriseAdversary, EnterpriseMalware, EnterpriseTechnique };
+
+    fn row(tid: &str, name: &str, tactic: Tactic) -> EnterpriseTechnique
+    {
+        let mut _row = EnterpriseTechnique::new();
+        _row.tid = tid.to_string();
+        _row.technique = name.to_string();
+        _row.tactic = tactic;
+        _row
+    }
+
+    fn heatmap(subtechniques: bool) -> EnterpriseHeatmap
+    {
+        let mut _baseline = EnterpriseMatrixBreakdown::default();
+        _baseline.breakdown_techniques.platforms = vec![
+            row("T1003", "OS Credential Dumping", Tactic::CredentialAccess),
+            row("T1110", "Brute Force", Tactic::CredentialAccess),
+            row("T1059", "Command and Scripting Interpreter", Tactic::Execution),
+        ];
+        _baseline.breakdown_subtechniques.platforms = vec![
+            row("T1003.001", "LSASS Memory", Tactic::CredentialAccess),
+        ];
+        let mut _group = EnterpriseAdversary::new();
+        _group.profile.techniques.items = vec!["T1003".to_string(), "T1059".to_string()];
+        _group.profile.subtechniques.items = vec!["T1003.001".to_string()];
+        let mut _malware = EnterpriseMalware::new();
+        _malware.profile.subtechniques.items = vec!["T1003.001".to_string()];
+        _baseline.breakdown_adversaries = vec![_group];
+        _baseline.breakdown_malware = vec![_malware];
+        EnterpriseHeatmap::from_query(&EnterpriseQuery::from_baseline(_baseline), subtechniques)
+    }
+
+    #[test]
+    fn from_query_counts_a_profile_once_per_technique()
+    {
+        let _heatmap = heatmap(true);
+        let _score = |tid: &str| _heatmap.scores.iter().find(|_s| _s.tid == tid).map(|_s| (_s.groups, _s.malware, _s.score));
+        assert_eq!(_score("T1003"), Some((1, 1, 2)));
+        assert_eq!(_score("T1003.001"), Some((1, 1, 2)));
+        assert_eq!(_score("T1110"), Some((0, 0, 0)));
+        assert_eq!(_heatmap.max_score, 2);
+        assert!(heatmap(false).scores.iter().all(|_s| !_s.tid.contains('.')));
+    }
+
+    #[test]
+    fn select_follows_the_kill_chain_and_keeps_the_top()
+    {
+        let _heatmap = heatmap(false);
+        let _tactics = _heatmap.select("None", 1).unwrap();
+        let _names: Vec<&Tactic> = _tactics.iter().map(|(_t, _)| *_t).collect();
+        assert_eq!(_names, vec![&Tactic::Execution, &Tactic::CredentialAccess]);
+        assert_eq!(_tactics[1].1.len(), 1);
+        assert_eq!(_tactics[1].1[0].tid, "T1003");
+    }
+
+    #[test]
+    fn select_parses_the_tactic_given_by_the_user()
+    {
+        let _heatmap = heatmap(false);
+        for _name in ["Credential Access", "credential-access", "CREDENTIAL_ACCESS"].iter() {
+            let _tactics = _heatmap.select(_name, 0).unwrap();
+            assert_eq!(_tactics.len(), 1);
+            assert_eq!(_tactics[0].0, &Tactic::CredentialAccess);
+            assert_eq!(_tactics[0].1.len(), 2);
+        }
+        assert!(_heatmap.select("credential", 0).is_err());
+        assert!(_heatmap.select("impact", 0).is_err());
+    }
+}
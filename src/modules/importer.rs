@@ -0,0 +1,167 @@
+use std::io::{ self, Read };
+
+
+use crate::utils::fshandler::FileHandler;
+
+use crate::structs::errors::AssistantError;
+
+
+/// # Mitre-Assistant: Matrix Importer Module
+/// The importer allows analysts on air-gapped networks to install a STIX bundle
+/// they already have on disk, or receive through `stdin`, into the config folder
+/// where the `baseline` subcommand expects it.
+///
+/// ## Example
+/// ```ignore
+/// let _mi = MatrixImporter::from_file("./enterprise-attack.json")?;
+///
+/// _mi.install("None")?;
+/// ```
+pub struct MatrixImporter {
+    pub domain:     String,
+    pub content:    String
+}
+impl MatrixImporter {
+    /// # Matrix Importer - From File
+    /// Reads and validates a local STIX bundle.  When the file path
+    /// is `-`, the bundle is read from `stdin`.
+    pub fn from_file(fp: &str) -> Result<Self, AssistantError>
+    {
+        if fp == "-" {
+            return MatrixImporter::from_stdin();
+        }
+        let _f = FileHandler::open(fp, "r")?;
+        let _bytes = _f.read_as_vecbytes(_f.size)?;
+        match String::from_utf8(_bytes) {
+            Ok(_content) => MatrixImporter::from_string(_content),
+            Err(_) => Err(AssistantError::Parse(format!("Content Is Not UTF-8 Text: {}", fp)))
+        }
+    }
+    /// # Matrix Importer - From Stdin
+    /// Reads and validates a STIX bundle piped into the program.
+    pub fn from_stdin() -> Result<Self, AssistantError>
+    {
+        let mut _content = String::new();
+        io::stdin().read_to_string(&mut _content)?;
+        MatrixImporter::from_string(_content)
+    }
+    fn from_string(content: String) -> Result<Self, AssistantError>
+    {
+        let _domain = MatrixImporter::validate(content.as_str())?;
+        Ok(MatrixImporter {
+            domain:  _domain,
+            content
+        })
+    }
+    /// # Matrix Importer - Validate
+    /// Inspects the content for a STIX bundle with ATT&CK objects and returns
+    /// the matrix domain it belongs to: `enterprise`, `mobile` or `pre-attack`.
+    ///
+    /// The domain is taken from the `x-mitre-matrix` object of the bundle, and when
+    /// it is absent, from the kill chain names of the techniques.
+    ///
+    /// ```ignore
+    /// let _domain = MatrixImporter::validate(_json.as_str())?;     // "enterprise"
+    /// ```
+    pub fn validate(content: &str) -> Result<String, AssistantError>
+    {
+        let _invalid = |reason: &str| AssistantError::Parse(reason.to_string());
+        let _json: serde_json::Value = match serde_json::from_str(content) {
+            Ok(_v) => _v,
+            Err(_e) => return Err(_invalid(format!("Content Is Not Valid JSON - {}", _e).as_str()))
+        };
+        if _json["type"].as_str() != Some("bundle") {
+            return Err(_invalid("Content Is Not A STIX Bundle - Missing `\"type\": \"bundle\"`"));
+        }
+        let _objects = match _json["objects"].as_array() {
+            Some(_o) if !_o.is_empty() => _o,
+            _ => return Err(_invalid("STIX Bundle Has No Objects"))
+        };
+        let mut _domain: Option<&str> = None;
+        for _object in _objects.iter() {
+            if _object["type"].as_str() == Some("x-mitre-matrix") {
+                if let Some(_refs) = _object["external_references"].as_array() {
+                    for _ref in _refs.iter() {
+                        _domain = match _ref["external_id"].as_str() {
+                            Some("enterprise-attack") => Some("enterprise"),
+                            Some("mobile-attack") => Some("mobile"),
+                            Some("pre-attack") => Some("pre-attack"),
+                            _ => _domain
+                        };
+                    }
+                }
+            }
+            if _domain.is_some() {
+                break;
+            }
+        }
+        if _domain.is_none() {
+            for _object in _objects.iter().filter(|_o| _o["type"].as_str() == Some("attack-pattern")) {
+                if let Some(_phases) = _object["kill_chain_phases"].as_array() {
+                    for _phase in _phases.iter() {
+                        _domain = match _phase["kill_chain_name"].as_str() {
+                            Some("mitre-attack") => Some("enterprise"),
+                            Some("mitre-mobile-attack") => Some("mobile"),
+                            Some("mitre-pre-attack") => Some("pre-attack"),
+                            _ => _domain
+                        };
+                    }
+                }
+                if _domain.is_some() {
+                    break;
+                }
+            }
+        }
+        match _domain {
+            Some(_d) => Ok(_d.to_string()),
+            None => Err(_invalid("STIX Bundle Is Not An ATT&CK Matrix - Unable To Detect The Domain"))
+        }
+    }
+    /// # Matrix Importer - Install
+    /// Writes the bundle into the `matrixes` folder under the name expected by
+    /// the `baseline` subcommand, optionally pinned to a `version`.
+    ///
+    /// Returns the filename of the installed matrix.
+    pub fn install(&self, version: &str) -> Result<String, AssistantError>
+    {
+        let _filename = FileHandler::versioned_name(self.domain.as_str(), version);
+        if FileHandler::check_for_config_folder()? {
+            FileHandler::write_download(_filename.as_str(), &self.content)?;
+        }
+        Ok(_filename)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BUNDLE: &str = r#"{"type": "bundle", "objects": [{"type": "attack-pattern", "kill_chain_phases": [{"kill_chain_name": "mitre-attack"}]}]}"#;
+
+    #[test]
+    fn validate_detects_the_domain()
+    {
+        assert_eq!(MatrixImporter::validate(BUNDLE).unwrap(), "enterprise");
+        let _mobile = r#"{"type": "bundle", "objects": [{"type": "x-mitre-matrix", "external_references": [{"external_id": "mobile-attack"}]}]}"#;
+        assert_eq!(MatrixImporter::validate(_mobile).unwrap(), "mobile");
+        for _invalid in ["{ not json", r#"{"type": "report"}"#, r#"{"type": "bundle", "objects": []}"#, r#"{"type": "bundle", "objects": [{"type": "tool"}]}"#].iter() {
+            assert!(matches!(MatrixImporter::validate(_invalid), Err(AssistantError::Parse(_))));
+        }
+    }
+
+    #[test]
+    fn install_replaces_the_existing_content()
+    {
+        let _source = std::env::temp_dir().join(format!("mitre-assistant-import-{}.json", std::process::id()));
+        std::fs::write(&_source, BUNDLE).unwrap();
+        let _mi = MatrixImporter::from_file(_source.to_str().unwrap());
+        std::fs::remove_file(&_source).unwrap();
+        let _mi = _mi.unwrap();
+        assert_eq!(_mi.domain, "enterprise");
+        FileHandler::write_download("enterprise-test-import.json", &"x".repeat(BUNDLE.len() * 4)).unwrap();
+        assert_eq!(_mi.install("test-import").unwrap(), "enterprise-test-import.json");
+        let _installed = FileHandler::read_resource("matrixes", "enterprise-test-import.json").unwrap();
+        assert_eq!(_installed, BUNDLE);
+    }
+}
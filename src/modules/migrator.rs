@@ -0,0 +1,371 @@
+use std::collections::{ HashMap, HashSet };
+use std::path::Path;
+
+use prettytable::{ Table, Row, Cell };
+use prettytable::csv::{ ReaderBuilder, Writer };
+use regex::{ Captures, Regex };
+use serde_derive::{ Deserialize, Serialize };
+
+
+use crate::modules::parser::EnterpriseMatrixBreakdown;
+use crate::utils::fshandler::FileHandler;
+use crate::structs::errors::AssistantError;
+use crate::utils::regexes::RegexPatternManager;
+
+
+/// # Globals
+/// Column, or key, added to the migrated mappings of the items needing a manual review.
+static _REVIEW_FIELD: &str = "migration_review";
+
+
+/// # Mapping Migration
+/// A technique ID of the mappings that was rewritten, or that needs a manual review.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MappingMigration {
+    pub location:   String,
+    pub before:     String,
+    pub after:      String,
+    pub status:     String,
+    pub review:     bool,
+    pub note:       String,
+}
+
+
+/// # Mitre-Assistant: Mapping Migrator Module
+/// Rewrites the technique IDs of a CSV or JSON file of mappings - e.g., the tags of
+/// detection rules, against the current baseline.
+///
+/// Revoked techniques are replaced by the technique that revoked them, following the
+/// `revoked-by` relationships of the matrix.  IDs that cannot be migrated with confidence -
+/// deprecated techniques, techniques revoked by more than one technique, or unknown IDs,
+/// are kept and flagged for a manual review.
+///
+/// ## Example
+/// ```ignore
+/// let _mm = MappingMigrator::new("None")?;
+///
+/// _mm.migrate("./rules.csv", "./rules.migrated.csv", "technique_id")?;
+/// ```
+pub struct MappingMigrator {
+    active:         HashSet<String>,
+    revoked:        HashSet<String>,
+    revoked_by:     HashMap<String, Vec<String>>,
+    deprecated:     HashSet<String>,
+    pattern:        Regex,
+    pub migrations: Vec<MappingMigration>
+}
+impl MappingMigrator {
+    /// # Mapping Migrator - Constructor
+    /// Loads the enterprise baseline of the `version`, use `"None"` for the
+    /// baseline created without a version.
+    pub fn new(version: &str) -> Result<Self, AssistantError>
+    {
+        let _baseline = FileHandler::versioned_name("baseline-enterprise", version);
+        let _content = match FileHandler::read_resource("baselines", _baseline.as_str()) {
+            Some(_c) => _c,
+            None => return Err(AssistantError::MissingResource(format!("Baseline Not Found: {} | Create It With: `baseline -m enterprise`", _baseline)))
+        };
+        let _json: EnterpriseMatrixBreakdown = serde_json::from_str(_content.as_str())?;
+        let mut _revoked_by: HashMap<String, Vec<String>> = HashMap::new();
+        for (_old, _new) in _json.revoked_by.iter() {
+            _revoked_by.entry(_old.clone()).or_default().push(_new.clone());
+        }
+        for _targets in _revoked_by.values_mut() {
+            _targets.sort();
+        }
+        Ok(MappingMigrator {
+            active:         _json.uniques_techniques.iter().chain(_json.uniques_subtechniques.iter()).cloned().collect(),
+            revoked:        _json.revoked_techniques.iter().map(|(_tid, _)| _tid.clone()).collect(),
+            revoked_by:     _revoked_by,
+            deprecated:     _json.deprecated_techniques.iter().map(|(_tid, _)| _tid.clone()).collect(),
+            pattern:        RegexPatternManager::load_technique_ids(),
+            migrations:     vec![]
+        })
+    }
+    /// # Mapping Migrator - Resolve
+    /// Returns the current ID of a technique ID, the status of the migration,
+    /// whether it needs a manual review, and a note for the analyst.
+    ///
+    /// ```ignore
+    /// let (_tid, _status, _review, _note) = _mm.resolve("T1081");     // T1552.001, migrated, false
+    /// ```
+    pub fn resolve(&self, tid: &str) -> (String, &'static str, bool, String)
+    {
+        let _tid = tid.to_uppercase();
+        if self.active.contains(&_tid) {
+            return (_tid, "current", false, String::new());
+        }
+        if self.deprecated.contains(&_tid) {
+            return (_tid, "deprecated", true, "Deprecated Without Replacement".to_string());
+        }
+        if self.revoked.contains(&_tid) {
+            // Follow the chain of revocations up to an active technique
+            let mut _current = _tid.clone();
+            let mut _seen: HashSet<String> = HashSet::new();
+            while let Some(_targets) = self.revoked_by.get(&_current) {
+                if !_seen.insert(_current.clone()) {
+                    break;
+                }
+                if _targets.len() > 1 {
+                    return (_tid, "split", true, format!("Revoked By Many: {}", _targets.join("|")));
+                }
+                _current = _targets[0].clone();
+            }
+            if self.active.contains(&_current) {
+                return (_current.clone(), "migrated", false, format!("Revoked By {}", _current));
+            }
+            return (_tid, "revoked", true, "Revoked Without Active Replacement".to_string());
+        }
+        if let Some(_parent) = _tid.split('.').next().filter(|_p| _tid.contains('.')) {
+            if self.active.contains(_parent) {
+                return (_parent.to_string(), "parent", true, format!("Unknown Subtechnique, Mapped To Its Technique {}", _parent));
+            }
+        }
+        (_tid, "unknown", true, "Unknown Technique ID".to_string())
+    }
+    /// # Mapping Migrator - Rewrite (Private Method)
+    /// Rewrites every technique ID of a value and records its migrations.
+    /// Returns the new value, and the notes of the IDs needing a review.
+    fn rewrite(&mut self, value: &str, location: &str) -> (String, Vec<String>)
+    {
+        let mut _resolved: Vec<MappingMigration> = vec![];
+        let _value = self.pattern.replace_all(value, |_caps: &Captures| {
+            let (_after, _status, _review, _note) = self.resolve(&_caps[0]);
+            if _status != "current" {
+                _resolved.push(MappingMigration {
+                    location:   location.to_string(),
+                    before:     _caps[0].to_string(),
+                    after:      _after.clone(),
+                    status:     _status.to_string(),
+                    review:     _review,
+                    note:       _note
+                });
+            }
+            _after
+        }).to_string();
+        let _notes = _resolved.iter()
+                              .filter(|_m| _m.review)
+                              .map(|_m| format!("{}: {}", _m.before, _m.note))
+                              .collect();
+        self.migrations.extend(_resolved);
+        (_value, _notes)
+    }
+    /// # Mapping Migrator - Migrate
+    /// Reads the mappings of `input` and writes them migrated to `output`, in the
+    /// same format - JSON when the file extension is `.json`, CSV otherwise.
+    ///
+    /// When `field` is `"None"`, the technique IDs of every column or key are migrated,
+    /// otherwise only those of the `field` column or key.  Items needing a review get a
+    /// `migration_review` column or key with the notes for the analyst.
+    pub fn migrate(&mut self, input: &str, output: &str, field: &str) -> Result<(), AssistantError>
+    {
+        let _is_json = Path::new(input).extension().map(|_e| _e.eq_ignore_ascii_case("json")).unwrap_or(false);
+        let _content = match _is_json {
+            true => self.migrate_json(FileHandler::read_to_string(input)?.as_str(), field)?,
+            false => self.migrate_csv(FileHandler::read_to_string(input)?.as_str(), field)?
+        };
+        FileHandler::write_atomic(output, &_content)?;
+        println!("\n\t[ INFO ] Migrated Mappings Saved: {}\n", output);
+        Ok(())
+    }
+    /// # Mapping Migrator - Migrate CSV (Private Method)
+    fn migrate_csv(&mut self, content: &str, field: &str) -> Result<String, AssistantError>
+    {
+        let mut _reader = ReaderBuilder::new().flexible(true).from_reader(content.as_bytes());
+        let mut _headers: Vec<String> = _reader.headers()?.iter().map(|_h| _h.to_string()).collect();
+        let _column = match field {
+            "None" => None,
+            _ => match _headers.iter().position(|_h| _h == field) {
+                Some(_c) => Some(_c),
+                None => return Err(AssistantError::InvalidQuery(format!("Column Not Found In Mappings: {}", field)))
+            }
+        };
+        let _review_column = _headers.iter().position(|_h| _h == _REVIEW_FIELD);
+        if _review_column.is_none() {
+            _headers.push(_REVIEW_FIELD.to_string());
+        }
+        let mut _writer = Writer::from_writer(vec![]);
+        _writer.write_record(&_headers)?;
+        for (_idx, _record) in _reader.records().enumerate() {
+            let _location = format!("row {}", _idx + 1);
+            let mut _row: Vec<String> = _record?.iter().map(|_v| _v.to_string()).collect();
+            let mut _notes: Vec<String> = vec![];
+            for (_c, _value) in _row.iter_mut().enumerate() {
+                if Some(_c) == _review_column || (_column.is_some() && _column != Some(_c)) {
+                    continue;
+                }
+                let (_new, _n) = self.rewrite(_value, _location.as_str());
+                *_value = _new;
+                _notes.extend(_n);
+            }
+            let _review = _review_column.unwrap_or(_headers.len() - 1);
+            _row.resize(_headers.len(), String::new());
+            _row[_review] = _notes.join("; ");
+            _writer.write_record(&_row)?;
+        }
+        let _bytes = _writer.into_inner().map_err(|_e| AssistantError::Io(_e.into_error()))?;
+        Ok(String::from_utf8_lossy(&_bytes).to_string())
+    }
+    /// # Mapping Migrator - Migrate JSON (Private Method)
+    /// The mappings are either an array of items, or a single item.
+    fn migrate_json(&mut self, content: &str, field: &str) -> Result<String, AssistantError>
+    {
+        let mut _json: serde_json::Value = serde_json::from_str(content)?;
+        match _json.as_array_mut() {
+            Some(_items) => {
+                for (_idx, _item) in _items.iter_mut().enumerate() {
+                    self.migrate_json_item(_item, format!("item {}", _idx + 1).as_str(), field);
+                }
+            },
+            None => self.migrate_json_item(&mut _json, "item 1", field)
+        }
+        Ok(serde_json::to_string_pretty(&_json)?)
+    }
+    /// # Mapping Migrator - Migrate JSON Item (Private Method)
+    fn migrate_json_item(&mut self, item: &mut serde_json::Value, location: &str, field: &str)
+    {
+        let mut _notes: Vec<String> = vec![];
+        self.migrate_json_value(item, location, field, field == "None", &mut _notes);
+        if let Some(_object) = item.as_object_mut() {
+            match _notes.is_empty() {
+                true => _object.remove(_REVIEW_FIELD),
+                false => _object.insert(_REVIEW_FIELD.to_string(), serde_json::Value::from(_notes.join("; ")))
+            };
+        }
+    }
+    /// # Mapping Migrator - Migrate JSON Value (Private Method)
+    /// Walks the value, rewriting the strings found under the `field` key,
+    /// or every string when `in_field` is already `true`.
+    fn migrate_json_value(&mut self, value: &mut serde_json::Value, location: &str, field: &str,
+        in_field: bool, notes: &mut Vec<String>)
+    {
+        match value {
+            serde_json::Value::String(_s) if in_field => {
+                let (_new, _n) = self.rewrite(_s.as_str(), location);
+                *_s = _new;
+                notes.extend(_n);
+            },
+            serde_json::Value::Array(_items) => {
+                for _item in _items.iter_mut() {
+                    self.migrate_json_value(_item, location, field, in_field, notes);
+                }
+            },
+            serde_json::Value::Object(_object) => {
+                for (_key, _item) in _object.iter_mut() {
+                    if _key == _REVIEW_FIELD {
+                        continue;
+                    }
+                    self.migrate_json_value(_item, location, field, in_field || _key == field, notes);
+                }
+            },
+            _ => ()
+        }
+    }
+    /// # Mapping Migrator - Render
+    /// Prints the rewritten IDs and the IDs needing a manual review.
+    pub fn render(&self)
+    {
+        let mut _table = Table::new();
+        _table.add_row(Row::new(vec![
+            Cell::new("INDEX").style_spec("FW"),
+            Cell::new("LOCATION").style_spec("FW"),
+            Cell::new("BEFORE").style_spec("FW"),
+            Cell::new("AFTER").style_spec("FG"),
+            Cell::new("STATUS").style_spec("FW"),
+            Cell::new("REVIEW").style_spec("FY"),
+            Cell::new("NOTE").style_spec("FW"),
+        ]));
+        for (_idx, _m) in self.migrations.iter().enumerate() {
+            let _style = match _m.review {
+                true => "FY",
+                false => "FG"
+            };
+            _table.add_row(Row::new(vec![
+                Cell::new((_idx + 1).to_string().as_str()),
+                Cell::new(_m.location.as_str()),
+                Cell::new(_m.before.as_str()),
+                Cell::new(_m.after.as_str()).style_spec(_style),
+                Cell::new(_m.status.as_str()),
+                Cell::new(if _m.review { "yes" } else { "no" }).style_spec(_style),
+                Cell::new(_m.note.replace("|", "\n").as_str()),
+            ]));
+        }
+        let _reviews = self.migrations.iter().filter(|_m| _m.review).count();
+        println!("\n\nMigrated IDs: {} | Manual Reviews: {}\n", self.migrations.len() - _reviews, _reviews);
+        _table.printstd();
+        println!("\n\n");
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn migrator() -> MappingMigrator
+    {
+        let _set = |_ids: &[&str]| _ids.iter().map(|_i| _i.to_string()).collect::<HashSet<String>>();
+        let mut _revoked_by: HashMap<String, Vec<String>> = HashMap::new();
+        _revoked_by.insert("T1081".to_string(), vec!["T1552.001".to_string()]);
+        _revoked_by.insert("T1126".to_string(), vec!["T1009".to_string()]);
+        _revoked_by.insert("T1009".to_string(), vec!["T1001.003".to_string()]);
+        _revoked_by.insert("T1099".to_string(), vec!["T1070.006".to_string(), "T1562.001".to_string()]);
+        _revoked_by.insert("T1150".to_string(), vec!["T1200".to_string()]);
+        MappingMigrator {
+            active:         _set(&["T1001", "T1001.003", "T1003", "T1003.001", "T1552.001", "T1070.006", "T1562.001"]),
+            revoked:        _set(&["T1081", "T1126", "T1009", "T1099", "T1150"]),
+            revoked_by:     _revoked_by,
+            deprecated:     _set(&["T1043"]),
+            pattern:        RegexPatternManager::load_technique_ids(),
+            migrations:     vec![]
+        }
+    }
+
+    #[test]
+    fn resolve_keeps_current_ids()
+    {
+        let _mm = migrator();
+        assert_eq!(_mm.resolve("t1003"), ("T1003".to_string(), "current", false, String::new()));
+        assert_eq!(_mm.resolve("T1003.001").1, "current");
+    }
+
+    #[test]
+    fn resolve_follows_revocations()
+    {
+        let _mm = migrator();
+        assert_eq!(_mm.resolve("T1081"), ("T1552.001".to_string(), "migrated", false, "Revoked By T1552.001".to_string()));
+        assert_eq!(_mm.resolve("T1126").0, "T1001.003");
+    }
+
+    #[test]
+    fn resolve_flags_a_split_only_for_many_replacements()
+    {
+        let _mm = migrator();
+        let (_tid, _status, _review, _note) = _mm.resolve("T1099");
+        assert_eq!((_tid.as_str(), _status, _review), ("T1099", "split", true));
+        assert_eq!(_note, "Revoked By Many: T1070.006|T1562.001");
+        assert_eq!(_mm.resolve("T1001").1, "current");
+    }
+
+    #[test]
+    fn resolve_flags_the_ids_needing_a_review()
+    {
+        let _mm = migrator();
+        assert_eq!(_mm.resolve("T1043").1, "deprecated");
+        assert_eq!(_mm.resolve("T1150").1, "revoked");
+        assert_eq!(_mm.resolve("T1003.999"), ("T1003".to_string(), "parent", true,
+                   "Unknown Subtechnique, Mapped To Its Technique T1003".to_string()));
+        assert_eq!(_mm.resolve("T9999").1, "unknown");
+        assert!(_mm.resolve("T9999").2);
+    }
+
+    #[test]
+    fn rewrite_records_the_migrations()
+    {
+        let mut _mm = migrator();
+        let (_value, _notes) = _mm.rewrite("T1081, T1003 and T1043", "row 1");
+        assert_eq!(_value, "T1552.001, T1003 and T1043");
+        assert_eq!(_notes, vec!["T1043: Deprecated Without Replacement".to_string()]);
+        assert_eq!(_mm.migrations.len(), 2);
+    }
+}
@@ -1,3 +1,17 @@
 pub mod webclient;
 pub mod searcher;
-pub mod parser;
\ No newline at end of file
+pub mod parser;
+pub mod importer;
+pub mod differ;
+pub mod migrator;
+pub mod coverage;
+pub mod navigator;
+pub mod gridview;
+pub mod heatmap;
+pub mod query;
+pub mod expression;
+pub mod fuzzy;
+pub mod fulltext;
+pub mod pattern;
+pub mod shell;
+pub mod detail;
@@ -0,0 +1,303 @@
+use serde_derive::{ Deserialize, Serialize };
+
+
+use crate::utils::fshandler::FileHandler;
+use crate::structs::errors::AssistantError;
+
+
+/// # Navigator Layer Versions
+#[derive(Debug, Deserialize, Serialize)]
+pub struct NavigatorVersions {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub attack:     String,
+    #[serde(default)]
+    pub navigator:  String,
+    #[serde(default)]
+    pub layer:      String,
+}
+impl Default for NavigatorVersions {
+    fn default() -> Self
+    {
+        NavigatorVersions {
+            attack:     "".to_string(),
+            navigator:  "4.1".to_string(),
+            layer:      "4.1".to_string(),
+        }
+    }
+}
+
+
+/// # Navigator Layer Technique
+/// A technique or subtechnique of a layer.  Without a `tactic` the Navigator
+/// applies the entry to every tactic of the technique.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NavigatorTechnique {
+    #[serde(rename = "techniqueID")]
+    pub technique_id:       String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tactic:             Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score:              Option<f64>,
+    #[serde(default)]
+    pub color:              String,
+    #[serde(default)]
+    pub comment:            String,
+    #[serde(default = "NavigatorTechnique::enabled")]
+    pub enabled:            bool,
+    #[serde(default)]
+    pub show_subtechniques: bool,
+}
+impl NavigatorTechnique {
+    fn enabled() -> bool
+    {
+        true
+    }
+}
+
+
+/// # Navigator Layer Gradient
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NavigatorGradient {
+    pub colors:     Vec<String>,
+    pub min_value:  f64,
+    pub max_value:  f64,
+}
+
+
+/// # Navigator Layer Legend Item
+#[derive(Debug, Deserialize, Serialize)]
+pub struct NavigatorLegendItem {
+    pub label:  String,
+    pub color:  String,
+}
+
+
+/// # Mitre-Assistant: Navigator Module
+/// Reads and writes the layer files of the ATT&CK Navigator, so that any set of
+/// techniques found by the assistant can be opened in the Navigator, and a layer
+/// made in the Navigator can be used as a technique list by the assistant.
+///
+/// Only the fields used by the assistant are kept, the other fields of a layer
+/// are ignored when it is imported.
+///
+/// ## Example
+/// ```ignore
+/// let mut _layer = NavigatorLayer::new("Coverage", "Techniques Covered By Our Rules");
+///
+/// _layer.add("T1003.001", None, Some(2.0), "#8ec843", "Rules: 2");
+/// _layer.add_legend("Covered", "#8ec843");
+/// _layer.save("coverage-layer.json")?;
+///
+/// let _ids = NavigatorLayer::load("coverage-layer.json")?.technique_ids(None);
+/// ```
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NavigatorLayer {
+    #[serde(default)]
+    pub name:           String,
+    #[serde(default)]
+    pub versions:       NavigatorVersions,
+    #[serde(default)]
+    pub domain:         String,
+    #[serde(default)]
+    pub description:    String,
+    #[serde(default)]
+    pub techniques:     Vec<NavigatorTechnique>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gradient:       Option<NavigatorGradient>,
+    #[serde(default)]
+    pub legend_items:   Vec<NavigatorLegendItem>,
+}
+impl NavigatorLayer {
+    /// # Navigator - Constructor
+    /// Creates an empty layer of the enterprise domain.
+    pub fn new(name: &str, description: &str) -> Self
+    {
+        NavigatorLayer {
+            name:           name.to_string(),
+            versions:       NavigatorVersions::default(),
+            domain:         "enterprise-attack".to_string(),
+            description:    description.to_string(),
+            techniques:     vec![],
+            gradient:       None,
+            legend_items:   vec![],
+        }
+    }
+    /// # Navigator - Add
+    /// Adds a technique or subtechnique, use an empty `color` to let the gradient
+    /// color the technique by its `score`.
+    pub fn add(&mut self, tid: &str, tactic: Option<&str>, score: Option<f64>, color: &str, comment: &str)
+    {
+        self.techniques.push(NavigatorTechnique {
+            technique_id:       tid.to_uppercase(),
+            tactic:             tactic.map(|_t| _t.to_string()),
+            score,
+            color:              color.to_string(),
+            comment:            comment.to_string(),
+            enabled:            true,
+            show_subtechniques: false,
+        });
+    }
+    /// # Navigator - Add Gradient
+    /// Colors the techniques without a color from red, at `min_value`, to green, at `max_value`.
+    pub fn add_gradient(&mut self, min_value: f64, max_value: f64)
+    {
+        self.gradient = Some(NavigatorGradient {
+            colors:     vec!["#ff6666".to_string(), "#ffe766".to_string(), "#8ec843".to_string()],
+            min_value,
+            max_value,
+        });
+    }
+    /// # Navigator - Add Legend
+    pub fn add_legend(&mut self, label: &str, color: &str)
+    {
+        self.legend_items.push(NavigatorLegendItem {
+            label:  label.to_string(),
+            color:  color.to_string(),
+        });
+    }
+    /// # Navigator - Save
+    /// Saves the layer as JSON, printed to `stdout` when `fp` is `"None"`.
+    pub fn save(&mut self, fp: &str) -> Result<(), AssistantError>
+    {
+        // The Navigator hides subtechniques unless their parent is expanded
+        let _parents: Vec<String> = self.techniques.iter()
+                                                   .filter(|_t| _t.technique_id.contains('.'))
+                                                   .filter_map(|_t| _t.technique_id.split('.').next().map(|_p| _p.to_string()))
+                                                   .collect();
+        for _technique in self.techniques.iter_mut() {
+            _technique.show_subtechniques = _parents.contains(&_technique.technique_id);
+        }
+        let _json = serde_json::to_string_pretty(&self)?;
+        match fp {
+            "None" => println!("{}", _json),
+            _ => FileHandler::open(fp, "crw")?.write(&_json)?
+        }
+        Ok(())
+    }
+    /// # Navigator - Load
+    /// Reads a layer exported by the ATT&CK Navigator.
+    pub fn load(fp: &str) -> Result<Self, AssistantError>
+    {
+        let _content = FileHandler::read_to_string(fp)?;
+        let _layer: NavigatorLayer = match serde_json::from_str(_content.as_str()) {
+            Ok(_l) => _l,
+            Err(_e) => return Err(AssistantError::Parse(format!("Not A Navigator Layer: {} | {}", fp, _e)))
+        };
+        if !_layer.domain.is_empty() && _layer.domain != "enterprise-attack" && _layer.domain != "mitre-enterprise" {
+            return Err(AssistantError::Parse(format!("Layer Domain Is Not Supported: {} | Use An Enterprise Layer", _layer.domain)));
+        }
+        Ok(_layer)
+    }
+    /// # Navigator - Technique IDs
+    /// Returns the sorted IDs of the enabled techniques and subtechniques, keeping
+    /// only those scored at least `min_score` when it is given.
+    pub fn technique_ids(&self, min_score: Option<f64>) -> Vec<String>
+    {
+        let mut _ids: Vec<String> = self.techniques.iter()
+                                                   .filter(|_t| _t.enabled)
+                                                   .filter(|_t| match (min_score, _t.score) {
+                                                       (Some(_min), Some(_score)) => _score >= _min,
+                                                       (Some(_), None) => false,
+                                                       _ => true
+                                                   })
+                                                   .map(|_t| _t.technique_id.to_uppercase())
+                                                   .collect();
+        _ids.sort();
+        _ids.dedup();
+        _ids
+    }
+    /// # Navigator - Is Layer
+    /// Returns `true` when a file is a JSON object with the `versions` key of the
+    /// current layers, or the `layer` key of the older ones.
+    pub fn is_layer(fp: &str) -> bool
+    {
+        let _content = match std::fs::read_to_string(fp) {
+            Ok(_c) => _c,
+            Err(_) => return false
+        };
+        match serde_json::from_str::<serde_json::Value>(_content.as_str()) {
+            Ok(serde_json::Value::Object(_map)) => _map.contains_key("versions") || _map.contains_key("layer"),
+            _ => false
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_file(name: &str, content: &str) -> String
+    {
+        let _fp = std::env::temp_dir().join(format!("mitre-assistant-{}-{}", std::process::id(), name));
+        std::fs::write(&_fp, content).unwrap();
+        _fp.display().to_string()
+    }
+
+    #[test]
+    fn save_expands_the_parents_of_the_subtechniques()
+    {
+        let mut _layer = NavigatorLayer::new("Coverage", "Techniques Covered By Our Rules");
+        _layer.add("t1003", None, Some(1.0), "", "Rules: 1");
+        _layer.add("T1003.001", Some("credential-access"), Some(2.0), "#8ec843", "Rules: 2");
+        _layer.add("T1059", None, None, "#8ec843", "");
+        _layer.add_gradient(0.0, 2.0);
+        _layer.add_legend("Covered", "#8ec843");
+        let _fp = temp_file("saved-layer.json", "");
+        _layer.save(_fp.as_str()).unwrap();
+        let _loaded = NavigatorLayer::load(_fp.as_str()).unwrap();
+        let _expanded: Vec<(&str, bool)> = _loaded.techniques.iter()
+                                                  .map(|_t| (_t.technique_id.as_str(), _t.show_subtechniques))
+                                                  .collect();
+        assert_eq!(_expanded, vec![("T1003", true), ("T1003.001", false), ("T1059", false)]);
+        assert_eq!(_loaded.techniques[1].tactic.as_deref(), Some("credential-access"));
+        assert_eq!(_loaded.gradient.map(|_g| _g.max_value), Some(2.0));
+        assert_eq!(_loaded.legend_items[0].label, "Covered");
+        assert!(NavigatorLayer::is_layer(_fp.as_str()));
+        std::fs::remove_file(_fp).unwrap();
+    }
+
+    #[test]
+    fn technique_ids_skip_the_disabled_and_the_low_scores()
+    {
+        let _fp = temp_file("navigator-layer.json", r#"{
+            "name": "Made In The Navigator",
+            "versions": { "attack": "8", "navigator": "4.1", "layer": "4.1" },
+            "domain": "enterprise-attack",
+            "sorting": 0,
+            "techniques": [
+                { "techniqueID": "t1059.001", "score": 3 },
+                { "techniqueID": "T1003", "score": 1 },
+                { "techniqueID": "T1003", "tactic": "credential-access", "score": 1 },
+                { "techniqueID": "T1078", "enabled": false, "score": 5 },
+                { "techniqueID": "T1110" }
+            ]
+        }"#);
+        let _layer = NavigatorLayer::load(_fp.as_str()).unwrap();
+        assert_eq!(_layer.technique_ids(None), vec!["T1003", "T1059.001", "T1110"]);
+        assert_eq!(_layer.technique_ids(Some(2.0)), vec!["T1059.001"]);
+        std::fs::remove_file(_fp).unwrap();
+    }
+
+    #[test]
+    fn load_reads_the_older_layers_and_rejects_the_other_domains()
+    {
+        let _older = temp_file("older-layer.json", r#"{ "name": "v2", "version": "2.2", "layer": "2.2", "domain": "mitre-enterprise", "techniques": [] }"#);
+        assert!(NavigatorLayer::is_layer(_older.as_str()));
+        assert!(NavigatorLayer::load(_older.as_str()).unwrap().techniques.is_empty());
+        let _mobile = temp_file("mobile-layer.json", r#"{ "versions": {}, "domain": "mobile-attack" }"#);
+        match NavigatorLayer::load(_mobile.as_str()) {
+            Err(AssistantError::Parse(_reason)) => assert!(_reason.contains("mobile-attack")),
+            _other => panic!("Expected A Parse Error, Found {:?}", _other)
+        }
+        let _csv = temp_file("techniques.csv", "T1003,T1059\n");
+        assert!(!NavigatorLayer::is_layer(_csv.as_str()));
+        assert!(matches!(NavigatorLayer::load(_csv.as_str()), Err(AssistantError::Parse(_))));
+        for _fp in [_older, _mobile, _csv].iter() {
+            std::fs::remove_file(_fp).unwrap();
+        }
+    }
+}
@@ -1,31 +1,30 @@
 //use std::thread;
 //use std::sync::{Arc};
-use std::collections::HashSet;
-use std::sync::mpsc;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
 //use std::borrow::Cow;
 
 use serde_derive::{Deserialize, Serialize};
 use serde_json;
+use regex::Regex;
 
-#[path = "../utils/fshandler.rs"]
-mod fshandler;
-use fshandler::FileHandler;
-
-#[path = "../utils/regexes.rs"]
-mod regexes;
-use regexes::RegexPatternManager;
-
-#[path = "../structs/enterprise.rs"]
-mod enterprise;
-use enterprise::{
+use crate::utils::fshandler::FileHandler;
+use crate::utils::regexes::RegexPatternManager;
+use crate::structs::errors::AssistantError;
+use crate::structs::types::{ Datasource, Platform, StixKind, Tactic };
+use crate::structs::enterprise::{
     EnterpriseAdversary,
     EnterpriseAdversaryProfile,
-    EnterpriseProfileEntry,
     EnterpriseMalware,
+    EnterpriseDescription,
     EnterpriseMalwareProfile,
+    EnterpriseMitigation,
+    EnterpriseMitigationProfile,
     EnterpriseMatrixStatistics,
+    EnterpriseReference,
     EnterpriseRelationship,
     EnterpriseRelationships,
+    EnterpriseTacticDetail,
     EnterpriseTool,
     EnterpriseToolProfile,
     EnterpriseTechnique,
@@ -39,14 +38,24 @@ pub struct EnterpriseMatrixBreakdown {
     pub adversaries:                Vec<String>,
     pub malware:                    Vec<String>,
     pub tools:                      Vec<String>,
-    pub tactics:                    HashSet<String>,
-    pub platforms:                  HashSet<String>,
-    pub datasources:                Vec<String>,
+    pub tactics:                    HashSet<Tactic>,
+    pub platforms:                  HashSet<Platform>,
+    pub datasources:                Vec<Datasource>,
     pub revoked_techniques:         HashSet<(String, String)>,
+    #[serde(default)]
+    pub revoked_by:                 HashSet<(String, String)>,
     pub deprecated_techniques:      HashSet<(String, String)>,
     pub breakdown_adversaries:      Vec<EnterpriseAdversary>,
     pub breakdown_malware:          Vec<EnterpriseMalware>,
     pub breakdown_tools:            Vec<EnterpriseTool>,
+    #[serde(default)]
+    pub breakdown_mitigations:      Vec<EnterpriseMitigation>,
+    #[serde(default)]
+    pub breakdown_tactics:          Vec<EnterpriseTacticDetail>,
+    #[serde(default)]
+    pub descriptions:               Vec<EnterpriseDescription>,
+    #[serde(default)]
+    pub references:                 Vec<EnterpriseReference>,
     pub breakdown_techniques:       EnterpriseTechniquesByPlatform,
     pub breakdown_subtechniques:    EnterpriseSubtechniquesByPlatform,
     pub uniques_techniques:         Vec<String>,
@@ -65,10 +74,15 @@ impl EnterpriseMatrixBreakdown {
             platforms: HashSet::new(),
             datasources: Vec::new(),
             revoked_techniques: HashSet::new(),
+            revoked_by: HashSet::new(),
             deprecated_techniques: HashSet::new(),
             breakdown_adversaries: vec![],
             breakdown_malware: vec![],
             breakdown_tools: vec![],
+            breakdown_mitigations: vec![],
+            breakdown_tactics: vec![],
+            descriptions: vec![],
+            references: vec![],
             breakdown_techniques: EnterpriseTechniquesByPlatform::new(),
             breakdown_subtechniques: EnterpriseSubtechniquesByPlatform::new(),
             uniques_techniques: vec![],
@@ -79,10 +93,17 @@ impl EnterpriseMatrixBreakdown {
         }
     }
 }
+impl Default for EnterpriseMatrixBreakdown {
+    fn default() -> Self
+    {
+        EnterpriseMatrixBreakdown::new()
+    }
+}
 #[derive(Debug, Deserialize, Serialize)]
 pub struct EnterpriseMatrixParser {
     pub techniques: HashSet<String>,
     pub subtechniques: HashSet<String>,
+    pub revoked_ids: HashMap<String, String>,
     pub relationships: EnterpriseRelationships,
     pub details: EnterpriseMatrixBreakdown,
 }
@@ -91,69 +112,117 @@ impl EnterpriseMatrixParser {
         EnterpriseMatrixParser {
             techniques: HashSet::new(),
             subtechniques: HashSet::new(),
+            revoked_ids: HashMap::new(),
             relationships: EnterpriseRelationships::new(),
             details: EnterpriseMatrixBreakdown::new(),
         }
     }
-    pub fn baseline(&mut self, matrix_type: &str) -> Result<(), Box<dyn std::error::Error>> {
-        if FileHandler::check_for_config_folder().unwrap() {
-            match matrix_type {
-                "enterprise" => self.baseline_enterprise()?,
-                _ => (),
-            }
+    /// # Baseline
+    /// Parses a downloaded matrix into the custom `json` database.
+    ///
+    /// The `version` parameter selects which downloaded release is parsed,
+    /// use `"None"` for the matrix downloaded without a version.
+    /// ```ignore
+    /// let mut _emp = EnterpriseMatrixParser::new();
+    /// _emp.baseline("enterprise", "v7.2")?;
+    /// ```
+    pub fn baseline(&mut self, matrix_type: &str, version: &str) -> Result<(), AssistantError> {
+        if FileHandler::check_for_config_folder()? && matrix_type == "enterprise" {
+            self.baseline_enterprise(version)?;
         }
         Ok(())
     }
     /// # Baseline
     /// Private method used to read, parse the CTI matrix of choice
     /// and create the custom `json` database used by this program.
-    /// ```rust
+    /// ```ignore
     /// // Assumes you already downloaded the enterprise matrix
     /// // Gets invoked by the `baseline()` method.
     ///
-    /// self.baseline_enterprise()?
+    /// self.baseline_enterprise("None")?
+    /// ```
+    fn baseline_enterprise(&mut self, version: &str) -> Result<(), AssistantError> {
+        let _matrix = FileHandler::versioned_name("enterprise", version);
+        if !std::path::Path::new(FileHandler::resource_path("matrixes", _matrix.as_str()).as_str()).is_file() {
+            return Err(AssistantError::MissingResource(format!("Matrix Not Found: {} | Download It With: `download -m enterprise`", _matrix)));
+        }
+        let _bufr = FileHandler::load_resource("matrixes", _matrix.as_str())?;
+        let _json: serde_json::Value = serde_json::from_reader(_bufr)?;
+        self.parse_enterprise(&_json)
+    }
+    /// # Baseline From String
+    /// Parses an enterprise STIX bundle held in memory, without reading or
+    /// writing the config folder - e.g., to compare a bundle with a baseline.
+    /// ```ignore
+    /// let mut _emp = EnterpriseMatrixParser::new();
+    /// _emp.baseline_from_str(_bundle.as_str())?;
     /// ```
-    fn baseline_enterprise(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        let _bufr = FileHandler::load_resource("matrixes", "enterprise.json");
-        let _json: serde_json::Value = serde_json::from_reader(_bufr).unwrap();
+    pub fn baseline_from_str(&mut self, content: &str) -> Result<(), AssistantError> {
+        let _json: serde_json::Value = serde_json::from_str(content)?;
+        self.parse_enterprise(&_json)
+    }
+    /// # Parse Enterprise
+    /// Private method extracting the custom `json` database from the
+    /// objects of an enterprise STIX bundle.
+    fn parse_enterprise(&mut self, _json: &serde_json::Value) -> Result<(), AssistantError> {
         let _scanner = RegexPatternManager::load_subtechnique();
+        // The citations and the markdown links of a description - e.g., `(Citation: Microsoft)`
+        let _citations = Regex::new(r"\s*\(Citation:[^)]*\)|\[([^\]]*)\]\([^)]*\)").unwrap();
         let mut _is_subtechnique = false;
-        for _t in _json["objects"].as_array().unwrap().iter() {
-            let _s = _t["type"].as_str().unwrap();
-            let _x = serde_json::to_string(_t).unwrap();
-            if _s == "attack-pattern" && _x.contains("x_mitre_deprecated") {
-                self.extract_deprecated_techniques(_t);
+        for _t in _json["objects"].as_array().ok_or_else(|| malformed("No Objects In The Bundle"))?.iter() {
+            let _s = StixKind::parse(_t["type"].as_str().ok_or_else(|| malformed("Object Without A Type"))?);
+            let _x = serde_json::to_string(_t)?;
+            if _s == StixKind::AttackPattern && _x.contains("x_mitre_deprecated") {
+                self.extract_deprecated_techniques(_t)?;
             }
-            else if _s == "attack-pattern" && _x.contains("revoked") {
-                self.extract_revoked_techniques(_t);
+            else if _s == StixKind::AttackPattern && _x.contains("revoked") {
+                self.extract_revoked_techniques(_t)?;
             }
-            else if _s == "attack-pattern" && !_x.contains("revoked") {
+            else if _s == StixKind::AttackPattern && !_x.contains("revoked") {
                 if _scanner.pattern.is_match(&_x) {
                     _is_subtechnique = true;
-                    self.extract_techniques_and_tactics(_t, _is_subtechnique);
+                    self.extract_techniques_and_tactics(_t, _is_subtechnique)?;
                 } else {
                     _is_subtechnique = false;
-                    self.extract_techniques_and_tactics(_t, _is_subtechnique);
+                    self.extract_techniques_and_tactics(_t, _is_subtechnique)?;
                 }
-                self.extract_tactics(_t);
+                self.extract_tactics(_t)?;
                 if _x.contains("x_mitre_data_sources") {
-                    self.extract_datasources(_t);
+                    self.extract_datasources(_t)?;
                 }
+                self.extract_descriptions(_t, if _is_subtechnique { "subtechnique" } else { "technique" }, &_citations)?;
+                self.extract_references(_t)?;
             }
-            else if _s == "malware" {
+            else if _s == StixKind::Malware {
                 self.details.stats.count_malwares += 1;
-                self.extract_malware(_t);
+                self.extract_malware(_t)?;
+                self.extract_descriptions(_t, "malware", &_citations)?;
+                self.extract_references(_t)?;
             }
-            else if _s == "intrusion-set" {
+            else if _s == StixKind::IntrusionSet {
                 self.details.stats.count_adversaries += 1;
-                self.extract_adversaries(_t);
+                self.extract_adversaries(_t)?;
+                self.extract_descriptions(_t, "adversary", &_citations)?;
+                self.extract_references(_t)?;
             }
-            else if _s == "tool" {
+            else if _s == StixKind::Tool {
                 self.details.stats.count_tools += 1;
-                self.extract_tools(_t);
+                self.extract_tools(_t)?;
+                self.extract_descriptions(_t, "tool", &_citations)?;
+                self.extract_references(_t)?;
+            }
+            else if _s == StixKind::CourseOfAction {
+                self.extract_mitigations(_t)?;
+                self.extract_descriptions(_t, "mitigation", &_citations)?;
+                self.extract_references(_t)?;
+            }
+            else if _s == StixKind::XMitreTactic {
+                self.extract_tactic_details(_t)?;
+                self.extract_descriptions(_t, "tactic", &_citations)?;
+                self.extract_references(_t)?;
             }
-            else if _s == "relationship" {
-                self.extract_relationshsip(_t);
+            else if _s == StixKind::Relationship {
+                self.extract_relationshsip(_t)?;
             }
         }
         /*
@@ -178,21 +247,22 @@ impl EnterpriseMatrixParser {
     fn extract_revoked_techniques(
         &mut self,
         items: &serde_json::Value,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        if items["revoked"].as_bool().unwrap() {
+    ) -> Result<(), AssistantError> {
+        if items["revoked"].as_bool().unwrap_or(false) {
             let _tid = items["external_references"]
                 .as_array()
-                .expect("Problem With External References");
-            let _tid = _tid[0]["external_id"]
-                .as_str()
-                .expect("Problem With External ID");
-            let _tname = items["name"].as_str().expect("Problem With Technique Name");
+                .ok_or_else(|| malformed("Problem With External References"))?;
+            let _tid = _tid.first().and_then(|_r| _r["external_id"].as_str())
+                .ok_or_else(|| malformed("Problem With External ID"))?;
+            let _tname = items["name"].as_str().ok_or_else(|| malformed("Problem With Technique Name"))?;
+            let _id = items["id"].as_str().ok_or_else(|| malformed("Problem With Technique UID"))?;
+            self.revoked_ids.insert(_id.to_string(), _tid.to_string());
             self.details
                 .revoked_techniques
                 .insert((_tid.to_string(), _tname.to_string()));
             self.details.stats.count_revoked_techniques = self.details.revoked_techniques.len();
         } else {
-            self.extract_techniques_and_tactics(items, false);
+            self.extract_techniques_and_tactics(items, false)?;
         }
 
         Ok(())
@@ -200,21 +270,20 @@ impl EnterpriseMatrixParser {
     fn extract_deprecated_techniques(
         &mut self,
         items: &serde_json::Value,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        if items["x_mitre_deprecated"].as_bool().unwrap() {
+    ) -> Result<(), AssistantError> {
+        if items["x_mitre_deprecated"].as_bool().unwrap_or(false) {
             let _tid = items["external_references"]
                 .as_array()
-                .expect("Problem With External References");
-            let _tid = _tid[0]["external_id"]
-                .as_str()
-                .expect("Problem With External ID");
-            let _tname = items["name"].as_str().expect("Problem With Technique Name");
+                .ok_or_else(|| malformed("Problem With External References"))?;
+            let _tid = _tid.first().and_then(|_r| _r["external_id"].as_str())
+                .ok_or_else(|| malformed("Problem With External ID"))?;
+            let _tname = items["name"].as_str().ok_or_else(|| malformed("Problem With Technique Name"))?;
             self.details
                 .deprecated_techniques
                 .insert((_tid.to_string(), _tname.to_string()));
             self.details.stats.count_deprecated_techniques = self.details.deprecated_techniques.len();
         } else {
-            self.extract_techniques_and_tactics(items, false);
+            self.extract_techniques_and_tactics(items, false)?;
         }
 
         Ok(())
@@ -228,11 +297,11 @@ impl EnterpriseMatrixParser {
     fn extract_datasources(
         &mut self,
         items: &serde_json::Value,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        for _item in items["x_mitre_data_sources"].as_array().unwrap().iter() {
+    ) -> Result<(), AssistantError> {
+        for _item in items["x_mitre_data_sources"].as_array().ok_or_else(|| malformed("Problem With x_mitre_data_sources"))?.iter() {
             self.details
                 .datasources
-                .push(_item.as_str().unwrap().to_lowercase().replace(" ", "-").replace("/", "-"));
+                .push(Datasource::parse(_item.as_str().ok_or_else(|| malformed("Expected A String"))?));
         }
         self.details.datasources.sort();
         self.details.datasources.dedup();
@@ -243,57 +312,45 @@ impl EnterpriseMatrixParser {
         &mut self,
         items: &serde_json::Value,
         is_subtechnique: bool,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        let _id  = items["id"].as_str().expect("Problem With Technique UID");
+    ) -> Result<(), AssistantError> {
+        let _id  = items["id"].as_str().ok_or_else(|| malformed("Problem With Technique UID"))?;
         let _id  = _id.to_string();
         let _tid = items["external_references"]
             .as_array()
-            .expect("Problem With External References");
-        let _tid = _tid[0]["external_id"]
-            .as_str()
-            .expect("Problem With External ID");
-        let _tname = items["name"].as_str().expect("Problem With Technique Name");
-        let mut _platforms = String::from("");
-        for _os in items["x_mitre_platforms"].as_array().unwrap().iter() {
-            let _x = _os.as_str().unwrap().to_lowercase().replace(" ", "-");
-            &_platforms.push_str(_x.as_str());
-            &_platforms.push_str("|");
-            self.details.platforms.insert(_x);
-        }
-        _platforms.pop();
+            .ok_or_else(|| malformed("Problem With External References"))?;
+        let _tid = _tid.first().and_then(|_r| _r["external_id"].as_str())
+            .ok_or_else(|| malformed("Problem With External ID"))?;
+        let _tname = items["name"].as_str().ok_or_else(|| malformed("Problem With Technique Name"))?;
+        let mut _platforms: Vec<Platform> = vec![];
+        for _os in items["x_mitre_platforms"].as_array().ok_or_else(|| malformed("Problem With x_mitre_platforms"))?.iter() {
+            let _x = Platform::parse(_os.as_str().ok_or_else(|| malformed("Expected A String"))?);
+            self.details.platforms.insert(_x.clone());
+            _platforms.push(_x);
+        }
 
-        for _item in items["kill_chain_phases"].as_array().unwrap().iter() {
+        for _item in items["kill_chain_phases"].as_array().ok_or_else(|| malformed("Problem With kill_chain_phases"))?.iter() {
             let _tactic = &_item["phase_name"]
                 .as_str()
-                .expect("Problem With Killchain Phase");
+                .ok_or_else(|| malformed("Problem With Killchain Phase"))?;
             let mut _et = EnterpriseTechnique::new();
             _et.id = _id.clone();
             _et.platform = _platforms.clone();
             _et.tid = _tid.to_string();
-            _et.tactic = _tactic.to_string();
+            _et.tactic = Tactic::parse(_tactic);
             _et.technique = _tname.to_string();
             let _d = items
                 .as_object()
-                .expect("Unable to Deserialize into String");
+                .ok_or_else(|| malformed("Unable to Deserialize into String"))?;
             // Extract Data Sources
             // Normalize the Data Source
             if _d.contains_key("x_mitre_data_sources") {
-                let mut _data_sources = String::from("");
+                let mut _data_sources: Vec<Datasource> = vec![];
                 for _ds in items["x_mitre_data_sources"]
                     .as_array()
-                    .expect("Deserializing Data Sources Issue")
+                    .ok_or_else(|| malformed("Deserializing Data Sources Issue"))?
                 {
-                    _data_sources.push_str(
-                        _ds.as_str()
-                            .unwrap()
-                            .to_lowercase()
-                            .replace(" ", "-")
-                            .replace("/", "-")
-                            .as_str(),
-                    );
-                    _data_sources.push_str("|");
+                    _data_sources.push(Datasource::parse(_ds.as_str().ok_or_else(|| malformed("Expected A String"))?));
                 }
-                _data_sources.pop();
                 _et.datasources = _data_sources;
                 if is_subtechnique {
                     self.subtechniques.insert(_tid.to_string());
@@ -309,12 +366,12 @@ impl EnterpriseMatrixParser {
                 // Reference:  https://github.com/mitre/cti/issues/101#issuecomment-671639284
                 if is_subtechnique {
                     _et.id = _id.clone();
-                    _et.datasources = "none".to_string();
+                    _et.datasources = vec![];
                     self.subtechniques.insert(_tid.to_string());
                     self.details.breakdown_subtechniques.platforms.push(_et);
                     self.details.uniques_subtechniques.push(_tid.to_string());
                 } else {
-                    _et.datasources = "none".to_string();
+                    _et.datasources = vec![];
                     self.techniques.insert(_tid.to_string());
                     self.details.breakdown_techniques.platforms.push(_et);
                     self.details.uniques_techniques.push(_tid.to_string());
@@ -328,7 +385,7 @@ impl EnterpriseMatrixParser {
                     _record.subtechniques.push(_subtechnique.to_string());
                 }
             }
-            if _record.subtechniques.len() > 0usize {
+            if !_record.subtechniques.is_empty() {
                 _record.has_subtechniques = true;
                 _record.subtechniques.sort();
                 _record.subtechniques.dedup();
@@ -354,20 +411,25 @@ impl EnterpriseMatrixParser {
     fn extract_tactics(
         &mut self,
         items: &serde_json::Value,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        for _item in items["kill_chain_phases"].as_array().unwrap().iter() {
+    ) -> Result<(), AssistantError> {
+        for _item in items["kill_chain_phases"].as_array().ok_or_else(|| malformed("Problem With kill_chain_phases"))?.iter() {
             self.details
                 .tactics
-                .insert(_item["phase_name"].as_str().unwrap().to_string());
+                .insert(Tactic::parse(_item["phase_name"].as_str().ok_or_else(|| malformed("Problem With Killchain Phase"))?));
         }
         self.details.stats.count_tactics = self.details.tactics.len();
         Ok(())
     }
-    pub fn to_string(&self) -> String {
-        serde_json::to_string_pretty(&self.details).unwrap()
+    /// # Has Baseline
+    /// Returns `true` when the enterprise matrix of the `version` was already
+    /// baselined, used to refresh the baseline after a matrix update.
+    pub fn has_baseline(version: &str) -> bool {
+        let _baseline = FileHandler::versioned_name("baseline-enterprise", version);
+        std::path::Path::new(FileHandler::resource_path("baselines", _baseline.as_str()).as_str()).is_file()
     }
-    pub fn save_baseline(&self) {
-        FileHandler::write_baseline("baseline-enterprise.json", &self.to_string());
+    pub fn save_baseline(&self, version: &str) -> Result<(), AssistantError> {
+        let _baseline = FileHandler::versioned_name("baseline-enterprise", version);
+        FileHandler::write_baseline(_baseline.as_str(), &self.to_string())
     }
     /// # **Stats Functions**
     /// The functions in this code section baseline specific queries to offer
@@ -378,13 +440,6 @@ impl EnterpriseMatrixParser {
     /// `EnterpriseMatrixBreakdown` struct.
     ///
     ///
-    fn get_percentage(&self, total: usize, actual: usize) -> String
-    {
-        let _high = total as f64;
-        let _low = actual as f64;
-        let _percent = (_low / _high) * 100f64;
-        format!("{}{}", _percent.floor().to_string(), "%")
-    }
     fn extract_stats_techniques_by_totals(&mut self )
     {
         let mut _total_techniques: HashSet<String> = HashSet::new();
@@ -427,7 +482,7 @@ impl EnterpriseMatrixParser {
             let _os = _platform.as_str();
             // Now for each platform find the techniques
             for _technique in _iterable.iter() {
-                if _technique.platform.contains(_os) {
+                if _technique.platform.contains(_platform) {
                     _stub = format!("{}:{}", _technique.tid, _technique.tactic);
                     if _os == "aws" {
                         _aws.insert(_stub);
@@ -471,15 +526,15 @@ impl EnterpriseMatrixParser {
             self.details.stats.count_subtechniques_saas = _saas.len();
             self.details.stats.count_subtechniques_windows = _windows.len();
             // Percentages
-            self.details.stats.percent_subtechniques_aws = self.get_percentage(_total, _aws.len());
-            self.details.stats.percent_subtechniques_azure = self.get_percentage(_total, _azure.len());
-            self.details.stats.percent_subtechniques_azure_ad = self.get_percentage(_total, _azure_ad.len());
-            self.details.stats.percent_subtechniques_gcp = self.get_percentage(_total,_gcp.len());
-            self.details.stats.percent_subtechniques_linux = self.get_percentage(_total,_linux.len());
-            self.details.stats.percent_subtechniques_macos = self.get_percentage(_total, _macos.len());
-            self.details.stats.percent_subtechniques_office365 = self.get_percentage(_total, _office365.len());
-            self.details.stats.percent_subtechniques_saas = self.get_percentage(_total, _saas.len());
-            self.details.stats.percent_subtechniques_windows = self.get_percentage(_total, _windows.len());
+            self.details.stats.percent_subtechniques_aws = EnterpriseMatrixStatistics::get_percentage(_total, _aws.len());
+            self.details.stats.percent_subtechniques_azure = EnterpriseMatrixStatistics::get_percentage(_total, _azure.len());
+            self.details.stats.percent_subtechniques_azure_ad = EnterpriseMatrixStatistics::get_percentage(_total, _azure_ad.len());
+            self.details.stats.percent_subtechniques_gcp = EnterpriseMatrixStatistics::get_percentage(_total,_gcp.len());
+            self.details.stats.percent_subtechniques_linux = EnterpriseMatrixStatistics::get_percentage(_total,_linux.len());
+            self.details.stats.percent_subtechniques_macos = EnterpriseMatrixStatistics::get_percentage(_total, _macos.len());
+            self.details.stats.percent_subtechniques_office365 = EnterpriseMatrixStatistics::get_percentage(_total, _office365.len());
+            self.details.stats.percent_subtechniques_saas = EnterpriseMatrixStatistics::get_percentage(_total, _saas.len());
+            self.details.stats.percent_subtechniques_windows = EnterpriseMatrixStatistics::get_percentage(_total, _windows.len());
         } else {
             let _total = self.details.stats.count_active_total_techniques;
             self.details.stats.count_techniques_aws = _aws.len();
@@ -492,21 +547,18 @@ impl EnterpriseMatrixParser {
             self.details.stats.count_techniques_saas = _saas.len();
             self.details.stats.count_techniques_windows = _windows.len();
             // Percentages
-            self.details.stats.percent_techniques_aws = self.get_percentage(_total, _aws.len());
-            self.details.stats.percent_techniques_azure = self.get_percentage(_total, _azure.len());
-            self.details.stats.percent_techniques_azure_ad = self.get_percentage(_total, _azure_ad.len());
-            self.details.stats.percent_techniques_gcp = self.get_percentage(_total,_gcp.len());
-            self.details.stats.percent_techniques_linux = self.get_percentage(_total,_linux.len());
-            self.details.stats.percent_techniques_macos = self.get_percentage(_total, _macos.len());
-            self.details.stats.percent_techniques_office365 = self.get_percentage(_total, _office365.len());
-            self.details.stats.percent_techniques_saas = self.get_percentage(_total, _saas.len());
-            self.details.stats.percent_techniques_windows = self.get_percentage(_total, _windows.len());                        
+            self.details.stats.percent_techniques_aws = EnterpriseMatrixStatistics::get_percentage(_total, _aws.len());
+            self.details.stats.percent_techniques_azure = EnterpriseMatrixStatistics::get_percentage(_total, _azure.len());
+            self.details.stats.percent_techniques_azure_ad = EnterpriseMatrixStatistics::get_percentage(_total, _azure_ad.len());
+            self.details.stats.percent_techniques_gcp = EnterpriseMatrixStatistics::get_percentage(_total,_gcp.len());
+            self.details.stats.percent_techniques_linux = EnterpriseMatrixStatistics::get_percentage(_total,_linux.len());
+            self.details.stats.percent_techniques_macos = EnterpriseMatrixStatistics::get_percentage(_total, _macos.len());
+            self.details.stats.percent_techniques_office365 = EnterpriseMatrixStatistics::get_percentage(_total, _office365.len());
+            self.details.stats.percent_techniques_saas = EnterpriseMatrixStatistics::get_percentage(_total, _saas.len());
+            self.details.stats.percent_techniques_windows = EnterpriseMatrixStatistics::get_percentage(_total, _windows.len());                        
         }
     }
-    ///
-    /// 
-    /// 
-    /// 
+    /// # Extract Stats Techniques By Killchain (Private Method)
     fn extract_stats_techniques_by_killchain(&mut self, _wants_subtechniques: bool)
     {
         // Setup Tactics Hashsets for UNIQ
@@ -538,7 +590,7 @@ impl EnterpriseMatrixParser {
             let _kc = _tactic.as_str();
             let mut _kill_chain = EnterpriseTechniquesByTactic::new(_kc);
             for _technique in _iterable.iter() {
-                if _technique.tactic.contains(_kc) {
+                if &_technique.tactic == _tactic {
                     _stub = format!("{}:{}", _technique.tid, _technique.tactic);
                     _kill_chain.tactic.items.push(_stub.clone());
                     // Validate which Tactic
@@ -603,18 +655,18 @@ impl EnterpriseMatrixParser {
             self.details.stats.count_subtechniques_exfiltration = _exfiltration.len();
             self.details.stats.count_subtechniques_impact = _impact.len();
             // Percentages
-            self.details.stats.percent_subtechniques_initial_access = self.get_percentage(_total, _initial_access.len());
-            self.details.stats.percent_subtechniques_execution = self.get_percentage(_total, _execution.len());
-            self.details.stats.percent_subtechniques_persistence = self.get_percentage(_total, _persistence.len());
-            self.details.stats.percent_subtechniques_privilege_escalation = self.get_percentage(_total, _priv_escalation.len());
-            self.details.stats.percent_subtechniques_defense_evasion = self.get_percentage(_total, _defense_evasion.len());
-            self.details.stats.percent_subtechniques_credential_access = self.get_percentage(_total, _credential_access.len());
-            self.details.stats.percent_subtechniques_collection = self.get_percentage(_total, _collection.len());
-            self.details.stats.percent_subtechniques_discovery = self.get_percentage(_total, _discovery.len());
-            self.details.stats.percent_subtechniques_lateral_movement = self.get_percentage(_total, _lateral_movement.len());
-            self.details.stats.percent_subtechniques_command_and_control = self.get_percentage(_total, _command_and_control.len());
-            self.details.stats.percent_subtechniques_exfiltration = self.get_percentage(_total, _exfiltration.len());
-            self.details.stats.percent_subtechniques_impact = self.get_percentage(_total, _impact.len());            
+            self.details.stats.percent_subtechniques_initial_access = EnterpriseMatrixStatistics::get_percentage(_total, _initial_access.len());
+            self.details.stats.percent_subtechniques_execution = EnterpriseMatrixStatistics::get_percentage(_total, _execution.len());
+            self.details.stats.percent_subtechniques_persistence = EnterpriseMatrixStatistics::get_percentage(_total, _persistence.len());
+            self.details.stats.percent_subtechniques_privilege_escalation = EnterpriseMatrixStatistics::get_percentage(_total, _priv_escalation.len());
+            self.details.stats.percent_subtechniques_defense_evasion = EnterpriseMatrixStatistics::get_percentage(_total, _defense_evasion.len());
+            self.details.stats.percent_subtechniques_credential_access = EnterpriseMatrixStatistics::get_percentage(_total, _credential_access.len());
+            self.details.stats.percent_subtechniques_collection = EnterpriseMatrixStatistics::get_percentage(_total, _collection.len());
+            self.details.stats.percent_subtechniques_discovery = EnterpriseMatrixStatistics::get_percentage(_total, _discovery.len());
+            self.details.stats.percent_subtechniques_lateral_movement = EnterpriseMatrixStatistics::get_percentage(_total, _lateral_movement.len());
+            self.details.stats.percent_subtechniques_command_and_control = EnterpriseMatrixStatistics::get_percentage(_total, _command_and_control.len());
+            self.details.stats.percent_subtechniques_exfiltration = EnterpriseMatrixStatistics::get_percentage(_total, _exfiltration.len());
+            self.details.stats.percent_subtechniques_impact = EnterpriseMatrixStatistics::get_percentage(_total, _impact.len());            
             // Rollup
             self.details.rollup_subtechniques = _rollup; 
         } else {
@@ -633,65 +685,53 @@ impl EnterpriseMatrixParser {
             self.details.stats.count_techniques_exfiltration = _exfiltration.len();
             self.details.stats.count_techniques_impact = _impact.len();
             // Percentages
-            self.details.stats.percent_techniques_initial_access = self.get_percentage(_total, _initial_access.len());
-            self.details.stats.percent_techniques_execution = self.get_percentage(_total, _execution.len());
-            self.details.stats.percent_techniques_persistence = self.get_percentage(_total, _persistence.len());
-            self.details.stats.percent_techniques_privilege_escalation = self.get_percentage(_total, _priv_escalation.len());
-            self.details.stats.percent_techniques_defense_evasion = self.get_percentage(_total, _defense_evasion.len());
-            self.details.stats.percent_techniques_credential_access = self.get_percentage(_total, _credential_access.len());
-            self.details.stats.percent_techniques_collection = self.get_percentage(_total, _collection.len());
-            self.details.stats.percent_techniques_discovery = self.get_percentage(_total, _discovery.len());
-            self.details.stats.percent_techniques_lateral_movement = self.get_percentage(_total, _lateral_movement.len());
-            self.details.stats.percent_techniques_command_and_control = self.get_percentage(_total, _command_and_control.len());
-            self.details.stats.percent_techniques_exfiltration = self.get_percentage(_total, _exfiltration.len());
-            self.details.stats.percent_techniques_impact = self.get_percentage(_total, _impact.len());
+            self.details.stats.percent_techniques_initial_access = EnterpriseMatrixStatistics::get_percentage(_total, _initial_access.len());
+            self.details.stats.percent_techniques_execution = EnterpriseMatrixStatistics::get_percentage(_total, _execution.len());
+            self.details.stats.percent_techniques_persistence = EnterpriseMatrixStatistics::get_percentage(_total, _persistence.len());
+            self.details.stats.percent_techniques_privilege_escalation = EnterpriseMatrixStatistics::get_percentage(_total, _priv_escalation.len());
+            self.details.stats.percent_techniques_defense_evasion = EnterpriseMatrixStatistics::get_percentage(_total, _defense_evasion.len());
+            self.details.stats.percent_techniques_credential_access = EnterpriseMatrixStatistics::get_percentage(_total, _credential_access.len());
+            self.details.stats.percent_techniques_collection = EnterpriseMatrixStatistics::get_percentage(_total, _collection.len());
+            self.details.stats.percent_techniques_discovery = EnterpriseMatrixStatistics::get_percentage(_total, _discovery.len());
+            self.details.stats.percent_techniques_lateral_movement = EnterpriseMatrixStatistics::get_percentage(_total, _lateral_movement.len());
+            self.details.stats.percent_techniques_command_and_control = EnterpriseMatrixStatistics::get_percentage(_total, _command_and_control.len());
+            self.details.stats.percent_techniques_exfiltration = EnterpriseMatrixStatistics::get_percentage(_total, _exfiltration.len());
+            self.details.stats.percent_techniques_impact = EnterpriseMatrixStatistics::get_percentage(_total, _impact.len());
             // rollup            
             self.details.rollup_techniques = _rollup; 
         }
     }
     fn extract_malware(&mut self,
         items: &serde_json::Value   
-    ) -> Result<(), Box<dyn std::error::Error>>
+    ) -> Result<(), AssistantError>
     {
         let mut _is_revoked: bool = false;
-        let _malware = items.as_object().expect("Malware: Proble Convert Into Oject");
-        if _malware.contains_key("revoked") {
-            if _malware["revoked"].as_bool().expect("Malaware: Problem Is Revoked Check") {
-                _is_revoked = true;
-            }
+        let _malware = items.as_object().ok_or_else(|| malformed("Malware: Problem Converting Into Object"))?;
+        if _malware.contains_key("revoked") && _malware["revoked"].as_bool().unwrap_or(false) {
+            _is_revoked = true;
         }
-        let _malware_id = items["external_references"].as_array().expect("Malware: Problem With External References");
-        let _malware_id = _malware_id[0]["external_id"].as_str().expect("Malware: Problem With External ID");
+        let _malware_id = items["external_references"].as_array().ok_or_else(|| malformed("Malware: Problem With External References"))?;
+        let _malware_id = _malware_id.first().and_then(|_r| _r["external_id"].as_str()).ok_or_else(|| malformed("Malware: Problem With External ID"))?;
         let _malware_id = _malware_id.to_string();
-        let _id = items["id"].as_str().expect("Malware: Problem With UID");
+        let _id = items["id"].as_str().ok_or_else(|| malformed("Malware: Problem With UID"))?;
         let _id = _id.to_string();
-        let _name = items["name"].as_str().expect("Malware: Problem With Malware Name");
+        let _name = items["name"].as_str().ok_or_else(|| malformed("Malware: Problem With Malware Name"))?;
         let _name = _name.to_string();
-        let mut _platforms = String::from("");
+        let mut _platforms: Vec<Platform> = vec![];
         let mut _revoked_malware: usize = 0;
         if _is_revoked {
             _revoked_malware += 1;
         }
         if _malware.contains_key("x_mitre_platforms") {
-            for _os in items["x_mitre_platforms"].as_array().unwrap().iter() {
-                let _x = _os.as_str().unwrap().to_lowercase().replace(" ", "-");
-                &_platforms.push_str(_x.as_str());
-                &_platforms.push_str("|");
+            for _os in items["x_mitre_platforms"].as_array().ok_or_else(|| malformed("Problem With x_mitre_platforms"))?.iter() {
+                _platforms.push(Platform::parse(_os.as_str().ok_or_else(|| malformed("Expected A String"))?));
             }
-            _platforms.pop();
-        } else {
-            &_platforms.push_str("none");
         }
-        let mut _aliases = String::from("");
+        let mut _aliases: Vec<String> = vec![];
         if _malware.contains_key("aliases") {
-            for _alias in items["aliases"].as_array().unwrap().iter() {
-                let _x = _alias.as_str().unwrap().to_lowercase().replace(" ", "-");
-                &_aliases.push_str(_x.as_str());
-                &_aliases.push_str("|");
+            for _alias in items["aliases"].as_array().ok_or_else(|| malformed("Problem With aliases"))?.iter() {
+                _aliases.push(_alias.as_str().ok_or_else(|| malformed("Expected A String"))?.to_lowercase().replace(" ", "-"));
             }
-            _aliases.pop();
-        } else {
-            _aliases.push_str("none");
         }
         self.details.malware.push(_name.clone());
         let _em = EnterpriseMalware {
@@ -711,47 +751,35 @@ impl EnterpriseMatrixParser {
     }
     fn extract_tools(&mut self,
         items: &serde_json::Value   
-    ) -> Result<(), Box<dyn std::error::Error>>
+    ) -> Result<(), AssistantError>
     {
         let mut _is_revoked: bool = false;
-        let _tools = items.as_object().expect("Tools: Proble Convert Into Oject");
-        if _tools.contains_key("revoked") {
-            if _tools["revoked"].as_bool().expect("Tools: Problem Is Revoked Check") {
-                _is_revoked = true;
-            }
+        let _tools = items.as_object().ok_or_else(|| malformed("Tools: Problem Converting Into Object"))?;
+        if _tools.contains_key("revoked") && _tools["revoked"].as_bool().unwrap_or(false) {
+            _is_revoked = true;
         }
-        let _tool_id = items["external_references"].as_array().expect("Tools: Problem With External References");
-        let _tool_id = _tool_id[0]["external_id"].as_str().expect("Tools: Problem With External ID");
+        let _tool_id = items["external_references"].as_array().ok_or_else(|| malformed("Tools: Problem With External References"))?;
+        let _tool_id = _tool_id.first().and_then(|_r| _r["external_id"].as_str()).ok_or_else(|| malformed("Tools: Problem With External ID"))?;
         let _tool_id = _tool_id.to_string();
-        let _id = items["id"].as_str().expect("Tools: Problem With UID");
+        let _id = items["id"].as_str().ok_or_else(|| malformed("Tools: Problem With UID"))?;
         let _id = _id.to_string();
-        let _name = items["name"].as_str().expect("Tools: Problem With Malware Name");
+        let _name = items["name"].as_str().ok_or_else(|| malformed("Tools: Problem With Tool Name"))?;
         let _name = _name.to_string();
-        let mut _platforms = String::from("");
+        let mut _platforms: Vec<Platform> = vec![];
         let mut _revoked_tools: usize = 0;
         if _is_revoked {
             _revoked_tools += 1;
         }
         if _tools.contains_key("x_mitre_platforms") {
-            for _os in items["x_mitre_platforms"].as_array().unwrap().iter() {
-                let _x = _os.as_str().unwrap().to_lowercase().replace(" ", "-");
-                &_platforms.push_str(_x.as_str());
-                &_platforms.push_str("|");
+            for _os in items["x_mitre_platforms"].as_array().ok_or_else(|| malformed("Problem With x_mitre_platforms"))?.iter() {
+                _platforms.push(Platform::parse(_os.as_str().ok_or_else(|| malformed("Expected A String"))?));
             }
-            _platforms.pop();
-        } else {
-            &_platforms.push_str("none");
         }
-        let mut _aliases = String::from("");
+        let mut _aliases: Vec<String> = vec![];
         if _tools.contains_key("aliases") {
-            for _alias in items["aliases"].as_array().unwrap().iter() {
-                let _x = _alias.as_str().unwrap().to_lowercase().replace(" ", "-");
-                &_aliases.push_str(_x.as_str());
-                &_aliases.push_str("|");
+            for _alias in items["aliases"].as_array().ok_or_else(|| malformed("Problem With aliases"))?.iter() {
+                _aliases.push(_alias.as_str().ok_or_else(|| malformed("Expected A String"))?.to_lowercase().replace(" ", "-"));
             }
-            _aliases.pop();
-        } else {
-            _aliases.push_str("none");
         }
         self.details.tools.push(_name.clone());
         let _et = EnterpriseTool {
@@ -769,36 +797,143 @@ impl EnterpriseMatrixParser {
         self.details.tools.sort();                        
         Ok(())
     }    
+    /// # Extract Mitigations
+    /// Private method.
+    /// Keeps the `course-of-action` objects, the deprecated ones are flagged
+    /// rather than dropped.
+    fn extract_mitigations(&mut self,
+        items: &serde_json::Value
+    ) -> Result<(), AssistantError>
+    {
+        let _mitigation = items.as_object().ok_or_else(|| malformed("Mitigations: Problem Converting Into Object"))?;
+        let _mitigation_id = items["external_references"].as_array().ok_or_else(|| malformed("Mitigations: Problem With External References"))?;
+        let _mitigation_id = _mitigation_id.first().and_then(|_r| _r["external_id"].as_str()).ok_or_else(|| malformed("Mitigations: Problem With External ID"))?;
+        let _em = EnterpriseMitigation {
+            id:             items["id"].as_str().ok_or_else(|| malformed("Mitigations: Problem With UID"))?.to_string(),
+            name:           items["name"].as_str().ok_or_else(|| malformed("Mitigations: Problem With Mitigation Name"))?.to_string(),
+            mitigation_id:  _mitigation_id.to_string(),
+            is_deprecated:  _mitigation.get("x_mitre_deprecated").and_then(|_d| _d.as_bool()).unwrap_or(false),
+            profile:        EnterpriseMitigationProfile::new(),
+        };
+        self.details.breakdown_mitigations.push(_em);
+        Ok(())
+    }
+    /// # Extract Tactic Details
+    /// Private method.
+    /// Keeps the `x-mitre-tactic` objects, their ID and their shortname.
+    fn extract_tactic_details(&mut self,
+        items: &serde_json::Value
+    ) -> Result<(), AssistantError>
+    {
+        let _tactic_id = items["external_references"].as_array()
+                            .and_then(|_r| _r.first())
+                            .and_then(|_r| _r["external_id"].as_str())
+                            .ok_or_else(|| malformed("Tactics: Problem With External ID"))?;
+        let _name = items["name"].as_str().ok_or_else(|| malformed("Tactics: Problem With Tactic Name"))?;
+        let _shortname = match items["x_mitre_shortname"].as_str() {
+            Some(_shortname) => Tactic::parse(_shortname).to_string(),
+            None => Tactic::parse(_name).to_string()
+        };
+        self.details.breakdown_tactics.push(EnterpriseTacticDetail {
+            id:         items["id"].as_str().ok_or_else(|| malformed("Tactics: Problem With UID"))?.to_string(),
+            name:       _name.to_string(),
+            tactic_id:  _tactic_id.to_string(),
+            shortname:  _shortname,
+        });
+        Ok(())
+    }
+    /// # Extract References
+    /// Private method.
+    /// Keeps the external references of the active objects, by the ATT&CK ID of
+    /// the object - its first reference.
+    fn extract_references(&mut self,
+        items: &serde_json::Value
+    ) -> Result<(), AssistantError>
+    {
+        let _object = items.as_object().ok_or_else(|| malformed("References: Problem Converting Into Object"))?;
+        let _is_inactive = |_key: &str| _object.get(_key).and_then(|_v| _v.as_bool()).unwrap_or(false);
+        if _is_inactive("revoked") || _is_inactive("x_mitre_deprecated") {
+            return Ok(());
+        }
+        let _references = items["external_references"].as_array().ok_or_else(|| malformed("References: Problem With External References"))?;
+        let _id = _references.first()
+                    .and_then(|_r| _r["external_id"].as_str())
+                    .ok_or_else(|| malformed("References: Problem With External ID"))?;
+        let _value = |_reference: &serde_json::Value, _key: &str| match _reference[_key].as_str() {
+            Some(_text) if !_text.trim().is_empty() => _text.trim().to_string(),
+            _ => "none".to_string()
+        };
+        for _reference in _references.iter() {
+            self.details.references.push(EnterpriseReference {
+                id:             _id.to_string(),
+                source:         _value(_reference, "source_name"),
+                external_id:    _value(_reference, "external_id"),
+                url:            _value(_reference, "url"),
+                description:    _value(_reference, "description"),
+            });
+        }
+        Ok(())
+    }
+    /// # Extract Descriptions
+    /// Private method.
+    /// Keeps the description and the detection of the active objects, their
+    /// citations and the URLs of their links are removed.
+    fn extract_descriptions(&mut self,
+        items: &serde_json::Value,
+        kind: &str,
+        citations: &Regex
+    ) -> Result<(), AssistantError>
+    {
+        let _object = items.as_object().ok_or_else(|| malformed("Descriptions: Problem Converting Into Object"))?;
+        let _is_inactive = |_key: &str| _object.get(_key).and_then(|_v| _v.as_bool()).unwrap_or(false);
+        if _is_inactive("revoked") || _is_inactive("x_mitre_deprecated") {
+            return Ok(());
+        }
+        let _clean = |_key: &str| match items[_key].as_str() {
+            Some(_text) if !_text.trim().is_empty() => {
+                citations.replace_all(_text, "$1").split_whitespace().collect::<Vec<&str>>().join(" ")
+            },
+            _ => "none".to_string()
+        };
+        let _id = items["external_references"].as_array()
+                    .and_then(|_r| _r.first())
+                    .and_then(|_r| _r["external_id"].as_str())
+                    .ok_or_else(|| malformed("Descriptions: Problem With External ID"))?;
+        self.details.descriptions.push(EnterpriseDescription {
+            kind:           kind.to_string(),
+            id:             _id.to_string(),
+            name:           items["name"].as_str().ok_or_else(|| malformed("Descriptions: Problem With Name"))?.to_string(),
+            description:    _clean("description"),
+            detection:      _clean("x_mitre_detection"),
+        });
+        Ok(())
+    }
     fn extract_adversaries(&mut self,
         items: &serde_json::Value   
-    ) -> Result<(), Box<dyn std::error::Error>>
+    ) -> Result<(), AssistantError>
     {
         let mut _is_revoked: bool = false;
-        let _adversary = items.as_object().expect("Adversary: Proble Convert Into Oject");
-        if _adversary.contains_key("revoked") {
-            if _adversary["revoked"].as_bool().expect("Adversary: Problem Is Revoked Check") {
-                _is_revoked = true;
-            }
+        let _adversary = items.as_object().ok_or_else(|| malformed("Adversary: Problem Converting Into Object"))?;
+        if _adversary.contains_key("revoked") && _adversary["revoked"].as_bool().unwrap_or(false) {
+            _is_revoked = true;
         }
-        let _gid = items["external_references"].as_array().expect("Adversary: Problem With External References");
-        let _gid = _gid[0]["external_id"].as_str().expect("Adversary: Problem With External ID");
+        let _gid = items["external_references"].as_array().ok_or_else(|| malformed("Adversary: Problem With External References"))?;
+        let _gid = _gid.first().and_then(|_r| _r["external_id"].as_str()).ok_or_else(|| malformed("Adversary: Problem With External ID"))?;
         let _gid = _gid.to_string();
-        let _id = items["id"].as_str().expect("Adversary: Problem With UID");
+        let _id = items["id"].as_str().ok_or_else(|| malformed("Adversary: Problem With UID"))?;
         let _id = _id.to_string();
-        let _gname = items["name"].as_str().expect("Adversary: Problem With Technique Name");
+        let _gname = items["name"].as_str().ok_or_else(|| malformed("Adversary: Problem With Group Name"))?;
         let _gname = _gname.to_string();
-        let mut _aliases = String::from("");
+        let mut _aliases: Vec<String> = vec![];
         let mut _revoked_adversaries: usize = 0;
         if _is_revoked {
             _revoked_adversaries += 1;
         } else {
-            for _alias in items["aliases"].as_array().unwrap().iter() {
-                let _x = _alias.as_str().unwrap().to_lowercase().replace(" ", "-");
-                &_aliases.push_str(_x.as_str());
-                &_aliases.push_str("|");
-                self.details.adversaries.push(_x.to_string());
+            for _alias in items["aliases"].as_array().ok_or_else(|| malformed("Problem With aliases"))?.iter() {
+                let _x = _alias.as_str().ok_or_else(|| malformed("Expected A String"))?.to_lowercase().replace(" ", "-");
+                self.details.adversaries.push(_x.clone());
+                _aliases.push(_x);
             }
-            _aliases.pop();
         }
         let _ea = EnterpriseAdversary {
             id:         _id,
@@ -816,41 +951,57 @@ impl EnterpriseMatrixParser {
     }
     fn extract_relationshsip(&mut self,
         items: &serde_json::Value   
-    ) -> Result<(), Box<dyn std::error::Error>>
+    ) -> Result<(), AssistantError>
     {
-        let _relationship = items.as_object().expect("Relationship Problem, Convert To Object");
+        let _relationship = items.as_object().ok_or_else(|| malformed("Relationship Problem, Convert To Object"))?;
         let mut _er = EnterpriseRelationship::new();
-        _er.id = _relationship["id"].as_str().unwrap().to_string();
-        let _sr: &str = _relationship["source_ref"].as_str().expect("Relationship Problem, Convert Source Ref");
+        _er.id = _relationship["id"].as_str().ok_or_else(|| malformed("Relationship Problem, Convert ID"))?.to_string();
+        let _sr: &str = _relationship["source_ref"].as_str().ok_or_else(|| malformed("Relationship Problem, Convert Source Ref"))?;
         let _sr = _sr.to_string();
-        let _tr: &str = _relationship["target_ref"].as_str().expect("Relationship Problem, Convert Target Ref");
+        let _tr: &str = _relationship["target_ref"].as_str().ok_or_else(|| malformed("Relationship Problem, Convert Target Ref"))?;
         let _tr = _tr.to_string();
         _er.source = _sr;
         _er.target = _tr;
+        let _source = StixKind::of_id(_er.source.as_str());
+        let _target = StixKind::of_id(_er.target.as_str());
         if _relationship["relationship_type"] == "uses" {
             _er.relation_type = "uses".to_string();
             // Map relationships
             // adversary <---> technique
-            if _er.source.starts_with("intrusion-set") && _er.target.starts_with("attack-pattern") {
+            if _source == StixKind::IntrusionSet && _target == StixKind::AttackPattern {
                 self.relationships.adversary_to_techniques.insert(_er);
             }
             // adversary <---> weapon/malware
-            else if _er.source.starts_with("intrusion-set") && _er.target.starts_with("malware") {
+            else if _source == StixKind::IntrusionSet && _target == StixKind::Malware {
                 self.relationships.adversary_to_malware.insert(_er);
             }
             // adversary <---> weapon/non-malware
-            else if _er.source.starts_with("intrusion-set") && _er.target.starts_with("tool") {
+            else if _source == StixKind::IntrusionSet && _target == StixKind::Tool {
                 self.relationships.adversary_to_tools.insert(_er);
             }
             // weapon/malware <---> technique
-            else if _er.source.starts_with("malware") && _er.target.starts_with("attack-pattern") {
+            else if _source == StixKind::Malware && _target == StixKind::AttackPattern {
                 self.relationships.malware_to_techniques.insert(_er);
             }
             // weapon/non-malware <---> technique
-            else if _er.source.starts_with("tool") && _er.target.starts_with("attack-pattern") {
+            else if _source == StixKind::Tool && _target == StixKind::AttackPattern {
                 self.relationships.tool_to_techniques.insert(_er);
             }            
         }
+        else if _relationship["relationship_type"] == "mitigates" {
+            _er.relation_type = "mitigates".to_string();
+            // mitigation <---> technique
+            if _source == StixKind::CourseOfAction && _target == StixKind::AttackPattern {
+                self.relationships.mitigation_to_techniques.insert(_er);
+            }
+        }
+        else if _relationship["relationship_type"] == "revoked-by" {
+            _er.relation_type = "revoked-by".to_string();
+            // revoked technique <---> replacement technique
+            if _source == StixKind::AttackPattern && _target == StixKind::AttackPattern {
+                self.relationships.revoked_by.insert(_er);
+            }
+        }
         Ok(())
     }
     fn correlate_relationships(&mut self)
@@ -885,13 +1036,13 @@ impl EnterpriseMatrixParser {
                     for _technique in self.details.breakdown_techniques.platforms.iter() {
                         if _behavior.target.as_str() == _technique.id.as_str() {
                             _adversary.profile.techniques.items.push(_technique.tid.clone());
-                            _adversary.profile.tactics.items.push(_technique.tactic.clone());
+                            _adversary.profile.tactics.items.push(_technique.tactic.to_string());
                         }
                     }
                     for _subtechnique in self.details.breakdown_subtechniques.platforms.iter() {
                         if _behavior.target.as_str() == _subtechnique.id.as_str() {
                             _adversary.profile.subtechniques.items.push(_subtechnique.tid.clone());
-                            _adversary.profile.tactics.items.push(_subtechnique.tactic.clone());
+                            _adversary.profile.tactics.items.push(_subtechnique.tactic.to_string());
                         }
                     }
                 }
@@ -934,5 +1085,60 @@ impl EnterpriseMatrixParser {
             }
             _tool.profile.update();
         }
+        // Mitigations to Techniques and Subtechniques
+        for _mitigation in self.details.breakdown_mitigations.iter_mut() {
+            for _control in self.relationships.mitigation_to_techniques.iter() {
+                if _mitigation.id.as_str() == _control.source.as_str() {
+                    for _technique in self.details.breakdown_techniques.platforms.iter() {
+                        if _control.target.as_str() == _technique.id.as_str() {
+                            _mitigation.profile.techniques.items.push(_technique.tid.clone())
+                        }
+                    }
+                    for _subtechnique in self.details.breakdown_subtechniques.platforms.iter() {
+                        if _control.target.as_str() == _subtechnique.id.as_str() {
+                            _mitigation.profile.subtechniques.items.push(_subtechnique.tid.clone())
+                        }
+                    }
+                }
+            }
+            _mitigation.profile.update();
+        }
+        // Revoked Techniques to their Replacements
+        let mut _tids: HashMap<&str, &str> = HashMap::new();
+        for (_id, _tid) in self.revoked_ids.iter() {
+            _tids.insert(_id.as_str(), _tid.as_str());
+        }
+        for _technique in self.details.breakdown_techniques.platforms.iter()
+            .chain(self.details.breakdown_subtechniques.platforms.iter()) {
+            _tids.insert(_technique.id.as_str(), _technique.tid.as_str());
+        }
+        let mut _revocations: Vec<(String, String)> = vec![];
+        for _revocation in self.relationships.revoked_by.iter() {
+            if let (Some(_old), Some(_new)) = (_tids.get(_revocation.source.as_str()), _tids.get(_revocation.target.as_str())) {
+                _revocations.push((_old.to_string(), _new.to_string()));
+            }
+        }
+        self.details.revoked_by.extend(_revocations);
     }
-}
\ No newline at end of file
+}
+/// The baseline of the parser, as pretty JSON.
+impl fmt::Display for EnterpriseMatrixParser {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        write!(f, "{}", serde_json::to_string_pretty(&self.details).map_err(|_| fmt::Error)?)
+    }
+}
+impl Default for EnterpriseMatrixParser {
+    fn default() -> Self
+    {
+        EnterpriseMatrixParser::new()
+    }
+}
+
+
+/// # Malformed (Private Function)
+/// Returns the error of a bundle missing a key or a value the parser requires.
+fn malformed(reason: &str) -> AssistantError
+{
+    AssistantError::Parse(format!("Malformed ATT&CK Bundle: {}", reason))
+}
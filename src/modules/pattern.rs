@@ -0,0 +1,203 @@
+use regex::{ Regex, RegexBuilder };
+use serde_derive::{ Deserialize, Serialize };
+
+use std::collections::{ HashMap, HashSet };
+
+
+use crate::modules::parser::EnterpriseMatrixBreakdown;
+use crate::structs::enterprise::EnterpriseDescription;
+use crate::structs::errors::AssistantError;
+
+
+/// The characters shown on each side of a match in a description.
+const CONTEXT: usize = 40;
+
+
+/// # Pattern Match
+/// A technique, subtechnique, adversary, malware, tool or mitigation matched by a
+/// regular expression of the user.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct PatternMatch {
+    pub kind:       String,
+    pub id:         String,
+    pub name:       String,
+    /// The first field which matched: `id`, `name`, `alias`, `description` or `detection`
+    pub field:      String,
+    /// The match between `**`, with some context in a description
+    pub matched:    String,
+}
+
+
+/// # Mitre-Assistant: Pattern Module
+/// Matches a regular expression against the IDs, the names, the aliases and the
+/// descriptions of every kind of object of a baseline.
+///
+/// The pattern ignores the case, unless it starts with `(?-i)`.  A baseline written
+/// by an earlier release has no descriptions, only its IDs, names and aliases match.
+///
+/// ## Example
+/// ```ignore
+/// let _matcher = PatternMatcher::new(r"^T1003(\.00[1-3])?$")?;
+/// let _matches = _matcher.search(&_json);
+/// ```
+pub struct PatternMatcher {
+    pattern:    Regex,
+}
+impl PatternMatcher {
+    /// # Pattern - New
+    /// Compiles the pattern, an invalid pattern is an `InvalidQuery` telling where
+    /// the pattern is wrong.
+    pub fn new(pattern: &str) -> Result<Self, AssistantError>
+    {
+        if pattern.trim().is_empty() {
+            return Err(AssistantError::InvalidQuery("Invalid Pattern | The Pattern Is Empty".to_string()));
+        }
+        let _pattern = RegexBuilder::new(pattern)
+                        .case_insensitive(true)
+                        .size_limit(1 << 20)
+                        .build()?;
+        Ok(PatternMatcher { pattern: _pattern })
+    }
+    /// # Pattern - Search
+    /// Returns the objects matching the pattern, in the order of the matrix.
+    pub fn search<'a>(&self, baseline: &'a EnterpriseMatrixBreakdown) -> Vec<PatternMatch>
+    {
+        let _json = baseline;
+        let _descriptions: HashMap<(&str, &str), &EnterpriseDescription> = _json.descriptions.iter()
+            .map(|_d| ((_d.kind.as_str(), _d.id.as_str()), _d))
+            .collect();
+        let mut _seen: HashSet<(&str, &str)> = HashSet::new();
+        let mut _matches: Vec<PatternMatch> = vec![];
+        let mut _add = |_kind: &'static str, _id: &'a str, _name: &str, _aliases: &[String]| {
+            if !_seen.insert((_kind, _id)) {
+                return;
+            }
+            let _description = _descriptions.get(&(_kind, _id));
+            let _fields = vec![("id", _id), ("name", _name)].into_iter()
+                .chain(_aliases.iter().map(|_a| ("alias", _a.as_str())))
+                .chain(_description.map(|_d| ("description", _d.description.as_str())))
+                .chain(_description.map(|_d| ("detection", _d.detection.as_str())));
+            for (_field, _text) in _fields.filter(|(_, _t)| *_t != "none") {
+                // An empty match - e.g., of `x*` - would match everything
+                if let Some(_found) = self.pattern.find_iter(_text).find(|_m| _m.start() < _m.end()) {
+                    _matches.push(PatternMatch {
+                        kind:       _kind.to_string(),
+                        id:         _id.to_string(),
+                        name:       _name.to_string(),
+                        field:      _field.to_string(),
+                        matched:    highlight(_text, _found.start(), _found.end()),
+                    });
+                    return;
+                }
+            }
+        };
+        for _item in _json.breakdown_techniques.platforms.iter() {
+            _add("technique", _item.tid.as_str(), _item.technique.as_str(), &[]);
+        }
+        for _item in _json.breakdown_subtechniques.platforms.iter() {
+            _add("subtechnique", _item.tid.as_str(), _item.technique.as_str(), &[]);
+        }
+        for _item in _json.breakdown_adversaries.iter().filter(|_a| !_a.is_revoked) {
+            _add("adversary", _item.group_id.as_str(), _item.name.as_str(), &_item.aliases);
+        }
+        for _item in _json.breakdown_malware.iter().filter(|_m| !_m.is_revoked) {
+            _add("malware", _item.malware_id.as_str(), _item.name.as_str(), &_item.aliases);
+        }
+        for _item in _json.breakdown_tools.iter().filter(|_t| !_t.is_revoked) {
+            _add("tool", _item.tool_id.as_str(), _item.name.as_str(), &_item.aliases);
+        }
+        for _item in _json.breakdown_mitigations.iter().filter(|_m| !_m.is_deprecated) {
+            _add("mitigation", _item.mitigation_id.as_str(), _item.name.as_str(), &[]);
+        }
+        _matches
+    }
+}
+
+
+/// # Highlight (Private Function)
+/// Puts a match between `**`, a long text is cut to `CONTEXT` characters around it.
+fn highlight(text: &str, start: usize, end: usize) -> String
+{
+    let _before: String = text[..start].chars().rev().take(CONTEXT).collect::<Vec<char>>().into_iter().rev().collect();
+    let _after: String = text[end..].chars().take(CONTEXT).collect();
+    format!("{}{}**{}**{}{}",
+            if _before.len() < start { "..." } else { "" },
+            _before,
+            &text[start..end],
+            _after,
+            if _after.len() < text.len() - end { "..." } else { "" })
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::enterprise::{ EnterpriseTechnique, EnterpriseTool };
+
+    fn baseline() -> EnterpriseMatrixBreakdown
+    {
+        let mut _json = EnterpriseMatrixBreakdown::default();
+        for (_tid, _name) in [("T1003", "OS Credential Dumping"), ("T1059", "Command and Scripting Interpreter")].iter() {
+            let mut _technique = EnterpriseTechnique::new();
+            _technique.tid = _tid.to_string();
+            _technique.technique = _name.to_string();
+            _json.breakdown_techniques.platforms.push(_technique);
+        }
+        let mut _subtechnique = EnterpriseTechnique::new();
+        _subtechnique.tid = "T1003.001".to_string();
+        _subtechnique.technique = "LSASS Memory".to_string();
+        _json.breakdown_subtechniques.platforms.push(_subtechnique);
+        for (_id, _name, _revoked) in [("S0002", "Mimikatz", false), ("S0001", "Mimikatz", true)].iter() {
+            let mut _tool = EnterpriseTool::new();
+            _tool.tool_id = _id.to_string();
+            _tool.name = _name.to_string();
+            _tool.aliases = vec!["mimi".to_string()];
+            _tool.is_revoked = *_revoked;
+            _json.breakdown_tools.push(_tool);
+        }
+        _json.descriptions.push(EnterpriseDescription {
+            kind:           "subtechnique".to_string(),
+            id:             "T1003.001".to_string(),
+            name:           "LSASS Memory".to_string(),
+            description:    "Adversaries may attempt to access credential material stored in the process memory of the Local Security Authority Subsystem Service.".to_string(),
+            detection:      "Monitor for unexpected processes interacting with lsass.exe.".to_string(),
+        });
+        _json
+    }
+
+    fn found(pattern: &str) -> Vec<(String, String)>
+    {
+        PatternMatcher::new(pattern).unwrap().search(&baseline()).into_iter()
+            .map(|_m| (_m.id, _m.field))
+            .collect()
+    }
+
+    #[test]
+    fn search_tells_the_first_field_which_matched()
+    {
+        let _pairs = |_p: &[(&str, &str)]| _p.iter().map(|(_a, _b)| (_a.to_string(), _b.to_string())).collect::<Vec<_>>();
+        assert_eq!(found(r"^T1003(\.001)?$"), _pairs(&[("T1003", "id"), ("T1003.001", "id")]));
+        assert_eq!(found("credential"), _pairs(&[("T1003", "name"), ("T1003.001", "description")]));
+        assert_eq!(found(r"lsass\.exe"), _pairs(&[("T1003.001", "detection")]));
+        assert_eq!(found("^mimi$"), _pairs(&[("S0002", "alias")]));
+        assert_eq!(found("(?-i)^command"), vec![]);
+        assert!(found("q*").is_empty());
+    }
+
+    #[test]
+    fn search_highlights_the_match_in_its_context()
+    {
+        let _matches = PatternMatcher::new("authority").unwrap().search(&baseline());
+        assert_eq!(_matches.len(), 1);
+        assert_eq!(_matches[0].matched, "...he process memory of the Local Security **Authority** Subsystem Service.");
+        assert_eq!(highlight("LSASS Memory", 0, 5), "**LSASS** Memory");
+        assert_eq!(highlight("é memory", 3, 9), "é **memory**");
+    }
+
+    #[test]
+    fn new_rejects_the_empty_and_the_invalid_patterns()
+    {
+        assert!(matches!(PatternMatcher::new("  "), Err(AssistantError::InvalidQuery(_))));
+        assert!(PatternMatcher::new("T1003(").is_err());
+    }
+}
@@ -0,0 +1,265 @@
+use std::collections::BTreeMap;
+
+use serde_derive::{ Deserialize, Serialize };
+
+
+use crate::modules::parser::EnterpriseMatrixBreakdown;
+use crate::structs::enterprise::EnterpriseAdversary;
+use crate::utils::fshandler::FileHandler;
+use crate::structs::errors::AssistantError;
+use crate::structs::types::{ Datasource, Platform, Tactic };
+
+
+/// # Technique
+/// A technique or subtechnique of the baseline.  The baseline keeps a row per
+/// tactic of a technique, the query merges them into one technique.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Technique {
+    pub tid:                String,
+    pub name:               String,
+    pub is_subtechnique:    bool,
+    pub tactics:            Vec<Tactic>,
+    pub platforms:          Vec<Platform>,
+    pub datasources:        Vec<Datasource>,
+    pub subtechniques:      Vec<String>,
+}
+
+
+/// # Mitre-Assistant: Query Module
+/// The typed query API of the library, to look up the techniques and the adversary
+/// profiles of an enterprise baseline without printing anything.
+///
+/// IDs, names, tactics and platforms are matched ignoring the case.
+///
+/// ## Example
+/// ```ignore
+/// use mitre_assistant::EnterpriseQuery;
+///
+/// let _query = EnterpriseQuery::load("None")?;
+///
+/// let _technique = _query.technique("T1003.001");
+/// let _windows = _query.techniques_by_platform("windows");
+/// let _apt29 = _query.adversary("cozy bear").map(|_a| _a.profile.techniques.items.clone());
+/// ```
+pub struct EnterpriseQuery {
+    pub baseline:   EnterpriseMatrixBreakdown,
+    techniques:     Vec<Technique>,
+}
+impl EnterpriseQuery {
+    /// # Query - Load
+    /// Loads the enterprise baseline of the `version` from the assistant's folder,
+    /// use `"None"` for the baseline created without a version.
+    pub fn load(version: &str) -> Result<Self, AssistantError>
+    {
+        let _baseline = FileHandler::versioned_name("baseline-enterprise", version);
+        match FileHandler::read_resource("baselines", _baseline.as_str()) {
+            Some(_content) => EnterpriseQuery::from_json(_content.as_str()),
+            None => Err(AssistantError::MissingResource(format!("Baseline Not Found: {} | Create It With: `baseline -m enterprise`", _baseline)))
+        }
+    }
+    /// # Query - From JSON
+    /// Reads a baseline from its JSON, e.g., a baseline shipped with a service.
+    pub fn from_json(content: &str) -> Result<Self, AssistantError>
+    {
+        let _baseline: EnterpriseMatrixBreakdown = serde_json::from_str(content)?;
+        Ok(EnterpriseQuery::from_baseline(_baseline))
+    }
+    /// # Query - From Baseline
+    /// Wraps a baseline, e.g., the `details` of a parser that just parsed a matrix.
+    pub fn from_baseline(baseline: EnterpriseMatrixBreakdown) -> Self
+    {
+        let mut _techniques: BTreeMap<String, Technique> = BTreeMap::new();
+        let _rows = baseline.breakdown_techniques.platforms.iter().map(|_t| (false, _t))
+            .chain(baseline.breakdown_subtechniques.platforms.iter().map(|_t| (true, _t)));
+        for (_is_subtechnique, _row) in _rows {
+            let _technique = _techniques.entry(_row.tid.clone()).or_insert_with(|| Technique {
+                tid:                _row.tid.clone(),
+                name:               _row.technique.clone(),
+                is_subtechnique:    _is_subtechnique,
+                tactics:            vec![],
+                platforms:          _row.platform.clone(),
+                datasources:        _row.datasources.clone(),
+                subtechniques:      vec![],
+            });
+            if _row.tactic != Tactic::None {
+                _technique.tactics.push(_row.tactic.clone());
+            }
+            _technique.tactics.sort();
+            _technique.tactics.dedup();
+            _technique.subtechniques.extend(_row.subtechniques.iter().cloned());
+            _technique.subtechniques.sort();
+            _technique.subtechniques.dedup();
+        }
+        EnterpriseQuery {
+            baseline,
+            techniques: _techniques.into_values().collect(),
+        }
+    }
+    /// # Query - Techniques
+    /// Returns the active techniques and subtechniques, sorted by ID.
+    pub fn techniques(&self) -> &[Technique]
+    {
+        &self.techniques
+    }
+    /// # Query - Technique
+    /// Finds a technique or subtechnique by its ID - e.g., `T1003.001`.
+    pub fn technique(&self, tid: &str) -> Option<&Technique>
+    {
+        let _tid = tid.trim().to_uppercase();
+        self.techniques.iter().find(|_t| _t.tid == _tid)
+    }
+    /// # Query - Techniques By Name
+    /// Finds the techniques and subtechniques whose name contains `name`.
+    pub fn techniques_by_name(&self, name: &str) -> Vec<&Technique>
+    {
+        let _name = name.trim().to_lowercase();
+        self.techniques.iter().filter(|_t| _t.name.to_lowercase().contains(_name.as_str())).collect()
+    }
+    /// # Query - Techniques By Tactic
+    /// Lists the techniques and subtechniques of a tactic - e.g., `credential-access`
+    /// or `Credential Access`.
+    pub fn techniques_by_tactic(&self, tactic: &str) -> Vec<&Technique>
+    {
+        let _tactic = Tactic::parse(tactic);
+        self.techniques.iter().filter(|_t| _t.tactics.contains(&_tactic)).collect()
+    }
+    /// # Query - Techniques By Platform
+    /// Lists the techniques and subtechniques of a platform - e.g., `windows`.
+    pub fn techniques_by_platform(&self, platform: &str) -> Vec<&Technique>
+    {
+        let _platform = Platform::parse(platform);
+        self.techniques.iter().filter(|_t| _t.platforms.contains(&_platform)).collect()
+    }
+    /// # Query - Subtechniques
+    /// Lists the subtechniques of a technique.
+    pub fn subtechniques(&self, tid: &str) -> Vec<&Technique>
+    {
+        match self.technique(tid) {
+            Some(_technique) => _technique.subtechniques.iter().filter_map(|_s| self.technique(_s)).collect(),
+            None => vec![]
+        }
+    }
+    /// # Query - Adversaries
+    /// Returns the adversary profiles that are not revoked.
+    pub fn adversaries(&self) -> Vec<&EnterpriseAdversary>
+    {
+        self.baseline.breakdown_adversaries.iter().filter(|_a| !_a.is_revoked).collect()
+    }
+    /// # Query - Adversary
+    /// Finds an adversary profile by name, group ID or alias - e.g., `APT29`,
+    /// `G0016` or `cozy bear`.
+    pub fn adversary(&self, name: &str) -> Option<&EnterpriseAdversary>
+    {
+        self.adversaries().into_iter().find(|_a| _a.is_named(name))
+    }
+    /// # Query - Adversaries Using
+    /// Lists the adversary profiles using a technique or subtechnique.
+    pub fn adversaries_using(&self, tid: &str) -> Vec<&EnterpriseAdversary>
+    {
+        self.adversaries().into_iter().filter(|_a| _a.uses(tid)).collect()
+    }
+}
+/// A query of an empty baseline.
+impl Default for EnterpriseQuery {
+    fn default() -> Self
+    {
+        EnterpriseQuery::from_baseline(EnterpriseMatrixBreakdown::default())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::enterprise::EnterpriseTechnique;
+
+    fn row(tid: &str, name: &str, tactic: Tactic, platforms: &[Platform], subtechniques: &[&str]) -> EnterpriseTechnique
+    {
+        let mut _row = EnterpriseTechnique::new();
+        _row.tid = tid.to_string();
+        _row.technique = name.to_string();
+        _row.tactic = tactic;
+        _row.platform = platforms.to_vec();
+        _row.subtechniques = subtechniques.iter().map(|_s| _s.to_string()).collect();
+        _row.has_subtechniques = !subtechniques.is_empty();
+        _row
+    }
+
+    fn adversary(gid: &str, name: &str, aliases: &[&str], techniques: &[&str], is_revoked: bool) -> EnterpriseAdversary
+    {
+        let mut _adversary = EnterpriseAdversary::new();
+        _adversary.group_id = gid.to_string();
+        _adversary.name = name.to_string();
+        _adversary.aliases = aliases.iter().map(|_a| _a.to_string()).collect();
+        _adversary.profile.techniques.items = techniques.iter().map(|_t| _t.to_string()).collect();
+        _adversary.is_revoked = is_revoked;
+        _adversary
+    }
+
+    fn query() -> EnterpriseQuery
+    {
+        let mut _baseline = EnterpriseMatrixBreakdown::default();
+        _baseline.breakdown_techniques.platforms = vec![
+            row("T1003", "OS Credential Dumping", Tactic::CredentialAccess, &[Platform::Windows, Platform::Linux], &["T1003.001"]),
+            row("T1078", "Valid Accounts", Tactic::Persistence, &[Platform::Windows, Platform::Aws], &[]),
+            row("T1078", "Valid Accounts", Tactic::DefenseEvasion, &[Platform::Windows, Platform::Aws], &[]),
+            row("T1078", "Valid Accounts", Tactic::None, &[Platform::Windows, Platform::Aws], &[]),
+        ];
+        _baseline.breakdown_subtechniques.platforms = vec![
+            row("T1003.001", "LSASS Memory", Tactic::CredentialAccess, &[Platform::Windows], &[]),
+        ];
+        _baseline.breakdown_adversaries = vec![
+            adversary("G0016", "APT29", &["cozy-bear"], &["T1078"], false),
+            adversary("G0099", "Revoked Group", &[], &["T1078"], true),
+        ];
+        EnterpriseQuery::from_baseline(_baseline)
+    }
+
+    #[test]
+    fn from_baseline_merges_the_rows_of_a_technique()
+    {
+        let _query = query();
+        let _ids: Vec<&str> = _query.techniques().iter().map(|_t| _t.tid.as_str()).collect();
+        assert_eq!(_ids, vec!["T1003", "T1003.001", "T1078"]);
+        let _accounts = _query.technique("t1078").unwrap();
+        assert_eq!(_accounts.tactics, vec![Tactic::Persistence, Tactic::DefenseEvasion]);
+        assert!(!_accounts.is_subtechnique);
+        assert!(_query.technique("T1003.001").unwrap().is_subtechnique);
+        assert!(_query.technique("T9999").is_none());
+    }
+
+    #[test]
+    fn techniques_filter_by_name_tactic_and_platform()
+    {
+        let _query = query();
+        let _ids = |_found: Vec<&Technique>| _found.iter().map(|_t| _t.tid.clone()).collect::<Vec<String>>();
+        assert_eq!(_ids(_query.techniques_by_name("credential")), vec!["T1003"]);
+        assert_eq!(_ids(_query.techniques_by_tactic("Credential Access")), vec!["T1003", "T1003.001"]);
+        assert_eq!(_ids(_query.techniques_by_tactic("defense-evasion")), vec!["T1078"]);
+        assert_eq!(_ids(_query.techniques_by_platform("AWS")), vec!["T1078"]);
+        assert_eq!(_ids(_query.techniques_by_platform("linux")), vec!["T1003"]);
+        assert_eq!(_ids(_query.subtechniques("T1003")), vec!["T1003.001"]);
+        assert!(_query.subtechniques("T9999").is_empty());
+    }
+
+    #[test]
+    fn adversaries_skip_the_revoked_profiles()
+    {
+        let _query = query();
+        assert_eq!(_query.adversaries().len(), 1);
+        assert_eq!(_query.adversary("cozy bear").map(|_a| _a.group_id.as_str()), Some("G0016"));
+        assert_eq!(_query.adversary("g0016").map(|_a| _a.name.as_str()), Some("APT29"));
+        assert!(_query.adversary("Revoked Group").is_none());
+        assert_eq!(_query.adversaries_using("t1078").len(), 1);
+        assert!(_query.adversaries_using("T1003").is_empty());
+    }
+
+    #[test]
+    fn from_json_reads_a_baseline()
+    {
+        let _json = serde_json::to_string(&query().baseline).unwrap();
+        assert_eq!(EnterpriseQuery::from_json(_json.as_str()).unwrap().techniques().len(), 3);
+        assert!(EnterpriseQuery::from_json("{}").is_err());
+        assert!(EnterpriseQuery::default().techniques().is_empty());
+    }
+}
@@ -1,462 +1,392 @@
 use serde_json;
 use prettytable::{Table, Row, Cell};
 
-use std::collections::HashSet;
+use std::cell::RefCell;
+use std::collections::{ HashMap, HashSet };
 
 
-#[path = "./parser.rs"]
-mod parser;
-use parser::EnterpriseMatrixBreakdown;
-
-
-#[path = "../structs/enterprise.rs"]
-mod enterprise;
-use enterprise::{
+use crate::modules::parser::EnterpriseMatrixBreakdown;
+use crate::structs::enterprise::{
     EnterpriseAdversary,
     EnterpriseMalware,
+    EnterpriseTool,
     EnterpriseTechnique,
     EnterpriseMatrixStatistics};
-
-
-#[path = "../utils/fshandler.rs"]
-mod fshandler;
-use fshandler::FileHandler;
-
-
-#[path = "../utils/regexes.rs"]
-mod regexes;
-use regexes::RegexPatternManager;
+use crate::utils::fshandler::FileHandler;
+use crate::modules::navigator::NavigatorLayer;
+use crate::modules::expression::{ SearchExpression, SearchTerm };
+use crate::modules::detail::ObjectDetail;
+use crate::modules::fuzzy::{ FuzzyMatch, FuzzyMatcher };
+use crate::modules::fulltext::{ FullTextIndex, FullTextMatch };
+use crate::modules::pattern::{ PatternMatch, PatternMatcher };
+use crate::utils::markup::MarkupRenderer;
+use crate::structs::errors::AssistantError;
+use crate::structs::types::{ join, Datasource, Platform, Tactic };
 
 
 pub struct EnterpriseMatrixSearcher{
-    matrix:     String,
-    content:    Vec<u8> 
+    baseline:   EnterpriseMatrixBreakdown,
+    term:       RefCell<String>,                // The search term being rendered
+    filter:     Option<SearchExpression>,       // The filter flags, applied to the techniques found
+    sections:   RefCell<Vec<(String, String)>>  // The HTML tables collected for a report
 }
 impl EnterpriseMatrixSearcher {
-    pub fn new(matrix_type: &str) -> Self
+    /// # Searcher - Constructor
+    /// Loads the baseline of the `version` of a matrix, only the enterprise matrix
+    /// can be searched.
+    ///
+    /// ```ignore
+    /// let _searcher = EnterpriseMatrixSearcher::new("enterprise", "None")?;
+    /// ```
+    pub fn new(matrix_type: &str, version: &str) -> Result<Self, AssistantError>
     {
         let _input = matrix_type.to_lowercase();
-        let mut _content: Vec<u8> = vec![];
-        if _input == "enterprise".to_string() {
-            _content = FileHandler::load_baseline("baselines", "baseline-enterprise.json");
+        if _input != "enterprise" {
+            return Err(AssistantError::InvalidQuery(format!("Search Is Not Supported For The Matrix: {}", matrix_type)));
+        }
+        let _baseline = FileHandler::versioned_name("baseline-enterprise", version);
+        if FileHandler::read_resource("baselines", _baseline.as_str()).is_none() {
+            return Err(AssistantError::MissingResource(format!("Baseline Not Found: {} | Create It With: `baseline -m enterprise`", _baseline)));
         }
+        let _content = FileHandler::load_baseline("baselines", _baseline.as_str())?;
+        Ok(EnterpriseMatrixSearcher::from_baseline(serde_json::from_slice(&_content[..])?))
+    }
+    /// # Searcher - From Baseline
+    /// Searches a baseline already loaded.
+    pub fn from_baseline(baseline: EnterpriseMatrixBreakdown) -> Self
+    {
         EnterpriseMatrixSearcher {
-            matrix:  _input,
-            content: _content
-        } 
+            baseline,
+            term:     RefCell::new(String::new()),
+            filter:   None,
+            sections: RefCell::new(vec![])
+        }
+    }
+    /// # Searcher - With Filter
+    /// Keeps only the techniques and subtechniques matching a filter, whatever the
+    /// search term - e.g., the `--platform` and `--tactic` flags of `search`.
+    ///
+    /// ```ignore
+    /// let _filter = SearchExpression::from_filters(&[("platform", "linux"), ("tactic", "persistence")])?;
+    /// let _searcher = EnterpriseMatrixSearcher::new("enterprise", "None")?.with_filter(_filter)?;
+    /// ```
+    pub fn with_filter(mut self, filter: Option<SearchExpression>) -> Result<Self, AssistantError>
+    {
+        self.set_filter(filter)?;
+        Ok(self)
+    }
+    /// # Searcher - Set Filter
+    /// Replaces the filter of the next searches, `None` removes it.
+    pub fn set_filter(&mut self, filter: Option<SearchExpression>) -> Result<(), AssistantError>
+    {
+        if let Some(_filter) = &filter {
+            _filter.validate(&self.baseline)?;
+        }
+        self.filter = filter;
+        Ok(())
+    }
+    /// # Searcher - Baseline
+    /// The baseline loaded by the searcher.
+    pub fn baseline(&self) -> &EnterpriseMatrixBreakdown
+    {
+        &self.baseline
+    }
+    pub fn save_csv_export(&self, _wants_outfile: &str, _table: &Table) -> Result<(), AssistantError>
+    {
+        _table.to_csv(FileHandler::open(_wants_outfile, "crw")?.handle)?;
+        Ok(())
     }
-    pub fn save_csv_export(&self, _wants_outfile: &str, _table: &Table)
+    /// # Searcher - Save Navigator Export
+    /// Saves an ATT&CK Navigator layer, printed to `stdout` when there is no output file.
+    pub fn save_navigator_export(&self, _wants_outfile: &str, _layer: &mut NavigatorLayer) -> Result<(), AssistantError>
     {
-        let _fp = FileHandler::open(_wants_outfile, "crw");
-        _table.to_csv(_fp.handle).expect("(?) Error: Unable to Save CSV Output File");
+        _layer.save(_wants_outfile)
+    }
+    /// # Searcher - Save Markup Export
+    /// Saves a table as a Markdown section or a self-contained HTML page, printed to
+    /// `stdout` when there is no output file.  With the `report` export, the table
+    /// is kept as a section of the report instead.
+    pub fn save_markup_export(&self, _wants_export: &str, _wants_outfile: &str, _title: &str, _table: &Table) -> Result<(), AssistantError>
+    {
+        let _content = match _wants_export {
+            "report" => {
+                self.sections.borrow_mut().push((_title.to_string(), MarkupRenderer::html_table(_table)));
+                return Ok(());
+            },
+            "markdown" => MarkupRenderer::markdown(_title, _table),
+            _ => MarkupRenderer::html_document(_title, &[(_title.to_string(), MarkupRenderer::html_table(_table))])
+        };
+        match _wants_outfile {
+            "None" => print!("{}", _content),
+            _ => FileHandler::open(_wants_outfile, "crw")?.write(&_content)?
+        }
+        Ok(())
+    }
+    /// # Searcher - Render Table
+    /// Prints a table, or saves it with the export of the user: the `csv_table` as a
+    /// CSV file, or the table as Markdown, HTML or a section of the report.  Renderers
+    /// without a plain table for CSV pass the same table twice.
+    pub fn render_table(&self,
+        _wants_export: &str,
+        _wants_outfile: &str,
+        _title: &str,
+        _table: &Table,
+        _csv_table: &Table
+    ) -> Result<(), AssistantError>
+    {
+        match _wants_export {
+            "csv" => self.save_csv_export(_wants_outfile, _csv_table)?,
+            "markdown" | "html" | "report" => self.save_markup_export(_wants_export, _wants_outfile, _title, _table)?,
+            _ => {
+                println!("\n\n");
+                _table.printstd();
+                println!("\n\n");
+            }
+        }
+        Ok(())
+    }
+    /// # Searcher - Report
+    /// Saves a self-contained HTML report of the matrix: the stats, the techniques of
+    /// each tactic and the profiles of the `adversaries` - a comma separated list of
+    /// names, or `"None"` for all of them.
+    ///
+    /// ```ignore
+    /// let _searcher = EnterpriseMatrixSearcher::new("enterprise", "None");
+    ///
+    /// _searcher.report("apt29,fin7", "briefing.html")?;
+    /// ```
+    pub fn report(&self, adversaries: &str, _wants_outfile: &str) -> Result<(), AssistantError>
+    {
+        let _json = &self.baseline;
+        self.sections.borrow_mut().clear();
+        self.search("stats", false, "report", "None")?;
+        // The tactics of the baseline, in the order of the kill chain
+        for _tactic in Tactic::ALL.iter().filter(|_t| _json.tactics.contains(*_t)) {
+            self.search(_tactic.as_str(), false, "report", "None")?;
+        }
+        match adversaries {
+            "None" => self.search("adversaries", false, "report", "None")?,
+            _ => self.search(adversaries, false, "report", "None")?
+        }
+        let _sections: Vec<(String, String)> = self.sections.borrow_mut().drain(..).collect();
+        let _content = MarkupRenderer::html_document("Mitre-Assistant: Enterprise Matrix Report", &_sections);
+        match _wants_outfile {
+            "None" => print!("{}", _content),
+            _ => FileHandler::open(_wants_outfile, "crw")?.write(&_content)?
+        }
+        Ok(())
+    }
+    /// # Searcher - Full-Text Search
+    /// Searches the words of the descriptions and the detections, the results are
+    /// ranked and shown with a snippet of their text.
+    ///
+    /// ```ignore
+    /// _searcher.search_text("scheduled task xml", "None", "None")?;
+    /// ```
+    pub fn search_text(&self,
+        search_term: &str,
+        _wants_export: &str,
+        _wants_outfile: &str
+    ) -> Result<(), AssistantError>
+    {
+        self.term.replace(search_term.trim().to_lowercase());
+        let _index = FullTextIndex::new(&self.baseline);
+        if _index.is_empty() {
+            return Err(AssistantError::MissingResource("Descriptions Not Found In The Baseline | Create It Again With: `baseline -m enterprise`".to_string()));
+        }
+        let mut _matches = _index.search(search_term);
+        if self.filter.is_some() {
+            _matches.retain(|_m| (_m.kind == "technique" || _m.kind == "subtechnique") && self.passes_filter(_m.id.as_str(), None));
+        }
+        let _results = vec![serde_json::to_string(&_matches)?];
+        if _wants_export == "json" || _wants_export == "jsonl" {
+            self.render_enterprise_json(&_results, "fulltext", _wants_export, _wants_outfile)?;
+        } else {
+            self.render_enterprise_fulltext_table(&_results, _wants_export, _wants_outfile)?;
+        }
+        Ok(())
+    }
+    /// # Searcher - Regex Search
+    /// Matches a regular expression against the IDs, the names, the aliases and the
+    /// descriptions of every kind of object.
+    ///
+    /// ```ignore
+    /// _searcher.search_regex(r"^T1003\.00[1-3]$", "None", "None")?;
+    /// ```
+    pub fn search_regex(&self,
+        search_term: &str,
+        _wants_export: &str,
+        _wants_outfile: &str
+    ) -> Result<(), AssistantError>
+    {
+        self.term.replace(search_term.to_string());
+        let _matcher = PatternMatcher::new(search_term)?;
+        let mut _matches = _matcher.search(&self.baseline);
+        if self.filter.is_some() {
+            _matches.retain(|_m| (_m.kind == "technique" || _m.kind == "subtechnique") && self.passes_filter(_m.id.as_str(), None));
+        }
+        let _results = vec![serde_json::to_string(&_matches)?];
+        if _wants_export == "json" || _wants_export == "jsonl" {
+            self.render_enterprise_json(&_results, "regex", _wants_export, _wants_outfile)?;
+        } else {
+            self.render_enterprise_regex_table(&_results, _wants_export, _wants_outfile)?;
+        }
+        Ok(())
+    }
+    /// # Searcher - Show
+    /// Shows everything the baseline stores about one object, found by its ID or
+    /// its name - as text, or exported as `json`, `jsonl` or `markdown`.
+    ///
+    /// ```ignore
+    /// _searcher.show("T1003.001", "None", "None")?;
+    /// _searcher.show("apt29", "markdown", "apt29.md")?;
+    /// ```
+    pub fn show(&self,
+        search_term: &str,
+        _wants_export: &str,
+        _wants_outfile: &str
+    ) -> Result<(), AssistantError>
+    {
+        let _detail = ObjectDetail::find(&self.baseline, search_term)?;
+        let _content = match _wants_export {
+            "None" => _detail.to_text(),
+            "json" => format!("{}\n", serde_json::to_string_pretty(&_detail)?),
+            "jsonl" => format!("{}\n", serde_json::to_string(&_detail)?),
+            "markdown" => _detail.to_markdown(),
+            _ => return Err(AssistantError::InvalidQuery(format!("Export Format Not Supported: {} | Use: json, jsonl or markdown", _wants_export)))
+        };
+        match _wants_outfile {
+            "None" => print!("{}", _content),
+            _ => FileHandler::open(_wants_outfile, "crw")?.write(&_content)?
+        }
+        Ok(())
     }
     pub fn search(&self,
         search_term: &str,
         _wants_subtechniques: bool,
         _wants_export: &str,
         _wants_outfile: &str
-    )
+    ) -> Result<(), AssistantError>
     {
         let search_term = search_term.trim_end();
+        self.term.replace(search_term.to_lowercase());
         let mut _results: Vec<String> = vec![];
-        let mut _valid: Vec<(&str, usize)> = vec![];
-        let _st = search_term.to_lowercase();
-        let _st = _st.as_str();
-        let _json: EnterpriseMatrixBreakdown = serde_json::from_slice(&self.content[..]).unwrap();
-        let _scanner = RegexPatternManager::load_search_term_patterns();
-        let _scanner_ad = RegexPatternManager::load_search_adversaries(&_json.adversaries);
-        let _scanner_mw = RegexPatternManager::load_search_malware(&_json.malware);
-        let _scanner_to = RegexPatternManager::load_search_tools(&_json.tools);
-        let _scanner_ds = RegexPatternManager::load_search_datasources(&_json.datasources, &_json.platforms);
-        // Special Flags
-        //      Easier to search this way without flooding the user with parameters
-        //      These flags are commonly placed in both the query and render functions
+        let _json = &self.baseline;
+        // Search Terms
+        //      A term is read once by the expression module - e.g., an expression, a keyword
+        //      or a comma list of IDs - and each term is redirected to its query function.
+        //      Any query function must return a Stringified Vector from
+        //      the `EnterpriseMatrixBreakdown` struct.
         //
-        let mut _matches_many: Vec<usize> = vec![];
-        //
-        //
-        let mut _wants_stats: bool = false;                         // Returns The Stats Key
-        let mut _wants_nosub: bool = false;                         // Returns Techniques That Don't Have Subtechniques
-        let mut _wants_revoked: bool = false;                       // Returns Techniques Revoked By Mitre
-        let mut _wants_tactics: bool = false;                       // Returns The Tactics Key
-        let mut _wants_platforms: bool = false;                     // Returns The Platforms Key
-        let mut _wants_deprecated: bool = false;                    // Returns The Deprecated Techniques
-        let mut _wants_datasources: bool = false;                   // Returns The Data Sources Key
-        let mut _wants_adversary: bool = false;
-        let mut _wants_malware: bool = false;
-        let mut _wants_tool: bool = false;
-        let mut _wants_all_adversaries: bool = false;
-        let mut _wants_all_malware: bool = false;
-        let mut _wants_all_tools: bool = false;
-        let mut _wants_xref_datasources_tactics: bool = false;      // Returns The Stats Count XREF of Datasoources By Tactic
-        let mut _wants_xref_datasources_platforms: bool = false;    // Return The Stats Count XREF of Datasources By Platform
-        // Parse the search term explicitly
-        //      We are not using partial matches on search term keywords
-        //      We keep a simple incrementing usize by search term
-        if _st == "revoked" {
-            _valid.push((_st, 3usize));
-            _wants_revoked = true;
-        }
-        else if _st == "stats" {
-            _valid.push((_st, 4usize));
-            _wants_stats = true;
-        }
-        else if _st == "nosub" {
-            _valid.push((_st, 5usize));
-            _wants_nosub = true;
-        }
-        else if _st == "techniques" {
-            _valid.push((_st, 6usize)); 
-        }
-        else if _st == "subtechniques" {
-            _valid.push((_st, 7usize));     
-        }
-        else if _st == "datasources" {
-            _valid.push((_st, 8usize));     
-            _wants_datasources = true;
-        }
-        else if _st == "platforms" {
-            _valid.push((_st, 9usize));     
-            _wants_platforms = true;
-        }
-        else if _st == "nodatasources" {
-            _valid.push((_st, 10usize));
-        }
-        else if _st == "tactics" {
-            _valid.push((_st, 11usize));
-            _wants_tactics = true;
-        }
-        else if _st == "deprecated" {
-            _valid.push((_st, 12usize));
-            _wants_deprecated = true;
-        }
-        else if _st == "initial-access" {
-            _valid.push((_st, 13usize));
-        }
-        else if _st == "execution" {
-            _valid.push((_st, 14usize));
-        }
-        else if _st == "persistence" {
-            _valid.push((_st, 15usize));
-        }
-        else if _st == "privilege-escalation" {
-            _valid.push((_st, 16usize));
-        }
-        else if _st == "defense-evasion" {
-            _valid.push((_st, 17usize));
-        }
-        else if _st == "credential-access" {
-            _valid.push((_st, 18usize));
-        }   
-        else if _st == "discovery" {
-            _valid.push((_st, 19usize));
-        }
-        else if _st == "lateral-movement" {
-            _valid.push((_st, 20usize));
-        }
-        else if _st == "collection" {
-            _valid.push((_st, 21usize));
-        }
-        else if _st == "command-and-control" {
-            _valid.push((_st, 22usize));
-        }
-        else if _st == "exfiltration" {
-            _valid.push((_st, 23usize));
-        }
-        else if _st == "impact" {
-            _valid.push((_st, 24usize));
-        }
-        else if _st == "aws" {
-            _valid.push((_st, 25usize));
-        }
-        else if _st == "azure" {
-            _valid.push((_st, 26usize));
-        }
-        else if _st == "azure-ad" {
-            _valid.push((_st, 27usize));
-        }
-        else if _st == "gcp" {
-            _valid.push((_st, 28usize));
-        }
-        else if _st == "linux" {
-            _valid.push((_st, 29usize));
-        }
-        else if _st == "macos" {
-            _valid.push((_st, 30usize));
-        }
-        else if _st == "office-365" {
-            _valid.push((_st, 31usize));
-        }
-        else if _st == "saas" {
-            _valid.push((_st, 32usize));
-        }
-        else if _st == "windows" {
-            _valid.push((_st, 33usize));
-        }
-        else if _st == "overlap" {
-            _valid.push((_st, 34usize));
-        }
-        else if _st == "xref:datasources:platforms" {
-            _valid.push((_st, 35usize));
-            _wants_xref_datasources_platforms = true;
-        }
-        else if _st == "xref:datasources:tactics" {
-            _valid.push((_st, 36usize));
-            _wants_xref_datasources_tactics = true;
-        }
-        else if _scanner_ds.pattern.is_match(_st) {
-            let _idx: Vec<usize> = _scanner_ds.pattern.matches(_st).into_iter().collect();
-            _valid.push((_st, 37usize));
-            //_wants_by_datasource = true;
-            //println!("Matches:\n{:#?}", _idx);
-        }
-        // Adversaries
-        else if _scanner_ad.pattern.is_match(_st) {
-            _matches_many = _scanner_ad.pattern.matches(_st).into_iter().collect();
-            _valid.push((_st, 38usize));
-            _wants_adversary = true;
-            //_wants_by_datasource = true;
-            //println!("Matches:\n{:#?}", _matches_many);
-        }          
-        // Malware
-        else if _scanner_mw.pattern.is_match(_st) {
-            let _idx: Vec<usize> = _scanner_mw.pattern.matches(_st).into_iter().collect();
-            _valid.push((_st, 39usize));
-            _wants_malware = true;
-            //_wants_by_datasource = true;
-            //println!("Matches:\n{:#?}", _idx);
-        }          
-        // Tools  
-        else if _scanner_to.pattern.is_match(_st) {
-            let _idx: Vec<usize> = _scanner_to.pattern.matches(_st).into_iter().collect();
-            _valid.push((_st, 40usize));
-            _wants_tool  = true;
-        }
-        else if _st == "adversaries" {
-            _valid.push((_st, 41usize));
-            _wants_all_adversaries = true;
-        }
-        else if _st == "malware" {
-            _valid.push((_st, 42usize));
-            _wants_all_malware = true;
-        }
-        else if _st == "tools" {
-            _valid.push((_st, 43usize));
-            _wants_all_tools = true;
-        }                                                                                 
-        else if !_st.contains(",") {
-            if _scanner.pattern.is_match(_st) {
-                let _idx: Vec<usize> = _scanner.pattern.matches(_st).into_iter().collect();
-                _valid.push((_st, _idx[0]));  // Search Term 0usize
+        let _terms = SearchTerm::read(search_term, _json)?;
+        if _terms.is_empty() {
+            if _wants_export == "json" || _wants_export == "jsonl" {
+                self.render_enterprise_json(&_results, "techniques", _wants_export, _wants_outfile)?;
+            } else {
+                println!(r#"[ "Results": None Found, "SearchTerm": {} ]"#, search_term);
+            }
+            return Ok(());
+        }
+        // The names of a term, with the IDs of its comma list, are ranked together
+        // against the names of any kind
+        let _wants_names = _terms.iter().any(|_t| matches!(_t, SearchTerm::Name(_)));
+        if _wants_names {
+            let _names: Vec<&str> = _terms.iter()
+                .filter_map(|_t| match _t {
+                    SearchTerm::Name(_name) | SearchTerm::Id(_name) | SearchTerm::SubtechniqueId(_name) => Some(_name.as_str()),
+                    _ => None
+                })
+                .collect();
+            _results.push(self.search_by_fuzzy_name(&_names)?);
+        } else {
+            for _term in _terms.iter() {
+                let _result = match _term {
+                    SearchTerm::Expression(_expression) => self.search_by_expression(_expression, _wants_subtechniques)?,
+                    SearchTerm::Id(_tid) => self.search_by_id(_tid, _wants_subtechniques)?,
+                    SearchTerm::SubtechniqueId(_tid) => self.search_by_subtechnique_id(_tid)?,
+                    SearchTerm::Name(_name) => self.search_by_fuzzy_name(&[_name.as_str()])?,
+                    SearchTerm::Tactic(_tactic) => self.search_by_tactic(_tactic.as_str(), _wants_subtechniques)?,
+                    SearchTerm::Platform(_platform) => self.search_by_platform(_platform.as_str(), _wants_subtechniques)?,
+                    SearchTerm::Datasource(_datasource) => self.search_by_datasource(_datasource, _wants_subtechniques)?,
+                    SearchTerm::Adversary(_adversary) => self.search_by_adversary(_adversary)?,
+                    SearchTerm::Malware(_malware) => self.search_by_malware(_malware)?,
+                    SearchTerm::Tool(_tool) => self.search_by_tool(_tool)?,
+                    SearchTerm::Revoked => self.search_revoked()?,
+                    SearchTerm::Deprecated => self.search_by_deprecated()?,
+                    SearchTerm::Stats => self.search_stats()?,
+                    SearchTerm::Overlap => self.search_all_overlapped()?,
+                    SearchTerm::NoSubtechniques => self.search_by_no_subtechniques()?,
+                    SearchTerm::NoDatasources => self.search_by_no_datasources()?,
+                    SearchTerm::AllTechniques if _wants_subtechniques => {
+                        _results.push(self.search_all_techniques()?);
+                        self.search_all_subtechniques()?
+                    },
+                    SearchTerm::AllTechniques => self.search_all_techniques()?,
+                    SearchTerm::AllSubtechniques => self.search_all_subtechniques()?,
+                    SearchTerm::AllTactics => self.search_all_tactics()?,
+                    SearchTerm::AllPlatforms => self.search_all_platforms()?,
+                    SearchTerm::AllDatasources => self.search_all_datasources()?,
+                    SearchTerm::AllAdversaries => self.search_all_adversaries()?,
+                    SearchTerm::AllMalware => self.search_all_malware()?,
+                    SearchTerm::AllTools => self.search_all_tools()?,
+                    SearchTerm::XrefDatasourcesPlatforms => self.search_stats_datasources_and_platforms()?,
+                    SearchTerm::XrefDatasourcesTactics => self.search_stats_datasources_and_tactics()?,
+                };
+                _results.push(_result);
             }
         }
-        else if _st.contains(",") {
-            let _terms: Vec<&str> = _st.split(',').collect();
-            _valid = _terms.iter()
-                        .filter(|_x| _scanner.pattern.is_match(_x))
-                        .map(|_x| {
-                            let _idx: Vec<_> = _scanner.pattern.matches(_x).into_iter().collect();
-                            (*_x, _idx[0]) // Search Term 1usize
-                        })
-                        .collect();
-        }        
-        // Query
-        // —————
-        // Once a full match is valid and a pattern is assigned
-        // let's redirect the pattern to the relevant query function
-        //      Notice:     Based on the pattern usize, a specific function is called.
-        //                  Any query function must return a Stringified Vector from
-        //                  the `EnterpriseMatrixBreakdown` struct.
-        if _valid.len() >= 1 {
-            for (_term, _pattern) in _valid.iter() {
-                if _pattern == &0usize {
-                    _results.push(self.search_by_id(_term, _wants_subtechniques));
-                }
-                else if _pattern == &1usize {
-                    _results.push(self.search_by_subtechnique_id(_term));
-                }
-                else if _pattern == &2usize {
-                    _results.push(self.search_by_name(_term));
-                }
-                else if _pattern == &3usize {
-                    _results.push(self.search_revoked());
-                }
-                else if _pattern == &4usize {
-                    _results.push(self.search_stats());
-                }
-                else if _pattern == &5usize {
-                    _results.push(self.search_by_no_subtechniques());
-                }
-                else if _pattern == &6usize {
-                    _results.push(self.search_all_techniques());
-                }
-                else if _pattern == &7usize {
-                    _results.push(self.search_all_subtechniques());
-                }
-                else if _pattern == &8usize {
-                    _results.push(self.search_all_datasources());
-                }
-                else if _pattern == &9usize {
-                    _results.push(self.search_all_platforms());
-                }
-                else if _pattern == &10usize {
-                    _results.push(self.search_by_no_datasources());
-                }
-                else if _pattern == &11usize {
-                    _results.push(self.search_all_tactics());
-                }
-                else if _pattern == &12usize {
-                    _results.push(self.search_by_deprecated());
-                }
-                else if _pattern == &13usize {
-                    _results.push(self.search_by_tactic(_term, _wants_subtechniques));
-                }
-                else if _pattern == &14usize {
-                    _results.push(self.search_by_tactic(_term, _wants_subtechniques));
-                }
-                else if _pattern == &15usize {
-                    _results.push(self.search_by_tactic(_term, _wants_subtechniques));
-                } 
-                else if _pattern == &16usize {
-                    _results.push(self.search_by_tactic(_term, _wants_subtechniques));
-                }
-                else if _pattern == &17usize {
-                    _results.push(self.search_by_tactic(_term, _wants_subtechniques));
-                }
-                else if _pattern == &18usize {
-                    _results.push(self.search_by_tactic(_term, _wants_subtechniques));
-                }
-                else if _pattern == &19usize {
-                    _results.push(self.search_by_tactic(_term, _wants_subtechniques));
-                }
-                else if _pattern == &20usize {
-                    _results.push(self.search_by_tactic(_term, _wants_subtechniques));
-                }
-                else if _pattern == &21usize {
-                    _results.push(self.search_by_tactic(_term , _wants_subtechniques));
-                }
-                else if _pattern == &22usize {
-                    _results.push(self.search_by_tactic(_term, _wants_subtechniques));
-                }
-                else if _pattern == &23usize {
-                    _results.push(self.search_by_tactic(_term, _wants_subtechniques));
-                }
-                else if _pattern == &24usize {
-                    _results.push(self.search_by_tactic(_term, _wants_subtechniques));
-                }
-                else if _pattern == &25usize {
-                    _results.push(self.search_by_platform(_term, _wants_subtechniques));
-                }
-                else if _pattern == &26usize {
-                    _results.push(self.search_by_platform(_term, _wants_subtechniques));
-                }
-                else if _pattern == &27usize {
-                    _results.push(self.search_by_platform(_term, _wants_subtechniques));
-                }
-                else if _pattern == &28usize {
-                    _results.push(self.search_by_platform(_term, _wants_subtechniques));
-                }
-                else if _pattern == &29usize {
-                    _results.push(self.search_by_platform(_term, _wants_subtechniques));
-                }
-                else if _pattern == &30usize {
-                    _results.push(self.search_by_platform(_term, _wants_subtechniques));
-                }
-                else if _pattern == &31usize {
-                    _results.push(self.search_by_platform(_term, _wants_subtechniques));
-                }
-                else if _pattern == &32usize {
-                    _results.push(self.search_by_platform(_term, _wants_subtechniques));
-                }
-                else if _pattern == &33usize {
-                    _results.push(self.search_by_platform(_term, _wants_subtechniques));
-                }
-                else if _pattern == &34usize {
-                    _results.push(self.search_all_overlapped());
-                }
-                else if _pattern == &35usize {
-                    _results.push(self.search_stats_datasources_and_platforms());
-                }
-                else if _pattern == &36usize {
-                    _results.push(self.search_stats_datasources_and_tactics());
-                }
-                else if _pattern == &37usize {
-                    _results.push(self.search_by_datasource(_term, _wants_subtechniques));
-                }
-                else if _pattern == &38usize {
-                    _results.push(self.search_by_adversary(_term, _matches_many.clone()));
-                }
-                else if _pattern == &39usize {
-                    _results.push(self.search_by_malware(_term));
-                }
-                else if _pattern == &40usize {
-                    _results.push(self.search_by_tool(_term));
-                }
-                else if _pattern == &41usize {
-                    _results.push(self.search_all_adversaries());
-                } 
-                else if _pattern == &42usize {
-                    _results.push(self.search_all_malware());
+        let _kind = match _wants_names {
+            true => "names",
+            false => _terms[0].kind()
+        };
+        // Filter Flags
+        //      Only the techniques and subtechniques found can be filtered
+        //
+        if let Some(_filter) = &self.filter {
+            if _kind == "names" {
+                let mut _filtered: Vec<String> = vec![];
+                for _result in _results.iter() {
+                    let mut _matches: Vec<FuzzyMatch> = serde_json::from_str(_result.as_str())?;
+                    _matches.retain(|_m| (_m.kind == "technique" || _m.kind == "subtechnique") && self.passes_filter(_m.id.as_str(), None));
+                    _filtered.push(serde_json::to_string(&_matches)?);
                 }
-                else if _pattern == &43usize {
-                    _results.push(self.search_all_tools());
-                }                                                                                                                                                                                                                                                                                                                                                                                                              
-            }
-            // Render Query Results
-            // --------------------
-            // Upon getting search query results, apply a renderer to present results.
-            // By default, pretty tables are used to render results.
-            //
-            //      Note:   Transforming results into CSV, JSON should be done within
-            //              the renderer functions.
-            //   
-            if _wants_adversary {
-                self.render_enterprise_adversaries_table(&_results, _wants_export, _wants_outfile);
-            }
-            else if _wants_malware {
-                1 + 1;
-            }
-            else if _wants_tool {
-                1 + 1;
-            }
-            else if _wants_all_adversaries {
-                self.render_enterprise_adversaries_table(&_results, _wants_export, _wants_outfile);
-            }     
-            else if _wants_all_malware {
-                self.render_enterprise_malware_table(&_results, _wants_export, _wants_outfile);
-            }
-            else if _wants_all_tools {
-                self.render_enterprise_tools_table(&_results, _wants_export, _wants_outfile);
-            }                               
-            else if _wants_revoked {
-                self.render_enterprise_revoked_table(&_results, _wants_export, _wants_outfile);
-            }
-            else if _wants_stats {
-                self.render_enterprise_stats(&_results, _wants_export, _wants_outfile);
-            }
-            else if _wants_datasources {
-                self.render_enterprise_datasources_table(&_results, _wants_export, _wants_outfile);
-            }
-            else if _wants_platforms {
-                self.render_enterprise_platforms_table(&_results, _wants_export, _wants_outfile);
-            }
-            else if _wants_tactics {
-                self.render_enterprise_tactics_table(&_results, _wants_export, _wants_outfile);
-            }
-            else if _wants_deprecated {
-                self.render_enterprise_deprecated_table(&_results, _wants_export, _wants_outfile);
-            }
-            else if _wants_xref_datasources_platforms {
-                self.render_enterprise_stats_xref_datasource_platforms(&_results, _wants_export, _wants_outfile);
-            }
-            else if _wants_xref_datasources_tactics {
-                self.render_enterprise_stats_xref_datasource_tactics(&_results, _wants_export, _wants_outfile);
-            }
-            else {
-                self.render_enterprise_table(&_results, _wants_export, _wants_outfile);
+                _results = _filtered;
+            } else if _kind == "techniques" {
+                _results = self.filter_rows(&_results)?;
+            } else {
+                return Err(AssistantError::InvalidQuery(format!("Filters Apply To Techniques & Subtechniques Only, Not To: {}", search_term)));
             }
-        } else {
-            println!(r#"[ "Results": {}, "SearchTerm": {} ]"#, "None Found", search_term);
+        }
+        if _wants_export != "json" && _wants_export != "jsonl" && _kind == "techniques"
+            && _results.iter().all(|_r| serde_json::from_str::<Vec<EnterpriseTechnique>>(_r).map(|_rows| _rows.is_empty()).unwrap_or(false)) {
+            println!(r#"[ "Results": None Found, "SearchTerm": {} ]"#, search_term);
+            return Ok(());
+        }
+        // Render Query Results
+        // --------------------
+        // Upon getting search query results, apply a renderer to present results.
+        // By default, pretty tables are used to render results.
+        //
+        //      Note:   Transforming results into CSV, JSON should be done within
+        //              the renderer functions.
+        //
+        if _wants_export == "json" || _wants_export == "jsonl" {
+            return self.render_enterprise_json(&_results, _kind, _wants_export, _wants_outfile);
+        }
+        match _kind {
+            "adversaries" => self.render_enterprise_adversaries_table(&_results, _wants_export, _wants_outfile),
+            "malware" => self.render_enterprise_malware_table(&_results, _wants_export, _wants_outfile),
+            "tools" => self.render_enterprise_tools_table(&_results, _wants_export, _wants_outfile),
+            "revoked" => self.render_enterprise_revoked_table(&_results, _wants_export, _wants_outfile),
+            "deprecated" => self.render_enterprise_deprecated_table(&_results, _wants_export, _wants_outfile),
+            "stats" => self.render_enterprise_stats(&_results, _wants_export, _wants_outfile),
+            "tactics" => self.render_enterprise_tactics_table(&_results, _wants_export, _wants_outfile),
+            "platforms" => self.render_enterprise_platforms_table(&_results, _wants_export, _wants_outfile),
+            "datasources" => self.render_enterprise_datasources_table(&_results, _wants_export, _wants_outfile),
+            "xref:platforms" => self.render_enterprise_stats_xref_datasource_platforms(&_results, _wants_export, _wants_outfile),
+            "xref:tactics" => self.render_enterprise_stats_xref_datasource_tactics(&_results, _wants_export, _wants_outfile),
+            "names" => self.render_enterprise_names_table(&_results, _wants_export, _wants_outfile),
+            _ => self.render_enterprise_table(&_results, _wants_export, _wants_outfile)
         }
     }
     /// # **Query Functions**
@@ -467,7 +397,7 @@ impl EnterpriseMatrixSearcher {
     /// Query functions must return a Stringified version of a JSON object - i.e., Vec<EnterpriseTechnique>
     ///
     /// The searcher uses the `serde_json::to_string` method for the conversion of objects to provide the
-    /// Stringified version of the JSON object.
+    /// Stringified version of the JSON object, a failed conversion is returned as an `AssistantError`.
     ///
     ///
     /// ## **Query Functions Are Private**
@@ -475,85 +405,34 @@ impl EnterpriseMatrixSearcher {
     /// All of the functions are **private functions** that are not exposed to the end-user.  They are only accessible
     /// from the module itself, and specifically, when invoked by the `self.search()` method.
     ///
-    fn search_by_adversary(&self, adversary: &str, many: Vec<usize>) -> String
+    fn search_by_adversary(&self, adversary: &str) -> Result<String, AssistantError>
     {
-        let mut _results = vec![];
-        let adversary = adversary.to_lowercase();
-        let adversary = adversary.as_str();
-        let _msg = format!("(?) Error: Unable To Deserialize String of All Techniques by Adversary: {}", adversary);
-        let _json: EnterpriseMatrixBreakdown = serde_json::from_slice(&self.content[..]).expect(_msg.as_str());
-        let _msg = format!("(?) Error: Unable To Convert String of All Techniques by Adversary: {}", adversary);
-        if many.len() == 1 {
-            for _item in _json.breakdown_adversaries.iter() {
-                if _item.name.to_lowercase().as_str() == adversary {
-                    _results.push(_item);
-                }
-            }
-        } else {
-            if adversary.contains(",") {
-                let _terms: Vec<_> = adversary.split(',').collect();
-                for _term in _terms {
-                    for _item in _json.breakdown_adversaries.iter() {
-                        if _item.name.to_lowercase().as_str() == _term {
-                            _results.push(_item);
-                        }
-                    }
-                }
-            }
-        }
-        //println!("{}", serde_json::to_string_pretty(&_results).unwrap());
-        serde_json::to_string(&_results).expect(_msg.as_str())  
+        let _results: Vec<_> = self.baseline.breakdown_adversaries.iter()
+                                   .filter(|_item| _item.is_named(adversary))
+                                   .collect();
+        Ok(serde_json::to_string(&_results)?)
     }
-    ///
-    /// 
-    /// 
-    /// 
-    fn search_by_malware(&self, malware: &str) -> String
+    /// # Query By Malware
+    fn search_by_malware(&self, malware: &str) -> Result<String, AssistantError>
     {
-        let mut _results = vec![];
-        let malware = malware.to_lowercase();
-        let malware = malware.as_str();
-        let _msg = format!("(?) Error: Unable To Deserialize String of All Techniques by malware: {}", malware);
-        let _json: EnterpriseMatrixBreakdown = serde_json::from_slice(&self.content[..]).expect(_msg.as_str());
-        let _msg = format!("(?) Error: Unable To Convert String of All Techniques by malware: {}", malware);
-        for _weapon in _json.breakdown_malware.iter() {
-            if _weapon.name.to_lowercase().as_str() == malware {
-                _results.push(_weapon);
-            }
-        }
-        println!("{}", serde_json::to_string_pretty(&_results).unwrap());
-        serde_json::to_string(&_results).expect(_msg.as_str())  
+        let _results: Vec<_> = self.baseline.breakdown_malware.iter()
+                                   .filter(|_weapon| _weapon.is_named(malware))
+                                   .collect();
+        Ok(serde_json::to_string(&_results)?)
     }
-    ///
-    /// 
-    /// 
-    /// 
-    /// 
-    fn search_by_tool(&self, tool: &str) -> String
+    /// # Query By Tool
+    fn search_by_tool(&self, tool: &str) -> Result<String, AssistantError>
     {
-        let mut _results = vec![];
-        let tool = tool.to_lowercase();
-        let tool = tool.as_str();
-        let _msg = format!("(?) Error: Unable To Deserialize String of All Techniques by tool: {}", tool);
-        let _json: EnterpriseMatrixBreakdown = serde_json::from_slice(&self.content[..]).expect(_msg.as_str());
-        let _msg = format!("(?) Error: Unable To Convert String of All Techniques by tool: {}", tool);
-        for _weapon in _json.breakdown_tools.iter() {
-            if _weapon.name.to_lowercase().as_str() == tool {
-                _results.push(_weapon);
-            }
-        }
-        println!("{}", serde_json::to_string_pretty(&_results).unwrap());
-        serde_json::to_string(&_results).expect(_msg.as_str())  
+        let _results: Vec<_> = self.baseline.breakdown_tools.iter()
+                                   .filter(|_weapon| _weapon.is_named(tool))
+                                   .collect();
+        Ok(serde_json::to_string(&_results)?)
     }    
-    ///
-    /// 
-    /// 
-    /// 
-    fn search_by_datasource(&self, datasource: &str, _wants_subtechniques: bool) -> String
+    /// # Query By Datasource
+    fn search_by_datasource(&self, datasource: &str, _wants_subtechniques: bool) -> Result<String, AssistantError>
     {
         let mut _results = vec![];
-        let _msg = format!("(?) Error: Unable To Deserialize String of All Techniques by Datasource: {}", datasource);
-        let _json: EnterpriseMatrixBreakdown = serde_json::from_slice(&self.content[..]).expect(_msg.as_str());
+        let _json = &self.baseline;
         let mut _os: &str = "";
         let mut _terms: Vec<&str>;
         let mut _weird: bool = false;
@@ -564,25 +443,21 @@ impl EnterpriseMatrixSearcher {
             _os = "n_a";
             _terms = vec![_os, datasource];
         }
-        println!("{:#?}", _terms);
         
         // Cloud Operating System Weirdness
-        if  _terms[1].starts_with("aws")
+        if (_terms[1].starts_with("aws")
             || _terms[1].starts_with("azure")
-            || _terms[1].starts_with("stack-driver-logs")
+            || _terms[1].starts_with("stack-driver-logs"))
+            && _os != "aws"
+            && _os != "azure"
+            && _os != "gcp"
+            && _os != "saas"
+            && _os != "n_a"
         {
-            if _os != "aws"
-                && _os != "azure"
-                && _os != "gcp"
-                && _os != "saas"
-                && _os != "n_a"
-                {
-                    _weird = true;
-                }
-            print!("Weird: {}", _weird);
+            _weird = true;
         }
         // Client Operating System Weirdness
-        if _terms[1].starts_with("anti-virus")
+        if (_terms[1].starts_with("anti-virus")
             || _terms[1].starts_with("bios")
             || _terms[1].starts_with("browser-extensions")
             || _terms[1].starts_with("disk-forensics")
@@ -590,180 +465,95 @@ impl EnterpriseMatrixSearcher {
             || _terms[1].starts_with("named-pipes")
             || _terms[1].starts_with("vbr")
             || _terms[1].starts_with("wmi")
-            || _terms[1].starts_with("win")
+            || _terms[1].starts_with("win"))
+            && _os != "windows"
+            && _os != "macos"
+            && _os != "linux"
+            && _os != "n_a"
         {
-            if _os != "windows"
-                && _os != "macos"
-                && _os != "linux"
-                && _os != "n_a"
-                {
-                    _weird = true;
-                }
-            print!("Weird: {}", _weird);
+            _weird = true;
         }
         // Office 365 Weirdness
         if _terms[1].starts_with("office-365")
+            && _os != "office-365"
+            && _os != "n_a"
         {
-            if _os != "office-365"
-                && _os != "n_a"
-                {
-                    _weird = true;
-                }
-            print!("Weird: {}", _weird);
+            _weird = true;
         }
         if !_weird {
             if _wants_subtechniques {
-                println!("{:#?}", _terms);
-                for _item in _json.breakdown_subtechniques.platforms.iter() {
-                    if _item.datasources.contains(_terms[1]) {
-                        let mut _modified = EnterpriseTechnique::new();
-                        if _os == "None" {
-                            _modified.platform = _item.platform.clone();
-                        } else {
-                            _modified.platform = _os.to_string();
-                        }
-                        _modified.tid = _item.tid.clone();
-                        _modified.technique = _item.technique.clone();
-                        _modified.tactic = _item.tactic.clone();
-                        _modified.datasources = _terms[1].to_string();
-                        _modified.has_subtechniques = _item.has_subtechniques.clone();
-                        _modified.subtechniques = _item.subtechniques.clone();
-                        _results.push(_modified);
-                    }
-                }
-            } else {
-                println!("{:#?}", _terms);
-                for _item in _json.breakdown_techniques.platforms.iter() {
-                    if _item.datasources.contains(_terms[1]) {
-                        let mut _modified = EnterpriseTechnique::new();
-                        if _os == "None" {
-                            _modified.platform = _item.platform.clone();
-                        } else {
-                            _modified.platform = _os.to_string();
-                        }
-                        _modified.tid = _item.tid.clone();
-                        _modified.technique = _item.technique.clone();
-                        _modified.tactic = _item.tactic.clone();
-                        _modified.datasources = _terms[1].to_string();
-                        _modified.has_subtechniques = _item.has_subtechniques.clone();
-                        _modified.subtechniques = _item.subtechniques.clone();
-                        _results.push(_modified);
-                    }
-                }
-            }
-        }
-        /*
-        if (_terms[1].starts_with("win") && !_os.starts_with("windows") && !_os.starts_with("None"))
-            || (_terms[1].starts_with("wmi") && !_os.starts_with("windows") && !_os.starts_with("None"))
-            || (_terms[1].starts_with("gcp") && !_os.starts_with("gcp") && !_os.starts_with("None"))
-            || (_terms[1].starts_with("anti-virus") && !_os.starts_with("windows") && !_os.starts_with("None"))
-            || (_terms[1].starts_with("win"))
-            //|| (_terms[1].starts_with("anti-virus") && !_os.starts_with("linux") && !_os.starts_with("None"))
-            //|| (_terms[1].starts_with("anti-virus") && !_os.starts_with("macos") && !_os.starts_with("None"))
-            || (_terms[1].starts_with("bios") && !_os.starts_with("windows") && !_os.starts_with("None"))
-            || (_terms[1].starts_with("bios") && !_os.starts_with("linux") && !_os.starts_with("None"))
-            || (_terms[1].starts_with("bios") && !_os.starts_with("macos") && !_os.starts_with("None"))
-            || (_terms[1].starts_with("mbr") && !_os.starts_with("windows") && !_os.starts_with("None"))
-            || (_terms[1].starts_with("mbr") && !_os.starts_with("linux") && !_os.starts_with("None"))
-            || (_terms[1].starts_with("mbr") && !_os.starts_with("macos") && !_os.starts_with("None"))
-            || (_terms[1].starts_with("vbr") && !_os.starts_with("windows") && !_os.starts_with("None"))
-            || (_terms[1].starts_with("vbr") && !_os.starts_with("linux") && !_os.starts_with("None"))
-            || (_terms[1].starts_with("vbr") && !_os.starts_with("macos") && !_os.starts_with("None"))
-            || (_terms[1].starts_with("disk-forensics") && !_os.starts_with("windows") && !_os.starts_with("None"))
-            || (_terms[1].starts_with("disk-forensics") && !_os.starts_with("linux") && !_os.starts_with("None"))
-            || (_terms[1].starts_with("disk-forensics") && !_os.starts_with("macos") && !_os.starts_with("None"))
-            || (_terms[1].starts_with("browser-extensions") && !_os.starts_with("windows") && !_os.starts_with("None"))
-            || (_terms[1].starts_with("browser-extensions") && !_os.starts_with("linux") && !_os.starts_with("None"))
-            || (_terms[1].starts_with("browser-extensions") && !_os.starts_with("macos") && !_os.starts_with("None"))
-            || (_terms[1].starts_with("named-pipes") && !_os.starts_with("windows") && !_os.starts_with("None"))
-            || (_terms[1].starts_with("named-pipes") && !_os.starts_with("linux") && !_os.starts_with("None"))
-            || (_terms[1].starts_with("named-pipes") && !_os.starts_with("macos") && !_os.starts_with("None"))
-            || (_terms[1].starts_with("office-365") && !_os.starts_with("office-365") && !_os.starts_with("None"))
-            || (_terms[1].starts_with("stack-driver-logs") && !_os.starts_with("aws") && !_os.starts_with("None"))
-            || (_terms[1].starts_with("stack-driver-logs") && !_os.starts_with("azure") && !_os.starts_with("None"))
-            || (_terms[1].starts_with("stack-driver-logs") && !_os.starts_with("gcp") && !_os.starts_with("None"))
-            || (_terms[1].starts_with("stack-driver-logs") && !_os.starts_with("saas") && !_os.starts_with("None"))
-            */
-        /*else {
-            if _wants_subtechniques {
-                println!("{:#?}", _terms);
                 for _item in _json.breakdown_subtechniques.platforms.iter() {
-                    if _item.datasources.contains(_terms[1]) {
+                    if _item.datasources.contains(&Datasource::parse(_terms[1])) {
                         let mut _modified = EnterpriseTechnique::new();
                         if _os == "None" {
                             _modified.platform = _item.platform.clone();
                         } else {
-                            _modified.platform = _os.to_string();
+                            _modified.platform = vec![Platform::parse(_os)];
                         }
                         _modified.tid = _item.tid.clone();
                         _modified.technique = _item.technique.clone();
                         _modified.tactic = _item.tactic.clone();
-                        _modified.datasources = _terms[1].to_string();
-                        _modified.has_subtechniques = _item.has_subtechniques.clone();
+                        _modified.datasources = vec![Datasource::parse(_terms[1])];
+                        _modified.has_subtechniques = _item.has_subtechniques;
                         _modified.subtechniques = _item.subtechniques.clone();
                         _results.push(_modified);
                     }
                 }
             } else {
-                println!("{:#?}", _terms);
                 for _item in _json.breakdown_techniques.platforms.iter() {
-                    if _item.datasources.contains(_terms[1]) {
+                    if _item.datasources.contains(&Datasource::parse(_terms[1])) {
                         let mut _modified = EnterpriseTechnique::new();
                         if _os == "None" {
                             _modified.platform = _item.platform.clone();
                         } else {
-                            _modified.platform = _os.to_string();
+                            _modified.platform = vec![Platform::parse(_os)];
                         }
                         _modified.tid = _item.tid.clone();
                         _modified.technique = _item.technique.clone();
                         _modified.tactic = _item.tactic.clone();
-                        _modified.datasources = _terms[1].to_string();
-                        _modified.has_subtechniques = _item.has_subtechniques.clone();
+                        _modified.datasources = vec![Datasource::parse(_terms[1])];
+                        _modified.has_subtechniques = _item.has_subtechniques;
                         _modified.subtechniques = _item.subtechniques.clone();
                         _results.push(_modified);
                     }
                 }
             }
         }
-        */
-        let _msg = format!("(?) Error: Unable To Convert String of All Techniques by Datasource: {}", datasource);
-        serde_json::to_string(&_results).expect(_msg.as_str())           
+        Ok(serde_json::to_string(&_results)?)
     }
-    fn search_by_platform(&self, platform: &str, _wants_subtechniques: bool) -> String
+    fn search_by_platform(&self, platform: &str, _wants_subtechniques: bool) -> Result<String, AssistantError>
     {
         let mut _results = vec![];
-        let _msg = format!("(?) Error: Unable To Deserialize String of All Techniques by Platform: {}", platform);
-        let _json: EnterpriseMatrixBreakdown = serde_json::from_slice(&self.content[..]).expect(_msg.as_str());
+        let _json = &self.baseline;
         for _item in _json.breakdown_techniques.platforms.iter() {
-            if _item.platform.contains(platform) {
+            if _item.platform.contains(&Platform::parse(platform)) {
                 let mut _modified = EnterpriseTechnique::new();
                 _modified.tid = _item.tid.clone();
                 _modified.technique = _item.technique.clone();
                 _modified.tactic = _item.tactic.clone();
                 _modified.datasources = _item.datasources.clone();
-                _modified.has_subtechniques = _item.has_subtechniques.clone();
+                _modified.has_subtechniques = _item.has_subtechniques;
                 _modified.subtechniques = _item.subtechniques.clone();
-                _modified.platform = platform.to_string();
+                _modified.platform = vec![Platform::parse(platform)];
                 _results.push(_modified);
             }
         }
         if _wants_subtechniques {
             for _item in _json.breakdown_subtechniques.platforms.iter() {
-                if _item.platform.contains(platform) {
+                if _item.platform.contains(&Platform::parse(platform)) {
                     let mut _modified = EnterpriseTechnique::new();
                     _modified.tid = _item.tid.clone();
                     _modified.technique = _item.technique.clone();
                     _modified.tactic = _item.tactic.clone();
                     _modified.datasources = _item.datasources.clone();
-                    _modified.has_subtechniques = _item.has_subtechniques.clone();
+                    _modified.has_subtechniques = _item.has_subtechniques;
                     _modified.subtechniques = _item.subtechniques.clone();
-                    _modified.platform = platform.to_string();
+                    _modified.platform = vec![Platform::parse(platform)];
                     _results.push(_modified);
                 }
             }
         }
-        let _msg = format!("(?) Error: Unable To Convert String of All Techniques by Platform: {}", platform);
-        serde_json::to_string(&_results).expect(_msg.as_str())    
+        Ok(serde_json::to_string(&_results)?)
     }
     /// # Query By Tactics
     ///
@@ -772,25 +562,88 @@ impl EnterpriseMatrixSearcher {
     /// ```ignore
     /// self.search_by_tactic("initial-access", false)
     /// ```
-    fn search_by_tactic(&self, tactic: &str, _wants_subtechniques: bool) -> String
+    fn search_by_tactic(&self, tactic: &str, _wants_subtechniques: bool) -> Result<String, AssistantError>
     {
         let mut _results = vec![];
-        let _msg = format!("(?) Error: Unable To Deserialize String of All Techniques by Tactic: {}", tactic);
-        let _json: EnterpriseMatrixBreakdown = serde_json::from_slice(&self.content[..]).expect(_msg.as_str());
+        let _json = &self.baseline;
         for _item in _json.breakdown_techniques.platforms.iter() {
-            if _item.tactic.contains(tactic) {
+            if _item.tactic == Tactic::parse(tactic) {
                 _results.push(_item);
             }
         }
         if _wants_subtechniques {
             for _item in _json.breakdown_subtechniques.platforms.iter() {
-                if _item.tactic.contains(tactic) {
+                if _item.tactic == Tactic::parse(tactic) {
                     _results.push(_item);
                 }
             }
         }
-        let _msg = format!("(?) Error: Unable To Convert String of All Techniques by Tactic: {}", tactic);
-        serde_json::to_string(&_results).expect(_msg.as_str())
+        Ok(serde_json::to_string(&_results)?)
+    }
+    /// # Filter Rows
+    ///
+    /// Keeps the technique rows of the results which pass the filter flags.
+    ///
+    /// ```ignore
+    /// _results = self.filter_rows(&_results)?;
+    /// ```
+    fn filter_rows(&self, results: &[String]) -> Result<Vec<String>, AssistantError>
+    {
+        if self.filter.is_none() {
+            return Ok(results.to_vec());
+        }
+        let mut _filtered: Vec<String> = vec![];
+        for _result in results.iter() {
+            let mut _rows: Vec<EnterpriseTechnique> = serde_json::from_str(_result.as_str())?;
+            _rows.retain(|_row| {
+                let _tactic = match _row.tactic {
+                    Tactic::None => None,
+                    _ => Some(&_row.tactic)
+                };
+                self.passes_filter(_row.tid.as_str(), _tactic)
+            });
+            _filtered.push(serde_json::to_string(&_rows)?);
+        }
+        Ok(_filtered)
+    }
+    /// # Passes Filter
+    ///
+    /// Returns `true` when the technique or subtechnique of the baseline, in the tactic
+    /// when it is known, matches the filter flags.  The baseline row is used since a
+    /// query can narrow a row - e.g., to the platform searched.
+    fn passes_filter(&self, tid: &str, tactic: Option<&Tactic>) -> bool
+    {
+        let _json = &self.baseline;
+        match &self.filter {
+            None => true,
+            Some(_filter) => _json.breakdown_techniques.platforms.iter()
+                .chain(_json.breakdown_subtechniques.platforms.iter())
+                .filter(|_row| _row.tid == tid && match tactic {
+                    Some(_tactic) => &_row.tactic == _tactic,
+                    None => true
+                })
+                .any(|_row| _filter.matches(_row, _json))
+        }
+    }
+    /// # Query By Expression
+    ///
+    /// Allows the user to get the techniques matching an expression of the query language.
+    ///
+    /// ```ignore
+    /// let _expression = SearchExpression::parse("tactic:persistence AND NOT platform:windows")?;
+    /// self.search_by_expression(&_expression, false)
+    /// ```
+    fn search_by_expression(&self, expression: &SearchExpression, _wants_subtechniques: bool) -> Result<String, AssistantError>
+    {
+        let _json = &self.baseline;
+        let mut _results: Vec<&EnterpriseTechnique> = _json.breakdown_techniques.platforms.iter()
+                                                           .filter(|_item| expression.matches(_item, _json))
+                                                           .collect();
+        if _wants_subtechniques {
+            _results.extend(_json.breakdown_subtechniques.platforms.iter()
+                                 .filter(|_item| expression.matches(_item, _json)));
+        }
+        Ok(serde_json::to_string(&_results)?)
     }
     /// # Query By Deprecated Techniques
     ///
@@ -799,70 +652,48 @@ impl EnterpriseMatrixSearcher {
     /// ```ignore
     /// self.deprecated();
     /// ```
-    fn search_by_deprecated(&self) -> String
+    fn search_by_deprecated(&self) -> Result<String, AssistantError>
     {
         let mut _results = vec![];
-        let _json: EnterpriseMatrixBreakdown = serde_json::from_slice(&self.content[..]).expect("(?) Error: Unable to Deserialize All Deprecated Techniques");
-        for _item in _json.deprecated_techniques {
+        let _json = &self.baseline;
+        for _item in _json.deprecated_techniques.iter() {
             _results.push(_item)
         }
         _results.sort();
-        serde_json::to_string(&_results).expect("(?) Error: Unable To Deserialize String Of All Deprecated Techniques")        
+        Ok(serde_json::to_string(&_results)?)
     }
-    fn search_all_malware(&self) -> String
+    fn search_all_malware(&self) -> Result<String, AssistantError>
     {
         let mut _results = vec![];
-        let _json: EnterpriseMatrixBreakdown = serde_json::from_slice(&self.content[..]).expect("(?) Error: Unable to Deserialize All Malware");
-        for _item in _json.malware {
-            for _malware in _json.breakdown_malware.iter() {
-                if _malware.aliases.contains(&_item) {
-                    _results.push(_malware);
-                } else {
-                    _results.push(_malware);
-                }
-            }
+        let _json = &self.baseline;
+        for _malware in _json.breakdown_malware.iter() {
+            _results.push(_malware);
         }
         _results.sort();
         _results.dedup();
-        _results.sort();
-        serde_json::to_string(&_results).expect("(?) Error: Unable To Deserialize All Malware")
+        Ok(serde_json::to_string(&_results)?)
     }
-    fn search_all_tools(&self) -> String
+    fn search_all_tools(&self) -> Result<String, AssistantError>
     {
         let mut _results = vec![];
-        let _json: EnterpriseMatrixBreakdown = serde_json::from_slice(&self.content[..]).expect("(?) Error: Unable to Deserialize All Malware");
-        for _item in _json.tools {
-            for _tool in _json.breakdown_tools.iter() {
-                if _tool.aliases.contains(&_item) {
-                    _results.push((_tool.name.clone(), _tool.aliases.clone()));
-                } else {
-                    _results.push((_tool.name.clone(), _tool.aliases.clone()));
-                }
-            }
+        let _json = &self.baseline;
+        for _tool in _json.breakdown_tools.iter() {
+            _results.push(_tool);
         }
-        _results.sort();
+        _results.sort_by(|_a, _b| _a.name.cmp(&_b.name));
         _results.dedup();
-        _results.sort();
-        serde_json::to_string(&_results).expect("(?) Error: Unable To Deserialize All Malware")
+        Ok(serde_json::to_string(&_results)?)
     }       
-    fn search_all_adversaries(&self) -> String
+    fn search_all_adversaries(&self) -> Result<String, AssistantError>
     {
         let mut _results = vec![];
-        let _err = "(?) Error: Unable to Deserialize All Adversaries";
-        let _json: EnterpriseMatrixBreakdown = serde_json::from_slice(&self.content[..]).expect(_err);
-        for _item in _json.adversaries {
-            for _adversary in _json.breakdown_adversaries.iter() {
-                if _adversary.aliases.contains(&_item) {
-                    _results.push(_adversary);
-                } else {
-                    _results.push(_adversary);
-                }
-            }
+        let _json = &self.baseline;
+        for _adversary in _json.breakdown_adversaries.iter() {
+            _results.push(_adversary);
         }
         _results.sort();
         _results.dedup();
-        _results.sort();
-        serde_json::to_string(&_results).expect("(?) Error: Unable To Deserialize All Adversaries")
+        Ok(serde_json::to_string(&_results)?)
     }    
     /// # Query To Get All Active Tactics
     ///
@@ -871,15 +702,15 @@ impl EnterpriseMatrixSearcher {
     /// ```ignore
     /// self.search_all_tactics();
     /// ```
-    fn search_all_tactics(&self) -> String
+    fn search_all_tactics(&self) -> Result<String, AssistantError>
     {
         let mut _results = vec![];
-        let _json: EnterpriseMatrixBreakdown = serde_json::from_slice(&self.content[..]).expect("(?) Error: Unable to Deserialize All Tactics");
-        for _item in _json.tactics {
+        let _json = &self.baseline;
+        for _item in _json.tactics.iter() {
             _results.push(_item)
         }
         _results.sort();
-        serde_json::to_string(&_results).expect("(?) Error: Unable To Deserialize All Tactics")
+        Ok(serde_json::to_string(&_results)?)
     }
     /// # Query To Get All Overlapped Techniques
     ///
@@ -889,12 +720,11 @@ impl EnterpriseMatrixSearcher {
     /// ```ignore
     /// self.search_all_overlapped();
     /// ```
-    fn search_all_overlapped(&self) -> String
+    fn search_all_overlapped(&self) -> Result<String, AssistantError>
     {   
         let mut _results = vec![];
         let mut _targets = HashSet::new();
-        let _msg = "(?) Error: Unable to Deserialize All Overlapped Techniques";
-        let _json: EnterpriseMatrixBreakdown = serde_json::from_slice(&self.content[..]).expect(_msg);
+        let _json = &self.baseline;
         // Iterate the Unique Techniques Key
         // Find the Techniques with Overlap by Tactic
         for _technique in _json.uniques_techniques.iter() {
@@ -917,8 +747,7 @@ impl EnterpriseMatrixSearcher {
                 }
             }
         }
-        let _msg = "(?) Error: Unable to Convert All Overlapped Techniques";
-        serde_json::to_string(&_results).expect(_msg)
+        Ok(serde_json::to_string(&_results)?)
     }
     /// # Query All Active Techniques
     ///
@@ -927,10 +756,10 @@ impl EnterpriseMatrixSearcher {
     /// ```ignore
     /// self.search_all_techniques();
     /// ```
-    fn search_all_techniques(&self) -> String
+    fn search_all_techniques(&self) -> Result<String, AssistantError>
     {
-        let _json: EnterpriseMatrixBreakdown = serde_json::from_slice(&self.content[..]).unwrap();
-        serde_json::to_string(&_json.breakdown_techniques.platforms).expect("(?) Error: Unable To Deserialize All Techniques")
+        let _json = &self.baseline;
+        Ok(serde_json::to_string(&_json.breakdown_techniques.platforms)?)
     }
     /// # Query All Active Subtechniques
     ///
@@ -939,10 +768,10 @@ impl EnterpriseMatrixSearcher {
     /// ```ignore
     /// self.search_all_subtechniques();
     /// ```
-    fn search_all_subtechniques(&self) -> String
+    fn search_all_subtechniques(&self) -> Result<String, AssistantError>
     {
-        let _json: EnterpriseMatrixBreakdown = serde_json::from_slice(&self.content[..]).unwrap();
-        serde_json::to_string(&_json.breakdown_subtechniques.platforms).expect("(?) Error: Unable To Deserialize All Techniques")
+        let _json = &self.baseline;
+        Ok(serde_json::to_string(&_json.breakdown_subtechniques.platforms)?)
     }
     /// # Query All Platforms
     ///
@@ -951,10 +780,10 @@ impl EnterpriseMatrixSearcher {
     /// ```ignore
     /// self.search_all_platforms();
     /// ```
-    fn search_all_platforms(&self) -> String
+    fn search_all_platforms(&self) -> Result<String, AssistantError>
     {
-        let _json: EnterpriseMatrixBreakdown = serde_json::from_slice(&self.content[..]).unwrap();
-        serde_json::to_string(&_json.platforms).expect("(?) Error: Unable To Deserialize All Platforms")
+        let _json = &self.baseline;
+        Ok(serde_json::to_string(&_json.platforms)?)
     }
     /// # Query All Datasources
     ///
@@ -963,10 +792,10 @@ impl EnterpriseMatrixSearcher {
     /// ```ignore
     /// self.search_all_datasources();
     /// ```
-    fn search_all_datasources(&self) -> String
+    fn search_all_datasources(&self) -> Result<String, AssistantError>
     {
-        let _json: EnterpriseMatrixBreakdown = serde_json::from_slice(&self.content[..]).unwrap();
-        serde_json::to_string(&_json.datasources).expect("(?) Error: Unable To Deserialize All Datasources")
+        let _json = &self.baseline;
+        Ok(serde_json::to_string(&_json.datasources)?)
     }
     /// # Query All Techniques That Do Not have Datasources
     /// 
@@ -976,49 +805,35 @@ impl EnterpriseMatrixSearcher {
     /// ```ignore
     /// self.search_by_no_datasources();
     /// ```
-    fn search_by_no_datasources(&self) -> String
+    fn search_by_no_datasources(&self) -> Result<String, AssistantError>
     {
         let mut _results = vec![];
-        let _json: EnterpriseMatrixBreakdown = serde_json::from_slice(&self.content[..]).expect("(?) Error: Unable to Deserialize By No Datasources");
+        let _json = &self.baseline;
         for _item in _json.breakdown_techniques.platforms.iter() {
-            if _item.datasources.as_str() == "none" {
+            if _item.datasources.is_empty() {
                 _results.push(_item);
             }
         }
         for _item in _json.breakdown_subtechniques.platforms.iter() {
-            if _item.datasources.as_str() == "none" {
+            if _item.datasources.is_empty() {
                 _results.push(_item);
             }
         }
-        serde_json::to_string(&_results).expect("(?) Error: Unable To Serialize By No Datasources")
+        Ok(serde_json::to_string(&_results)?)
     }
-    /// # Query Techniques By Name
-    /// 
-    /// Allows the user to query techniques by their name, works as `partial match`
-    /// 
+    /// # Query Any Name
+    ///
+    /// Allows the user to find the techniques, subtechniques, adversaries, malware,
+    /// tools and mitigations by a name, an alias or an ID, ranked by their score.
+    /// The names of a comma list are ranked together.
+    ///
     /// ```ignore
-    /// self.search_by_name();
+    /// self.search_by_fuzzy_name(&["powershel", "credental dumping"]);
     /// ```
-    fn search_by_name(&self, technique_name: &str) -> String
+    fn search_by_fuzzy_name(&self, names: &[&str]) -> Result<String, AssistantError>
     {
-        let mut _results = vec![];
-        let _json: EnterpriseMatrixBreakdown = serde_json::from_slice(&self.content[..]).unwrap();
-        for _item in _json.breakdown_techniques.platforms.iter() {
-            if _item.technique.to_lowercase().as_str() == technique_name.to_lowercase().as_str() {
-                _results.push(_item);
-            } else if _item.technique.to_lowercase().as_str().contains(technique_name.to_lowercase().as_str()) {
-               _results.push(_item);
-            }
-        }
-        // Now Search Subtechniques
-        for _item in _json.breakdown_subtechniques.platforms.iter() {
-            if _item.technique.to_lowercase().as_str() == technique_name.to_lowercase().as_str() {
-                _results.push(_item);
-            } else if _item.technique.to_lowercase().as_str().contains(technique_name.to_lowercase().as_str()) {
-               _results.push(_item);
-            }
-        }        
-        serde_json::to_string_pretty(&_results).expect("(?) Error:  Unable To Deserialize Search Results By Technique Name")
+        let _results: Vec<FuzzyMatch> = FuzzyMatcher::new(&self.baseline).search_all(names);
+        Ok(serde_json::to_string(&_results)?)
     }
     /// # Query By Technique ID
     /// 
@@ -1030,11 +845,11 @@ impl EnterpriseMatrixSearcher {
     /// ```ignore
     /// self.search_by_id("t1021", false);
     /// ```
-    fn search_by_id(&self, technique_id: &str, _wants_subtechniques: bool) -> String
+    fn search_by_id(&self, technique_id: &str, _wants_subtechniques: bool) -> Result<String, AssistantError>
     {
         let mut _results = vec![];
         //let mut _temp = HashSet::new();
-        let _json: EnterpriseMatrixBreakdown = serde_json::from_slice(&self.content[..]).expect("HERE");
+        let _json = &self.baseline;
         for _item in _json.breakdown_techniques.platforms.iter() {
             if _item.tid.to_lowercase().as_str() == technique_id.to_lowercase().as_str() {
                 if _wants_subtechniques {
@@ -1053,7 +868,7 @@ impl EnterpriseMatrixSearcher {
                 }
             }
         }
-        if _results.len() == 0usize {
+        if _results.is_empty() {
             // If no results then we want to search for a two conditions
             //      1. When the user wants subtechniques, then get them
             //      2. Or, when there are revoked techniques, let's add these
@@ -1089,11 +904,11 @@ impl EnterpriseMatrixSearcher {
             }
             _results.sort();
             _results.dedup();
-            serde_json::to_string_pretty(&_results).expect("(?) Error:  Unable To Deserialize Search Results By Revoked Technique ID")
+            Ok(serde_json::to_string_pretty(&_results)?)
         } else {
             _results.sort();
             _results.dedup();
-            serde_json::to_string_pretty(&_results).expect("(?) Error:  Unable To Deserialize Search Results By Technique ID")
+            Ok(serde_json::to_string_pretty(&_results)?)
         }
     }
     /// # Query By Subtechnique ID
@@ -1103,16 +918,16 @@ impl EnterpriseMatrixSearcher {
     /// ```ignore
     /// self.search_by_subtechnique_id("t1021.001");
     /// ```
-    fn search_by_subtechnique_id(&self, technique_id: &str) -> String
+    fn search_by_subtechnique_id(&self, technique_id: &str) -> Result<String, AssistantError>
     {
         let mut _results = vec![];
-        let _json: EnterpriseMatrixBreakdown = serde_json::from_slice(&self.content[..]).unwrap();
+        let _json = &self.baseline;
         for _item in _json.breakdown_subtechniques.platforms.iter() {
             if _item.tid.to_lowercase().as_str() == technique_id.to_lowercase().as_str() {
                 _results.push(_item);
             }
         }
-        serde_json::to_string_pretty(&_results).expect("(?) Error:  Unable To Deserialize Search Results By Subtechnique ID")
+        Ok(serde_json::to_string_pretty(&_results)?)
     }
     /// # Query By Revoked Techniques
     /// 
@@ -1121,14 +936,14 @@ impl EnterpriseMatrixSearcher {
     /// ```ignore
     /// self.search_revoked();
     /// ```
-    fn search_revoked(&self) -> String
+    fn search_revoked(&self) -> Result<String, AssistantError>
     {
         let mut _results = vec![];
-        let _json: EnterpriseMatrixBreakdown = serde_json::from_slice(&self.content[..]).unwrap();
+        let _json = &self.baseline;
         for _item in _json.revoked_techniques.iter() {
             _results.push(_item);
         }
-        serde_json::to_string_pretty(&_results).expect("(?) Error:  Unable To Deserialize Search Results By Revoked Techniques")
+        Ok(serde_json::to_string_pretty(&_results)?)
     }
     /// # Query To Get A Stats Overview
     /// 
@@ -1138,10 +953,10 @@ impl EnterpriseMatrixSearcher {
     /// ```ignore
     /// self.search_stats();
     /// ```
-    fn search_stats(&self) -> String
+    fn search_stats(&self) -> Result<String, AssistantError>
     {
-        let _json: EnterpriseMatrixBreakdown = serde_json::from_slice(&self.content[..]).unwrap();
-        serde_json::to_string_pretty(&_json.stats).expect("(?) Error:  Unable To Deserialize Search Results By Enterprise Stats")
+        let _json = &self.baseline;
+        Ok(serde_json::to_string_pretty(&_json.stats)?)
     }
     /// # Query For All Subtechniques
     /// 
@@ -1150,15 +965,15 @@ impl EnterpriseMatrixSearcher {
     /// ```ignore
     /// self.search_by_no_subtechniques();
     /// ```
-    fn search_by_no_subtechniques(&self) -> String {
+    fn search_by_no_subtechniques(&self) -> Result<String, AssistantError> {
         let mut _results = vec![];
-        let _json: EnterpriseMatrixBreakdown = serde_json::from_slice(&self.content[..]).unwrap();
+        let _json = &self.baseline;
         for _item in _json.breakdown_techniques.platforms.iter() {
             if !_item.has_subtechniques {
                 _results.push(_item);
             }
         }
-        serde_json::to_string_pretty(&_results).expect("(?) Error: Unable To Deserialize Search Results By HAS_NO_SUBTECHNIQUES")
+        Ok(serde_json::to_string_pretty(&_results)?)
     }
     /// # Query Via XREF Dataources to Platforms
     /// 
@@ -1169,29 +984,20 @@ impl EnterpriseMatrixSearcher {
     /// ```ignore
     /// self.search_stats_datatsources_and_platforms();
     /// ```
-    fn search_stats_datasources_and_platforms(&self) -> String
+    fn search_stats_datasources_and_platforms(&self) -> Result<String, AssistantError>
     {
-        use std::collections::HashMap;
-        let _json: EnterpriseMatrixBreakdown = serde_json::from_slice(&self.content[..]).unwrap();
-        let mut _ds: HashMap<String, HashMap<String, usize>> = HashMap::new();
-        let mut _results: Vec<HashMap<String, HashMap<String, usize>>> = vec![];
+        let _json = &self.baseline;
+        let mut _ds: HashMap<&Datasource, HashMap<&Platform, usize>> = HashMap::new();
         for _datasource in _json.datasources.iter() {
-            let mut _os: HashMap<String, usize> = HashMap::new();
-            
+            let _os = _ds.entry(_datasource).or_default();
             for _platform in _json.platforms.iter() {
-                _os.insert(_platform.clone(), 0usize);
-                for _technique in _json.breakdown_techniques.platforms.iter() {
-                    if _technique.datasources.contains(_datasource)
-                        && _technique.platform.contains(_platform) {
-                            let _value = _os.get_mut(_platform.as_str()).unwrap();
-                            *_value += 1usize;
-                        }
-                }   
+                let _count = _json.breakdown_techniques.platforms.iter()
+                                  .filter(|_t| _t.datasources.contains(_datasource) && _t.platform.contains(_platform))
+                                  .count();
+                _os.insert(_platform, _count);
             }
-            _ds.insert(_datasource.clone(), _os);
         }
-        _results.push(_ds);
-        serde_json::to_string_pretty(&_results).expect("(?) Error: Unable To Deserialize STATS For Datasources & Platforms")
+        Ok(serde_json::to_string_pretty(&vec![_ds])?)
     }
     /// # Query Via XREF Dataources to Tactics
     /// 
@@ -1202,65 +1008,120 @@ impl EnterpriseMatrixSearcher {
     /// ```ignore
     /// self.search_stats_datatsources_and_platforms();
     /// ```    
-    fn search_stats_datasources_and_tactics(&self) -> String
+    fn search_stats_datasources_and_tactics(&self) -> Result<String, AssistantError>
     {
-        use std::collections::HashMap;
-        let _json: EnterpriseMatrixBreakdown = serde_json::from_slice(&self.content[..]).unwrap();
-        let mut _ds: HashMap<String, HashMap<String, usize>> = HashMap::new();
-        let mut _results: Vec<HashMap<String, HashMap<String, usize>>> = vec![];
+        let _json = &self.baseline;
+        let mut _ds: HashMap<&Datasource, HashMap<&Tactic, usize>> = HashMap::new();
         for _datasource in _json.datasources.iter() {
-            let mut _tactics: HashMap<String, usize> = HashMap::new();
-        
+            let _tactics = _ds.entry(_datasource).or_default();
             for _tactic in _json.tactics.iter() {
-                _tactics.insert(_tactic.clone(), 0usize);
-                for _technique in _json.breakdown_techniques.platforms.iter() {
-                    if _technique.datasources.contains(_datasource)
-                        && _technique.tactic.contains(_tactic) {
-                            let _value = _tactics.get_mut(_tactic.as_str()).unwrap();
-                            *_value += 1usize;
-                        }
-                }   
+                let _count = _json.breakdown_techniques.platforms.iter()
+                                  .filter(|_t| _t.datasources.contains(_datasource) && &_t.tactic == _tactic)
+                                  .count();
+                _tactics.insert(_tactic, _count);
             }
-            _ds.insert(_datasource.clone(), _tactics);
         }
-        _results.push(_ds);
-        serde_json::to_string_pretty(&_results).expect("(?) Error: Unable To Deserialize STATS For Datasources & Tactics")
+        Ok(serde_json::to_string_pretty(&vec![_ds])?)
     }    
     /// # **Rendering Functions**
     /// This section of the source code is for functions that render queery results
     /// or render information to the end-user.
     ///
+    /// Renders any query results as records for `jq` and other tools, a JSON array
+    /// with `json` or one JSON object per line with `jsonl`.
+    ///
+    /// The `kind` of the results gives the shape of the records:
+    ///
+    /// | Kind | Record |
+    /// |---|---|
+    /// | `techniques` | The technique, sorted by `tid` |
+    /// | `adversaries`, `malware`, `tools` | The adversary, malware or tool with its profile |
+    /// | `revoked`, `deprecated` | `{"tid", "technique"}` |
+    /// | `tactics`, `platforms`, `datasources` | `{"tactic"}`, `{"platform"}`, `{"datasource"}` |
+    /// | `xref:platforms`, `xref:tactics` | `{"datasource", "platform" or "tactic", "count"}` |
+    /// | `stats` | The stats of the matrix |
+    fn render_enterprise_json(&self,
+        results: &[String],
+        kind: &str,
+        _wants_export: &str,
+        _wants_outfile: &str
+    ) -> Result<(), AssistantError>
+    {
+        use serde_json::{ json, Value };
+        let mut _records: Vec<Value> = vec![];
+        for _result in results.iter() {
+            match serde_json::from_str::<Value>(_result.as_str())? {
+                Value::Array(_items) => _records.extend(_items),
+                _item => _records.push(_item)
+            }
+        }
+        let _pair = |_item: Value, _first: &str, _second: &str| match _item {
+            Value::Array(_p) if _p.len() == 2 => json!({ _first: _p[0], _second: _p[1] }),
+            _other => _other
+        };
+        let mut _records: Vec<Value> = match kind {
+            "revoked" | "deprecated" => _records.into_iter().map(|_r| _pair(_r, "tid", "technique")).collect(),
+            "tactics" => _records.into_iter().map(|_r| json!({ "tactic": _r })).collect(),
+            "platforms" => _records.into_iter().map(|_r| json!({ "platform": _r })).collect(),
+            "datasources" => _records.into_iter().map(|_r| json!({ "datasource": _r })).collect(),
+            "xref:platforms" | "xref:tactics" => {
+                let _axis = if kind == "xref:platforms" { "platform" } else { "tactic" };
+                let mut _rows: Vec<Value> = vec![];
+                for _record in _records.iter().filter_map(|_r| _r.as_object()) {
+                    let mut _datasources: Vec<&String> = _record.keys().collect();
+                    _datasources.sort();
+                    for _datasource in _datasources {
+                        let _counts = _record[_datasource].as_object()
+                            .ok_or_else(|| AssistantError::Parse(format!("Malformed XREF Counts Of The Datasource: {}", _datasource)))?;
+                        let mut _names: Vec<&String> = _counts.keys().collect();
+                        _names.sort();
+                        for _name in _names {
+                            _rows.push(json!({ "datasource": _datasource, _axis: _name, "count": _counts[_name] }));
+                        }
+                    }
+                }
+                _rows
+            },
+            _ => _records
+        };
+        if kind == "techniques" || kind == "revoked" || kind == "deprecated" {
+            _records.sort_by(|_a, _b| _a["tid"].as_str().cmp(&_b["tid"].as_str()));
+        }
+        let _content = match _wants_export {
+            "jsonl" => _records.iter().map(|_r| format!("{}\n", _r)).collect::<String>(),
+            _ => format!("{}\n", serde_json::to_string_pretty(&_records)?)
+        };
+        match _wants_outfile {
+            "None" => print!("{}", _content),
+            _ => FileHandler::open(_wants_outfile, "crw")?.write(&_content)?
+        }
+        Ok(())
+    }
     fn render_enterprise_tactics_table(&self,
-        results: &Vec<String>,
+        results: &[String],
         _wants_export: &str,
         _wants_outfile: &str
-    )
+    ) -> Result<(), AssistantError>
     {
         let mut _table = Table::new();
         _table.add_row(Row::new(vec![
             Cell::new("INDEX").style_spec("FW"),
             Cell::new("TACTICS").style_spec("FW"),
         ]));
-        let _json: Vec<String> = serde_json::from_str(results[0].as_str()).expect("(?) Error: Unable To Deserialize Search Results By Tactics");
+        let _json: Vec<String> = serde_json::from_str(results[0].as_str())?;
         for (_idx, _row) in _json.iter().enumerate() {
             _table.add_row(Row::new(vec![
                 Cell::new((_idx + 1).to_string().as_str()).style_spec("FY"),
                 Cell::new(_row.as_str()).style_spec("FW"),
             ]));
         }
-        if _wants_export == "csv" {
-            self.save_csv_export(_wants_outfile, &_table);
-        } else {
-            println!("{}", "\n\n");
-            _table.printstd();
-            println!("{}", "\n\n");
-        }          
+        self.render_table(_wants_export, _wants_outfile, "Tactics", &_table, &_table)
     }
     fn render_enterprise_tools_table(&self,
-        results: &Vec<String>,
+        results: &[String],
         _wants_export: &str,
         _wants_outfile: &str
-    )
+    ) -> Result<(), AssistantError>
     {
         let mut _csv_table = Table::new();
         let mut _table = Table::new();
@@ -1274,34 +1135,29 @@ impl EnterpriseMatrixSearcher {
         } else {
             _table.add_row(_table_headers);
         }
-        let _json: Vec<(String, String)> = serde_json::from_str(results[0].as_str()).expect("(?) Error: Unable To Deserialize Search Results By Tools");
+        let _json: Vec<EnterpriseTool> = serde_json::from_str(results[0].as_str())?;
         for (_idx, _row) in _json.iter().enumerate() {
             if _wants_export == "csv" {
                 _csv_table.add_row(Row::new(vec![
+                    Cell::new((_idx + 1).to_string().as_str()),
+                    Cell::new(_row.name.as_str()),
+                    Cell::new(join(&_row.aliases, "|").as_str())
+                ]));
+            } else {
+                _table.add_row(Row::new(vec![
                     Cell::new((_idx + 1).to_string().as_str()).style_spec("FY"),
-                    Cell::new(_row.0.as_str()).style_spec("FW"),
-                    Cell::new(&_row.1.as_str()).style_spec("FW"),
+                    Cell::new(_row.name.as_str()).style_spec("FW"),
+                    Cell::new(join(&_row.aliases, "\n").as_str()).style_spec("FW"),
                 ]));
             }
-            _table.add_row(Row::new(vec![
-                Cell::new((_idx + 1).to_string().as_str()).style_spec("FY"),
-                Cell::new(_row.0.as_str()).style_spec("FW"),
-                Cell::new(&_row.1.as_str().replace("|", "\n")).style_spec("FW"),
-            ]));
-        }
-        if _wants_export == "csv" {
-            self.save_csv_export(_wants_outfile, &_table);
-        } else {
-            println!("{}", "\n\n");
-            _table.printstd();
-            println!("{}", "\n\n");
         }
+        self.render_table(_wants_export, _wants_outfile, "Tools", &_table, &_csv_table)
     }      
     fn render_enterprise_malware_table(&self,
-        results: &Vec<String>,
+        results: &[String],
         _wants_export: &str,
         _wants_outfile: &str
-    )
+    ) -> Result<(), AssistantError>
     {
         let mut _table = Table::new();
         let mut _csv_table = Table::new();
@@ -1315,36 +1171,29 @@ impl EnterpriseMatrixSearcher {
         } else {
             _table.add_row(_table_headers);
         }
-        let _msg = "(?) Error: Unable To Deserialize Search Results By Malware";
-        let _json: Vec<EnterpriseMalware> = serde_json::from_str(results[0].as_str()).expect(_msg);
+        let _json: Vec<EnterpriseMalware> = serde_json::from_str(results[0].as_str())?;
         for (_idx, _row) in _json.iter().enumerate() {
             if _wants_export == "csv" {
                 _csv_table.add_row(Row::new(vec![
                     Cell::new((_idx + 1).to_string().as_str()),
                     Cell::new(_row.name.as_str()),
-                    Cell::new(&_row.aliases)
+                    Cell::new(join(&_row.aliases, "|").as_str())
                 ]));
             } else {
                 _table.add_row(Row::new(vec![
                     Cell::new((_idx + 1).to_string().as_str()).style_spec("FY"),
                     Cell::new(_row.name.as_str()).style_spec("FW"),
-                    Cell::new(&_row.aliases.replace("|", "\n")).style_spec("FW"),
+                    Cell::new(join(&_row.aliases, "\n").as_str()).style_spec("FW"),
                 ]));
             }
         }
-        if _wants_export == "csv" {
-            self.save_csv_export(_wants_outfile, &_csv_table);
-        } else {
-            println!("{}", "\n\n");
-            _table.printstd();
-            println!("{}", "\n\n");
-        }
+        self.render_table(_wants_export, _wants_outfile, "Malware", &_table, &_csv_table)
     }     
     fn render_enterprise_adversaries_table(&self,
-        results: &Vec<String>,
+        results: &[String],
         _wants_export: &str,
         _wants_outfile: &str
-    )
+    ) -> Result<(), AssistantError>
     {
         let mut _table = Table::new();
         let mut _csv_table = Table::new();
@@ -1365,82 +1214,44 @@ impl EnterpriseMatrixSearcher {
         } else {
             _table.add_row(_table_headers);
         }
-        let _err = "(?) Error: Unable To Deserialize Search Results By Adversaries";
         let mut _json: Vec<EnterpriseAdversary>;
-        _json = serde_json::from_str(results[0].as_str()).expect(_err);
+        _json = serde_json::from_str(results[0].as_str())?;
+        if _wants_export == "navigator" {
+            return self.render_enterprise_adversaries_layer(&_json, _wants_outfile);
+        }
         for (_idx, _row) in _json.iter().enumerate() {
-            let mut _aliases = "".to_string();
-            if _row.aliases.len() == 0 {
-                _aliases.push_str("none");
-            } else {
-                _aliases = _row.aliases.clone();
-            }
+            let _aliases = join(&_row.aliases, "|");
             //
-            let mut _tactics = "".to_string();
-            if _row.profile.tactics.items.len() > 0 {
-                _row.profile.tactics.items.iter()
-                .map(|x| { _tactics.push_str(x.as_str()); _tactics.push_str("|") })
-                .collect::<Vec<_>>();
-            } else {
-                _tactics.push_str("none");
-            }
+            let _tactics = join(&_row.profile.tactics.items, "|");
             //
-            let mut _techniques = "".to_string();
-            if _row.profile.techniques.items.len() > 0 {
-                _row.profile.techniques.items.iter()
-                    .map(|x| { _techniques.push_str(x.as_str()); _techniques.push_str("|") })
-                    .collect::<Vec<_>>();
-            } else {
-                _techniques.push_str("none");
-            }
+            let _techniques = join(&_row.profile.techniques.items, "|");
             //
-            let mut _subtechniques = "".to_string();
-            if _row.profile.subtechniques.items.len() > 0 {
-                _row.profile.subtechniques.items.iter()
-                    .map(|x| { _subtechniques.push_str(x.as_str()); _subtechniques.push_str("|") })
-                    .collect::<Vec<_>>();
-            } else {
-                _subtechniques.push_str("none");
-            }
+            let _subtechniques = join(&_row.profile.subtechniques.items, "|");
             //
-            let mut _malware = "".to_string();
-            if _row.profile.malware.items.len() > 0 {
-                _row.profile.malware.items.iter()
-                    .map(|x| { _malware.push_str(x.as_str()); _malware.push_str("|") })
-                    .collect::<Vec<_>>();
-            } else {
-                _malware.push_str("none");
-            }
+            let _malware = join(&_row.profile.malware.items, "|");
             //
-            let mut _tools = "".to_string();
-            if _row.profile.tools.items.len() > 0 {
-                _row.profile.tools.items.iter()
-                    .map(|x| { _tools.push_str(x.as_str()); _tools.push_str("|") })
-                    .collect::<Vec<_>>();
-            } else {
-                _tools.push_str("none");
-            }
+            let _tools = join(&_row.profile.tools.items, "|");
             //
             let mut _revoked_cell: Cell;
             let mut _group_id_cell: Cell;
             if _row.is_revoked {
                 _revoked_cell = Cell::new("Revoked").style_spec("cFR");
-                _group_id_cell = Cell::new(&_row.group_id.as_str()).style_spec("cFR");
+                _group_id_cell = Cell::new(_row.group_id.as_str()).style_spec("cFR");
 
             } else {
                 _revoked_cell = Cell::new("Active").style_spec("cFG");
-                _group_id_cell = Cell::new(&_row.group_id.as_str()).style_spec("cFW");
+                _group_id_cell = Cell::new(_row.group_id.as_str()).style_spec("cFW");
             }
             if _wants_export == "csv" {
                 _csv_table.add_row(Row::new(vec![
                     Cell::new((_idx + 1).to_string().as_str()).style_spec("c"),
                     _revoked_cell.clone(),
                     _group_id_cell.clone(),
-                    Cell::new(&_row.name.as_str()),
+                    Cell::new(_row.name.as_str()),
                     Cell::new(&_aliases),
-                    Cell::new(&_tactics.as_str()),
+                    Cell::new(_tactics.as_str()),
                     Cell::new(&_techniques),
-                    Cell::new(&_subtechniques.as_str()),
+                    Cell::new(_subtechniques.as_str()),
                     Cell::new(&_malware),
                     Cell::new(&_tools)
                 ]));
@@ -1449,7 +1260,7 @@ impl EnterpriseMatrixSearcher {
                     Cell::new((_idx + 1).to_string().as_str()).style_spec("c"),
                     _revoked_cell.clone(),
                     _group_id_cell.clone(),
-                    Cell::new(&_row.name.as_str()),
+                    Cell::new(_row.name.as_str()),
                     Cell::new(&_aliases.replace("|", "\n")),
                     Cell::new(&_tactics.as_str().replace("|", "\n")),
                     Cell::new(&_techniques.as_str().replace("|", "\n")).style_spec("cFG"),
@@ -1459,74 +1270,79 @@ impl EnterpriseMatrixSearcher {
                 ]));
             }
         }
-        if _wants_export == "csv" {
-            self.save_csv_export(_wants_outfile, &_csv_table);
-        } else {
-            println!("{}", "\n");
-            //_table.printstd();
-            _table.print_tty(false);
-            println!("{}", "\n\n");
-        }
+        self.render_table(_wants_export, _wants_outfile, format!("Adversaries: {}", self.term.borrow()).as_str(), &_table, &_csv_table)
     }     
+    /// Scores each technique by the number of adversaries using it, so a layer of
+    /// many adversaries shows their overlap.
+    fn render_enterprise_adversaries_layer(&self,
+        adversaries: &[EnterpriseAdversary],
+        _wants_outfile: &str
+    ) -> Result<(), AssistantError>
+    {
+        let mut _users: std::collections::BTreeMap<&str, Vec<&str>> = std::collections::BTreeMap::new();
+        for _adversary in adversaries.iter() {
+            let _tids = _adversary.profile.techniques.items.iter().chain(_adversary.profile.subtechniques.items.iter());
+            for _tid in _tids {
+                let _entry = _users.entry(_tid.as_str()).or_default();
+                if !_entry.contains(&_adversary.name.as_str()) {
+                    _entry.push(_adversary.name.as_str());
+                }
+            }
+        }
+        let _names: Vec<&str> = adversaries.iter().map(|_a| _a.name.as_str()).collect();
+        let mut _layer = NavigatorLayer::new(_names.join(", ").as_str(), "Techniques & Subtechniques Used By The Adversaries");
+        for (_tid, _names) in _users.iter() {
+            _layer.add(_tid, None, Some(_names.len() as f64), "", format!("Used By: {}", _names.join(", ")).as_str());
+        }
+        _layer.add_gradient(0.0, adversaries.len() as f64);
+        self.save_navigator_export(_wants_outfile, &mut _layer)?;
+        Ok(())
+    }
     fn render_enterprise_platforms_table(&self,
-        results: &Vec<String>,
+        results: &[String],
         _wants_export: &str,
         _wants_outfile: &str
-    )
+    ) -> Result<(), AssistantError>
     {
         let mut _table = Table::new();
         _table.add_row(Row::new(vec![
             Cell::new("INDEX").style_spec("FW"),
             Cell::new("PLATFORMS").style_spec("FW"),
         ]));
-        let _err: &str = "(?) Error: Unable To Deserialize Search Results By DataSources";
-        let _json: Vec<String> = serde_json::from_str(results[0].as_str()).expect(_err);
+        let _json: Vec<String> = serde_json::from_str(results[0].as_str())?;
         for (_idx, _row) in _json.iter().enumerate() {
             _table.add_row(Row::new(vec![
                 Cell::new((_idx + 1).to_string().as_str()).style_spec("FY"),
                 Cell::new(_row.as_str()).style_spec("FW"),
             ]));
         }
-        if _wants_export == "csv" {
-            self.save_csv_export(_wants_outfile, &_table);
-        } else {
-            println!("{}", "\n\n");
-            _table.printstd();
-            println!("{}", "\n\n");
-        }
+        self.render_table(_wants_export, _wants_outfile, "Platforms", &_table, &_table)
     } 
     fn render_enterprise_datasources_table(&self,
-        results: &Vec<String>,
+        results: &[String],
         _wants_export: &str,
         _wants_outfile: &str
-    )
+    ) -> Result<(), AssistantError>
     {
         let mut _table = Table::new();
         _table.add_row(Row::new(vec![
             Cell::new("INDEX").style_spec("FW"),
             Cell::new("DATASOURCE").style_spec("FW"),
         ]));
-        let _err: &str = "(?) Error: Unable To Deserialize Search Results By DataSources";
-        let _json: Vec<String> = serde_json::from_str(results[0].as_str()).expect(_err);
+        let _json: Vec<String> = serde_json::from_str(results[0].as_str())?;
         for (_idx, _row) in _json.iter().enumerate() {
             _table.add_row(Row::new(vec![
                 Cell::new((_idx + 1).to_string().as_str()).style_spec("FY"),
                 Cell::new(_row.as_str()).style_spec("FW"),
             ]));
         }
-        if _wants_export == "csv" {
-            self.save_csv_export(_wants_outfile, &_table);
-        } else {
-            println!("{}", "\n\n");
-            _table.printstd();
-            println!("{}", "\n\n");
-        }        
+        self.render_table(_wants_export, _wants_outfile, "Datasources", &_table, &_table)
     } 
     fn render_enterprise_table(&self,
-        results: &Vec<String>,
+        results: &[String],
         _wants_export: &str,
         _wants_outfile: &str
-    )
+    ) -> Result<(), AssistantError>
     {
         let mut _csv_table = Table::new();
         let mut _table = Table::new();
@@ -1547,30 +1363,27 @@ impl EnterpriseMatrixSearcher {
         }
  
         let mut _sorted_index: Vec<(String, usize, usize)> = vec![];
-        let _err: &str = "(?) Error: Render Table Deserialization";
         for (_ridx, _item) in results.iter().enumerate() {
-            let _json: Vec<EnterpriseTechnique> = serde_json::from_str(results[_ridx].as_str()).expect(_err);
+            let _json: Vec<EnterpriseTechnique> = serde_json::from_str(results[_ridx].as_str())?;
             for (_jidx, _record) in _json.iter().enumerate() {
                 _sorted_index.push((_record.tid.clone(), _jidx, _ridx));
             }
         }
         _sorted_index.sort();
-        let mut _st = String::from("");
-        let mut _idx: usize = 0;
+        if _wants_export == "navigator" {
+            return self.render_enterprise_layer(results, &_sorted_index, _wants_outfile);
+        }
         // Iterate through the sorted index
         // Pay attention to:
         //      `_jidx` => JSON index
         //      `_ridx` => Root index
-        let _err: &str = "(?) Error: Render Table Deserialization";
-        for (_technique, _jidx, _ridx) in _sorted_index {
-            let _json: Vec<EnterpriseTechnique> = serde_json::from_str(results[_ridx].as_str()).expect(_err);
+        for (_idx, (_technique, _jidx, _ridx)) in _sorted_index.into_iter().enumerate() {
+            let _json: Vec<EnterpriseTechnique> = serde_json::from_str(results[_ridx].as_str())?;
             let _row = &_json[_jidx];
-            if _row.has_subtechniques {
-                _row.subtechniques.iter()
-                    .map(|x| { _st.push_str(x.as_str()); _st.push_str("|") }).collect::<Vec<_>>();
-            } else {
-                _st.push_str("n_a");
-            }
+            let _st = match _row.has_subtechniques {
+                true => _row.subtechniques.join("|"),
+                false => "n_a".to_string()
+            };
             // When a deprecated Technique is part of the result
             // then create a row for the deprecated technique
             let mut _status: Cell;
@@ -1590,42 +1403,225 @@ impl EnterpriseMatrixSearcher {
                     Row::new(vec![
                         Cell::new((_idx + 1).to_string().as_str()),
                         _status,
-                        Cell::new(_row.platform.as_str()),
+                        Cell::new(join(&_row.platform, "|").as_str()),
                         Cell::new(_row.tactic.as_str()),
                         _tid,
                         Cell::new(_row.technique.as_str()),
                         Cell::new(_st.as_str()),
-                        Cell::new(_row.datasources.as_str())
+                        Cell::new(join(&_row.datasources, "|").as_str())
                     ]));
             } else {
                 _table.add_row(
                     Row::new(vec![
                         Cell::new((_idx + 1).to_string().as_str()),
                         _status,
-                        Cell::new(_row.platform.replace("|", "\n").as_str()),
+                        Cell::new(join(&_row.platform, "\n").as_str()),
                         Cell::new(_row.tactic.as_str()),
                         _tid,
                         Cell::new(_row.technique.as_str()).style_spec("FW"),
                         Cell::new(_st.replace("|", "\n").as_str()).style_spec("cFW"),
-                        Cell::new(_row.datasources.replace("|", "\n").as_str())
+                        Cell::new(join(&_row.datasources, "\n").as_str())
                     ]));
             }
-            _st.clear();
-            _idx += 1;            
         }
-        if _wants_export == "csv" {
-            self.save_csv_export(_wants_outfile, &_csv_table);
-        } else {
-            println!("{}", "\n\n");
-            _table.printstd();
-            println!("{}", "\n\n");
+        self.render_table(_wants_export, _wants_outfile, format!("Techniques: {}", self.term.borrow()).as_str(), &_table, &_csv_table)
+    }
+    /// Techniques are added with the tactic of their row, the deprecated and revoked
+    /// techniques are colored to stand out.
+    fn render_enterprise_layer(&self,
+        results: &[String],
+        sorted_index: &[(String, usize, usize)],
+        _wants_outfile: &str
+    ) -> Result<(), AssistantError>
+    {
+        let _json: Vec<Vec<EnterpriseTechnique>> = results.iter().map(|_r| serde_json::from_str(_r.as_str())).collect::<Result<_, _>>()?;
+        let mut _layer = NavigatorLayer::new("Search Results", "Techniques & Subtechniques Found By The Search");
+        for (_, _jidx, _ridx) in sorted_index.iter() {
+            let _row = &_json[*_ridx][*_jidx];
+            let (_color, _comment) = if _row.is_deprecated {
+                ("#ffe766", "Deprecated")
+            } else if _row.is_revoked {
+                ("#ff6666", "Revoked")
+            } else {
+                ("#66b1ff", "")
+            };
+            let _tactic = match _row.tactic {
+                Tactic::None => None,
+                _ => Some(_row.tactic.as_str())
+            };
+            _layer.add(_row.tid.as_str(), _tactic, None, _color, _comment);
+        }
+        self.save_navigator_export(_wants_outfile, &mut _layer)?;
+        Ok(())
+    }
+    /// Names are kept in the order of their score, an empty result suggests the
+    /// closest names instead.
+    fn render_enterprise_names_table(&self,
+        results: &[String],
+        _wants_export: &str,
+        _wants_outfile: &str
+    ) -> Result<(), AssistantError>
+    {
+        let mut _matches: Vec<FuzzyMatch> = vec![];
+        for _item in results.iter() {
+            let _json: Vec<FuzzyMatch> = serde_json::from_str(_item.as_str())?;
+            _matches.extend(_json);
+        }
+        if _matches.is_empty() {
+            let _term = self.term.borrow();
+            println!(r#"[ "Results": None Found, "SearchTerm": {} ]"#, _term);
+            let _suggestions = FuzzyMatcher::new(&self.baseline).suggest(_term.as_str(), 5);
+            if !_suggestions.is_empty() {
+                println!("Did You Mean: {}", _suggestions.join(", "));
+            }
+            return Ok(());
+        }
+        if _wants_export == "navigator" {
+            let mut _layer = NavigatorLayer::new("Search Results", "Techniques & Subtechniques Found By Name");
+            for _match in _matches.iter().filter(|_m| _m.kind == "technique" || _m.kind == "subtechnique") {
+                _layer.add(_match.id.as_str(), None, None, "#66b1ff", format!("Score: {}", _match.score).as_str());
+            }
+            return self.save_navigator_export(_wants_outfile, &mut _layer);
+        }
+        let mut _table = Table::new();
+        _table.add_row(Row::new(vec![
+            Cell::new("INDEX").style_spec("FW"),
+            Cell::new("SCORE").style_spec("FG"),
+            Cell::new("KIND"),
+            Cell::new("ID").style_spec("FY"),
+            Cell::new("NAME").style_spec("FW"),
+            Cell::new("ALIAS"),
+        ]));
+        for (_idx, _match) in _matches.iter().enumerate() {
+            _table.add_row(
+                Row::new(vec![
+                    Cell::new((_idx + 1).to_string().as_str()),
+                    Cell::new(_match.score.to_string().as_str()).style_spec("FG"),
+                    Cell::new(_match.kind.as_str()),
+                    Cell::new(_match.id.as_str()).style_spec("FY"),
+                    Cell::new(_match.name.as_str()).style_spec("FW"),
+                    Cell::new(_match.alias.as_str()),
+                ])
+            );
+        }
+        self.render_table(_wants_export, _wants_outfile, format!("Names: {}", self.term.borrow()).as_str(), &_table, &_table)
+    }
+    /// The snippets are wrapped to keep the table readable, the matched words are
+    /// between `**`.
+    fn render_enterprise_fulltext_table(&self,
+        results: &[String],
+        _wants_export: &str,
+        _wants_outfile: &str
+    ) -> Result<(), AssistantError>
+    {
+        let mut _matches: Vec<FullTextMatch> = vec![];
+        for _item in results.iter() {
+            let _json: Vec<FullTextMatch> = serde_json::from_str(_item.as_str())?;
+            _matches.extend(_json);
+        }
+        if _matches.is_empty() {
+            println!(r#"[ "Results": None Found, "SearchTerm": {} ]"#, self.term.borrow());
+            return Ok(());
+        }
+        if _wants_export == "navigator" {
+            let mut _layer = NavigatorLayer::new("Search Results", "Techniques & Subtechniques Found By Their Text");
+            for _match in _matches.iter().filter(|_m| _m.kind == "technique" || _m.kind == "subtechnique") {
+                _layer.add(_match.id.as_str(), None, None, "#66b1ff", format!("Score: {}", _match.score).as_str());
+            }
+            return self.save_navigator_export(_wants_outfile, &mut _layer);
+        }
+        let mut _table = Table::new();
+        _table.add_row(Row::new(vec![
+            Cell::new("INDEX").style_spec("FW"),
+            Cell::new("SCORE").style_spec("FG"),
+            Cell::new("KIND"),
+            Cell::new("ID").style_spec("FY"),
+            Cell::new("NAME").style_spec("FW"),
+            Cell::new("FIELD"),
+            Cell::new("SNIPPET"),
+        ]));
+        for (_idx, _match) in _matches.iter().enumerate() {
+            // Wrap the snippet at about 60 characters
+            let mut _lines: Vec<String> = vec![];
+            let mut _line = String::new();
+            for _word in _match.snippet.split(' ') {
+                if !_line.is_empty() && _line.len() + _word.len() > 60 {
+                    _lines.push(std::mem::take(&mut _line));
+                }
+                if !_line.is_empty() {
+                    _line.push(' ');
+                }
+                _line.push_str(_word);
+            }
+            _lines.push(_line);
+            let _snippet = match _wants_export {
+                "csv" => _match.snippet.clone(),
+                _ => _lines.join("\n")
+            };
+            _table.add_row(
+                Row::new(vec![
+                    Cell::new((_idx + 1).to_string().as_str()),
+                    Cell::new(format!("{:.2}", _match.score).as_str()).style_spec("FG"),
+                    Cell::new(_match.kind.as_str()),
+                    Cell::new(_match.id.as_str()).style_spec("FY"),
+                    Cell::new(_match.name.as_str()).style_spec("FW"),
+                    Cell::new(_match.field.as_str()),
+                    Cell::new(_snippet.as_str()),
+                ])
+            );
+        }
+        self.render_table(_wants_export, _wants_outfile, format!("Full-Text: {}", self.term.borrow()).as_str(), &_table, &_table)
+    }
+    fn render_enterprise_regex_table(&self,
+        results: &[String],
+        _wants_export: &str,
+        _wants_outfile: &str
+    ) -> Result<(), AssistantError>
+    {
+        let mut _matches: Vec<PatternMatch> = vec![];
+        for _item in results.iter() {
+            let _json: Vec<PatternMatch> = serde_json::from_str(_item.as_str())?;
+            _matches.extend(_json);
+        }
+        if _matches.is_empty() {
+            println!(r#"[ "Results": None Found, "SearchTerm": {} ]"#, self.term.borrow());
+            return Ok(());
         }
+        if _wants_export == "navigator" {
+            let mut _layer = NavigatorLayer::new("Search Results", "Techniques & Subtechniques Found By A Pattern");
+            for _match in _matches.iter().filter(|_m| _m.kind == "technique" || _m.kind == "subtechnique") {
+                _layer.add(_match.id.as_str(), None, None, "#66b1ff", format!("Matched: {}", _match.field).as_str());
+            }
+            return self.save_navigator_export(_wants_outfile, &mut _layer);
+        }
+        let mut _table = Table::new();
+        _table.add_row(Row::new(vec![
+            Cell::new("INDEX").style_spec("FW"),
+            Cell::new("KIND"),
+            Cell::new("ID").style_spec("FY"),
+            Cell::new("NAME").style_spec("FW"),
+            Cell::new("FIELD"),
+            Cell::new("MATCH").style_spec("FG"),
+        ]));
+        for (_idx, _match) in _matches.iter().enumerate() {
+            _table.add_row(
+                Row::new(vec![
+                    Cell::new((_idx + 1).to_string().as_str()),
+                    Cell::new(_match.kind.as_str()),
+                    Cell::new(_match.id.as_str()).style_spec("FY"),
+                    Cell::new(_match.name.as_str()).style_spec("FW"),
+                    Cell::new(_match.field.as_str()),
+                    Cell::new(_match.matched.as_str()).style_spec("FG"),
+                ])
+            );
+        }
+        self.render_table(_wants_export, _wants_outfile, format!("Pattern: {}", self.term.borrow()).as_str(), &_table, &_table)
     }
     fn render_enterprise_revoked_table(&self,
-        results: &Vec<String>,
+        results: &[String],
         _wants_export: &str,
         _wants_outfile: &str
-    )
+    ) -> Result<(), AssistantError>
     {
         let mut _table = Table::new();
         _table.add_row(Row::new(vec![
@@ -1636,7 +1632,7 @@ impl EnterpriseMatrixSearcher {
         ]));
         let mut _idx: usize = 0;
         for _item in results.iter() {
-            let mut _json: Vec<(&str, &str)> = serde_json::from_str(_item.as_str()).expect("(?) Error:  Render Table Deserialization For Revoked");
+            let mut _json: Vec<(&str, &str)> = serde_json::from_str(_item.as_str())?;
             _json.sort();
             for (_tid, _technique) in _json.iter() {
                 _table.add_row(
@@ -1650,18 +1646,13 @@ impl EnterpriseMatrixSearcher {
                 _idx += 1;
             }
         }
-        println!("{}", "\n\n");
-        _table.printstd();
-        println!("{}", "\n\n");
-        if _wants_export == "csv" {
-            self.save_csv_export(_wants_outfile, &_table);
-        }  
+        self.render_table(_wants_export, _wants_outfile, "Revoked Techniques", &_table, &_table)
     }
     fn render_enterprise_deprecated_table(&self,
-        results: &Vec<String>,
+        results: &[String],
         _wants_export: &str,
         _wants_outfile: &str
-    )
+    ) -> Result<(), AssistantError>
     {
         let mut _table = Table::new();
         _table.add_row(Row::new(vec![
@@ -1672,7 +1663,7 @@ impl EnterpriseMatrixSearcher {
         ]));
         let mut _idx: usize = 0;
         for _item in results.iter() {
-            let mut _json: Vec<(&str, &str)> = serde_json::from_str(_item.as_str()).expect("(?) Error:  Render Table Deserialization For Revoked");
+            let mut _json: Vec<(&str, &str)> = serde_json::from_str(_item.as_str())?;
             _json.sort();
             for (_tid, _technique) in _json.iter() {
                 _table.add_row(
@@ -1686,19 +1677,21 @@ impl EnterpriseMatrixSearcher {
                 _idx += 1;
             }
         }
-        if _wants_export == "csv" {
-            self.save_csv_export(_wants_outfile, &_table);
-        } else {
-            println!("{}", "\n\n");
-            _table.printstd();
-            println!("{}", "\n\n");
-        }
+        self.render_table(_wants_export, _wants_outfile, "Deprecated Techniques", &_table, &_table)
+    }
+    /// # XREF Counts (Private Function)
+    /// Reads the counts of the xref queries, by datasource then by platform or tactic.
+    fn xref_counts(results: &[String]) -> Result<HashMap<String, HashMap<String, usize>>, AssistantError>
+    {
+        let _result = results.first().map(|_r| _r.as_str()).unwrap_or("[]");
+        let _data: Vec<HashMap<String, HashMap<String, usize>>> = serde_json::from_str(_result)?;
+        Ok(_data.into_iter().next().unwrap_or_default())
     }
     fn render_enterprise_stats_xref_datasource_platforms(&self,
-        results: &Vec<String>,
+        results: &[String],
         _wants_export: &str,
         _wants_outfile: &str
-    )
+    ) -> Result<(), AssistantError>
     {
         let mut _table = Table::new();
         _table.add_row(Row::new(vec![
@@ -1713,38 +1706,24 @@ impl EnterpriseMatrixSearcher {
             Cell::new("SAAS").style_spec("FW"),
             Cell::new("WINDOWS").style_spec("FW"),
         ]));
-        let _data: serde_json::Value = serde_json::from_str(results[0].as_str()).unwrap();
-        let _data = _data.as_array().unwrap();
-        let _data = _data[0].as_object().unwrap();
-
-        let _json: EnterpriseMatrixBreakdown = serde_json::from_slice(&self.content[..]).unwrap();
+        let _json = &self.baseline;
+        let _data = EnterpriseMatrixSearcher::xref_counts(results)?;
         for _datasource in _json.datasources.iter() {
-            _table.add_row(Row::new(vec![
-                Cell::new(_datasource.as_str()).style_spec("FW"),
-                Cell::new(&_data[_datasource]["aws"].as_i64().unwrap().to_string().as_str()).style_spec("cFW"),
-                Cell::new(&_data[_datasource]["azure"].as_i64().unwrap().to_string().as_str()).style_spec("cFW"),
-                Cell::new(&_data[_datasource]["azure-ad"].as_i64().unwrap().to_string().as_str()).style_spec("cFW"),
-                Cell::new(&_data[_datasource]["gcp"].as_i64().unwrap().to_string().as_str()).style_spec("cFW"),
-                Cell::new(&_data[_datasource]["linux"].as_i64().unwrap().to_string().as_str()).style_spec("cFW"),
-                Cell::new(&_data[_datasource]["macos"].as_i64().unwrap().to_string().as_str()).style_spec("cFW"),
-                Cell::new(&_data[_datasource]["office-365"].as_i64().unwrap().to_string().as_str()).style_spec("cFW"),
-                Cell::new(&_data[_datasource]["saas"].as_i64().unwrap().to_string().as_str()).style_spec("cFW"),
-                Cell::new(&_data[_datasource]["windows"].as_i64().unwrap().to_string().as_str()).style_spec("cFW"),
-            ])); 
-        }
-        if _wants_export == "csv" {
-            self.save_csv_export(_wants_outfile, &_table);
-        } else {
-            println!("{}", "\n\n");
-            _table.printstd();
-            println!("{}", "\n\n");
+            let _counts = _data.get(_datasource.as_str()).cloned().unwrap_or_default();
+            let mut _row = vec![Cell::new(_datasource.as_str()).style_spec("FW")];
+            for _platform in Platform::ALL.iter() {
+                let _count = _counts.get(_platform.as_str()).cloned().unwrap_or(0);
+                _row.push(Cell::new(_count.to_string().as_str()).style_spec("cFW"));
+            }
+            _table.add_row(Row::new(_row));
         }
+        self.render_table(_wants_export, _wants_outfile, "Datasources By Platform", &_table, &_table)
     }   
     fn render_enterprise_stats_xref_datasource_tactics(&self,
-        results: &Vec<String>,
+        results: &[String],
         _wants_export: &str,
         _wants_outfile: &str
-    )
+    ) -> Result<(), AssistantError>
     {
         let mut _table = Table::new();
         _table.add_row(Row::new(vec![
@@ -1763,41 +1742,25 @@ impl EnterpriseMatrixSearcher {
             Cell::new("IMPACT").style_spec("FW"),
 
         ]));
-        let _data: serde_json::Value = serde_json::from_str(results[0].as_str()).unwrap();
-        let _data = _data.as_array().unwrap();
-        let _data = _data[0].as_object().unwrap();
-
-        let _json: EnterpriseMatrixBreakdown = serde_json::from_slice(&self.content[..]).unwrap();
+        let _json = &self.baseline;
+        let _data = EnterpriseMatrixSearcher::xref_counts(results)?;
         for _datasource in _json.datasources.iter() {
-            _table.add_row(Row::new(vec![
-                Cell::new(_datasource.as_str()).style_spec("FW"),
-                Cell::new(&_data[_datasource]["initial-access"].as_i64().unwrap().to_string().as_str()).style_spec("cFW"),
-                Cell::new(&_data[_datasource]["execution"].as_i64().unwrap().to_string().as_str()).style_spec("cFW"),
-                Cell::new(&_data[_datasource]["persistence"].as_i64().unwrap().to_string().as_str()).style_spec("cFW"),
-                Cell::new(&_data[_datasource]["privilege-escalation"].as_i64().unwrap().to_string().as_str()).style_spec("cFW"),
-                Cell::new(&_data[_datasource]["defense-evasion"].as_i64().unwrap().to_string().as_str()).style_spec("cFW"),
-                Cell::new(&_data[_datasource]["credential-access"].as_i64().unwrap().to_string().as_str()).style_spec("cFW"),
-                Cell::new(&_data[_datasource]["discovery"].as_i64().unwrap().to_string().as_str()).style_spec("cFW"),
-                Cell::new(&_data[_datasource]["lateral-movement"].as_i64().unwrap().to_string().as_str()).style_spec("cFW"),
-                Cell::new(&_data[_datasource]["collection"].as_i64().unwrap().to_string().as_str()).style_spec("cFW"),
-                Cell::new(&_data[_datasource]["command-and-control"].as_i64().unwrap().to_string().as_str()).style_spec("cFW"),
-                Cell::new(&_data[_datasource]["exfiltration"].as_i64().unwrap().to_string().as_str()).style_spec("cFW"),
-                Cell::new(&_data[_datasource]["impact"].as_i64().unwrap().to_string().as_str()).style_spec("cFW"),
-            ])); 
+            let _counts = _data.get(_datasource.as_str()).cloned().unwrap_or_default();
+            let mut _row = vec![Cell::new(_datasource.as_str()).style_spec("FW")];
+            // The table starts at the initial access, like the tactics of the stats
+            for _tactic in Tactic::ALL.iter().skip_while(|_t| **_t != Tactic::InitialAccess) {
+                let _count = _counts.get(_tactic.as_str()).cloned().unwrap_or(0);
+                _row.push(Cell::new(_count.to_string().as_str()).style_spec("cFW"));
+            }
+            _table.add_row(Row::new(_row));
         }
-        if _wants_export == "csv" {
-            self.save_csv_export(_wants_outfile, &_table);
-        } else {
-            println!("{}", "\n\n");
-            _table.printstd();
-            println!("{}", "\n\n");
-        }              
+        self.render_table(_wants_export, _wants_outfile, "Datasources By Tactic", &_table, &_table)
     }    
     fn render_enterprise_stats(&self,
-        results: &Vec<String>,
+        results: &[String],
         _wants_export: &str,
         _wants_outfile: &str
-    )
+    ) -> Result<(), AssistantError>
     {
         let mut _table = Table::new();
         _table.add_row(Row::new(vec![
@@ -1806,7 +1769,7 @@ impl EnterpriseMatrixSearcher {
             Cell::new("PERCENT %")
         ]));
         let _item = &results[0];
-        let _json: EnterpriseMatrixStatistics = serde_json::from_str(_item.as_str()).expect("(?) Error:  Render Table Deserialization For Stats");
+        let _json: EnterpriseMatrixStatistics = serde_json::from_str(_item.as_str())?;
         // Uniques - Overview Section
         // Describes the uniq number of techniques
         // by platform only - no tactics are included
@@ -2255,15 +2218,83 @@ impl EnterpriseMatrixSearcher {
                 Cell::new(""),
             ])
         );
-        println!("\n\n");        
-        _table.printstd();
-        println!("\n\n");
-        /*
-        TO DO:
+        // The rows of the stats are uneven, they do not fit in a CSV file
         if _wants_export == "csv" {
-            _table.remove_row(index: usize)
-            self.save_csv_export(_wants_outfile, &_table);
+            return Err(AssistantError::InvalidQuery("Export Format Not Supported For Stats: csv | Use: json, markdown or html".to_string()));
         }
-        */          
+        self.render_table(_wants_export, _wants_outfile, "Stats", &_table, &_table)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn technique(tid: &str, tactic: Tactic, platforms: &[Platform], datasources: &[&str]) -> EnterpriseTechnique
+    {
+        let mut _row = EnterpriseTechnique::new();
+        _row.tid = tid.to_string();
+        _row.tactic = tactic;
+        _row.platform = platforms.to_vec();
+        _row.datasources = datasources.iter().map(|_d| Datasource::parse(_d)).collect();
+        _row
+    }
+
+    fn searcher() -> EnterpriseMatrixSearcher
+    {
+        let mut _baseline = EnterpriseMatrixBreakdown::default();
+        _baseline.breakdown_techniques.platforms = vec![
+            technique("T1003", Tactic::CredentialAccess, &[Platform::Windows, Platform::Linux], &["process-monitoring"]),
+            technique("T1059", Tactic::Execution, &[Platform::Windows], &["process-monitoring", "powershell-logs"]),
+        ];
+        _baseline.tactics = vec![Tactic::CredentialAccess, Tactic::Execution].into_iter().collect();
+        _baseline.platforms = vec![Platform::Windows, Platform::Linux].into_iter().collect();
+        _baseline.datasources = vec![Datasource::parse("powershell-logs"), Datasource::parse("process-monitoring")];
+        EnterpriseMatrixSearcher::from_baseline(_baseline)
+    }
+
+    #[test]
+    fn xref_counts_the_techniques_by_datasource()
+    {
+        let _searcher = searcher();
+        let _platforms = EnterpriseMatrixSearcher::xref_counts(&[_searcher.search_stats_datasources_and_platforms().unwrap()]).unwrap();
+        assert_eq!(_platforms["process-monitoring"]["windows"], 2);
+        assert_eq!(_platforms["process-monitoring"]["linux"], 1);
+        assert_eq!(_platforms["powershell-logs"]["linux"], 0);
+        let _tactics = EnterpriseMatrixSearcher::xref_counts(&[_searcher.search_stats_datasources_and_tactics().unwrap()]).unwrap();
+        assert_eq!(_tactics["powershell-logs"]["execution"], 1);
+        assert_eq!(_tactics["powershell-logs"]["credential-access"], 0);
+        assert!(EnterpriseMatrixSearcher::xref_counts(&["{}".to_string()]).is_err());
+    }
+
+    #[test]
+    fn render_enterprise_json_returns_the_errors_of_the_output_file()
+    {
+        let _searcher = searcher();
+        let _results = vec![_searcher.search_all_techniques().unwrap()];
+        let _outfile = std::env::temp_dir().join(format!("mitre-assistant-missing-{}", std::process::id())).join("techniques.json");
+        let _outfile = _outfile.display().to_string();
+        assert!(_searcher.render_enterprise_json(&_results, "techniques", "json", _outfile.as_str()).is_err());
+        let _broken = vec![r#"[{"not-a-map": 1}]"#.to_string()];
+        assert!(_searcher.render_enterprise_json(&_broken, "xref:platforms", "json", _outfile.as_str()).is_err());
+    }
+
+    #[test]
+    fn search_by_adversary_finds_the_aliases_and_the_group_id()
+    {
+        let mut _searcher = searcher();
+        let _adversary = EnterpriseAdversary {
+            name:       "APT29".to_string(),
+            aliases:    vec!["apt29".to_string(), "cozy-bear".to_string()],
+            group_id:   "G0016".to_string(),
+            ..Default::default()
+        };
+        _searcher.baseline.breakdown_adversaries = vec![_adversary];
+        for _term in ["apt29", "Cozy Bear", "g0016"].iter() {
+            let _found: Vec<EnterpriseAdversary> = serde_json::from_str(_searcher.search_by_adversary(_term).unwrap().as_str()).unwrap();
+            assert_eq!(_found.len(), 1, "{}", _term);
+        }
+        assert_eq!(_searcher.search_by_adversary("apt28").unwrap(), "[]");
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,375 @@
+use rustyline::completion::{ Completer, Pair };
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::validate::Validator;
+use rustyline::{ Context, Editor, Helper };
+
+use std::collections::BTreeSet;
+
+
+use crate::modules::expression::SearchExpression;
+use crate::modules::searcher::EnterpriseMatrixSearcher;
+use crate::structs::errors::AssistantError;
+use crate::structs::types::{ Platform, Tactic };
+use crate::utils::fshandler::FileHandler;
+
+
+/// The commands of the shell, completed at the start of a line.
+const COMMANDS: [&str; 10] = ["search", "text", "regex", "show", "filter", "subtechniques",
+                              "export", "help", "exit", "quit"];
+/// The fields of the `filter` command.
+const FILTERS: [&str; 4] = ["platform", "tactic", "datasource", "adversary"];
+/// The formats of the `export` command.
+const EXPORTS: [&str; 6] = ["csv", "json", "jsonl", "markdown", "html", "navigator"];
+
+
+/// # Mitre-Assistant: Shell Module
+/// An interactive prompt over a baseline loaded once, with the history of the
+/// commands and the tab completion of the IDs, tactics, platforms, datasources,
+/// adversaries and software.
+///
+/// ```text
+/// mitre-assistant> filter platform linux
+/// mitre-assistant> search persistence
+/// mitre-assistant> export json persistence.json
+/// ```
+///
+/// ## Example
+/// ```ignore
+/// let mut _shell = InteractiveShell::new("enterprise", "None")?;
+/// _shell.run()?;
+/// ```
+pub struct InteractiveShell {
+    searcher:           EnterpriseMatrixSearcher,
+    filters:            Vec<(String, String)>,  // The values of each filter field, in the order given
+    subtechniques:      bool,
+    last:               Option<(String, String)>,   // The last search command and its term, for `export`
+}
+impl InteractiveShell {
+    pub fn new(matrix: &str, version: &str) -> Result<Self, AssistantError>
+    {
+        Ok(InteractiveShell {
+            searcher:       EnterpriseMatrixSearcher::new(matrix, version)?,
+            filters:        vec![],
+            subtechniques:  false,
+            last:           None,
+        })
+    }
+    /// # Shell - Run
+    /// Reads the commands until `exit` or `Ctrl-D`, an error is printed and the
+    /// prompt goes on.  The history is kept in the config folder.
+    pub fn run(&mut self) -> Result<(), AssistantError>
+    {
+        FileHandler::check_for_config_folder()?;
+        let _history = FileHandler::resource_path("", "history.txt");
+        let mut _editor = Editor::<ShellHelper>::new();
+        _editor.set_helper(Some(ShellHelper::new(&self.searcher)));
+        _editor.load_history(_history.as_str()).ok();
+        println!("\n\tMitre-Assistant Interactive Shell | Type `help` For The Commands, `exit` To Leave\n");
+        loop {
+            let _line = match _editor.readline("mitre-assistant> ") {
+                Ok(_line) => _line,
+                Err(ReadlineError::Interrupted) => continue,
+                Err(ReadlineError::Eof) => break,
+                Err(_err) => return Err(_err.into())
+            };
+            let _line = _line.trim();
+            if _line.is_empty() {
+                continue;
+            }
+            _editor.add_history_entry(_line);
+            match self.execute(_line) {
+                Ok(true) => continue,
+                Ok(false) => break,
+                Err(_err) => eprintln!("Program Error: {}", _err)
+            }
+        }
+        _editor.save_history(_history.as_str()).ok();
+        Ok(())
+    }
+    /// # Shell - Execute
+    /// Runs one command, returns `false` when the shell must stop.
+    pub fn execute(&mut self, line: &str) -> Result<bool, AssistantError>
+    {
+        let (_command, _rest) = match line.find(char::is_whitespace) {
+            Some(_idx) => (&line[.._idx], line[_idx..].trim()),
+            None => (line, "")
+        };
+        match _command.to_lowercase().as_str() {
+            "exit" | "quit" => return Ok(false),
+            "help" => self.help(),
+            "search" | "text" | "regex" | "show" => {
+                if _rest.is_empty() {
+                    return Err(AssistantError::InvalidQuery(format!("Expected A Search Term | e.g., {} t1003", _command)));
+                }
+                self.query(_command, _rest, "None", "None")?;
+                self.last = Some((_command.to_lowercase(), _rest.to_string()));
+            },
+            "filter" => self.filter(_rest)?,
+            "subtechniques" => {
+                self.subtechniques = match _rest {
+                    "on" => true,
+                    "off" => false,
+                    _ => return Err(AssistantError::InvalidQuery("Expected: subtechniques on|off".to_string()))
+                };
+                println!("Subtechniques: {}", _rest);
+            },
+            "export" => {
+                let mut _args = _rest.splitn(2, char::is_whitespace);
+                let _format = _args.next().unwrap_or("");
+                let _outfile = _args.next().map(|_f| _f.trim()).unwrap_or("None");
+                if !EXPORTS.contains(&_format) {
+                    return Err(AssistantError::InvalidQuery(format!("Export Format Not Supported: {} | Use: {}", _format, EXPORTS.join(", "))));
+                }
+                if _format == "csv" && _outfile == "None" {
+                    return Err(AssistantError::InvalidQuery("Expected A File For The CSV Export | e.g., export csv results.csv".to_string()));
+                }
+                let (_command, _term) = self.last.clone()
+                    .ok_or_else(|| AssistantError::InvalidQuery("Nothing To Export Yet | Search Something First".to_string()))?;
+                self.query(_command.as_str(), _term.as_str(), _format, _outfile)?;
+            },
+            _ => return Err(AssistantError::InvalidQuery(format!("Unknown Command: {} | Use: {}", _command, COMMANDS.join(", "))))
+        }
+        Ok(true)
+    }
+    /// # Shell - Query (Private Method)
+    fn query(&self, command: &str, term: &str, _wants_export: &str, _wants_outfile: &str) -> Result<(), AssistantError>
+    {
+        match command.to_lowercase().as_str() {
+            "text" => self.searcher.search_text(term, _wants_export, _wants_outfile),
+            "regex" => self.searcher.search_regex(term, _wants_export, _wants_outfile),
+            "show" => self.searcher.show(term, _wants_export, _wants_outfile),
+            _ => self.searcher.search(term, self.subtechniques, _wants_export, _wants_outfile)
+        }
+    }
+    /// # Shell - Filter (Private Method)
+    /// `filter <field> <values>` sets a filter, `filter clear` removes them and
+    /// `filter` alone prints them.
+    fn filter(&mut self, args: &str) -> Result<(), AssistantError>
+    {
+        let mut _args = args.splitn(2, char::is_whitespace);
+        let _field = _args.next().unwrap_or("").to_lowercase();
+        let _values = _args.next().map(|_v| _v.trim()).unwrap_or("");
+        let mut _filters = self.filters.clone();
+        if _field == "clear" {
+            _filters.clear();
+        } else if FILTERS.contains(&_field.as_str()) {
+            if _values.is_empty() {
+                return Err(AssistantError::InvalidQuery(format!("Expected A Value | e.g., filter {} {}", _field, self.example(&_field))));
+            }
+            _filters.retain(|(_f, _)| *_f != _field);
+            _filters.push((_field, _values.to_string()));
+        } else if !_field.is_empty() {
+            return Err(AssistantError::InvalidQuery(format!("Unknown Filter: {} | Use: {}, clear", _field, FILTERS.join(", "))));
+        }
+        let _pairs: Vec<(&str, &str)> = _filters.iter().map(|(_f, _v)| (_f.as_str(), _v.as_str())).collect();
+        self.searcher.set_filter(SearchExpression::from_filters(&_pairs)?)?;
+        self.filters = _filters;
+        match self.filters.is_empty() {
+            true => println!("Filters: none"),
+            false => {
+                for (_field, _values) in self.filters.iter() {
+                    println!("Filter: --{} {}", _field, _values);
+                }
+            }
+        }
+        Ok(())
+    }
+    /// # Shell - Example (Private Method)
+    fn example(&self, field: &str) -> &str
+    {
+        match field {
+            "platform" => "linux,macos",
+            "tactic" => "persistence",
+            "datasource" => "process-monitoring",
+            _ => "apt29"
+        }
+    }
+    /// # Shell - Help (Private Method)
+    fn help(&self)
+    {
+        println!(r#"
+    search <term>               Search a term, a query, IDs or names - e.g., search tactic:persistence AND platform:linux
+    text <words>                Search the words of the descriptions & detections - e.g., text lsass memory
+    regex <pattern>             Search the IDs, names, aliases & descriptions - e.g., regex ^T1003\.00[1-3]$
+    show <ID or name>           Show everything about a technique, tactic, group, software, mitigation or datasource
+    filter <field> <values>     Keep the techniques of: platform, tactic, datasource or adversary
+    filter clear                Remove the filters, `filter` alone prints them
+    subtechniques on|off        Search the subtechniques too
+    export <format> [file]      Export the last search: {} - or json, jsonl, markdown after `show`
+    help                        Print this help
+    exit                        Leave the shell, or Ctrl-D
+
+    Press Tab to complete the commands, IDs, tactics, platforms, datasources, adversaries and software.
+"#, EXPORTS.join(", "));
+    }
+}
+
+
+/// # Shell Helper (Private Struct)
+/// The words completed by the Tab key, collected once from the baseline.
+struct ShellHelper {
+    words:          BTreeSet<String>,
+    datasources:    Vec<String>,
+    adversaries:    Vec<String>,
+}
+impl ShellHelper {
+    fn new(searcher: &EnterpriseMatrixSearcher) -> Self
+    {
+        let _json = searcher.baseline();
+        let _tactics: Vec<String> = Tactic::ALL.iter().map(|_t| _t.to_string()).collect();
+        let _platforms: Vec<String> = Platform::ALL.iter().map(|_p| _p.to_string()).collect();
+        let mut _words: BTreeSet<String> = BTreeSet::new();
+        _words.extend(_json.uniques_techniques.iter().cloned());
+        _words.extend(_json.uniques_subtechniques.iter().cloned());
+        _words.extend(_tactics);
+        _words.extend(_platforms);
+        _words.extend(_json.breakdown_adversaries.iter().map(|_a| _a.name.clone()));
+        _words.extend(_json.breakdown_malware.iter().map(|_m| _m.name.clone()));
+        _words.extend(_json.breakdown_tools.iter().map(|_t| _t.name.clone()));
+        _words.extend(_json.breakdown_mitigations.iter().map(|_m| _m.mitigation_id.clone()));
+        _words.extend(_json.breakdown_tactics.iter().map(|_t| _t.tactic_id.clone()));
+        _words.extend(_json.datasources.iter().map(|_d| _d.to_string()));
+        ShellHelper {
+            words:          _words,
+            datasources:    _json.datasources.iter().map(|_d| _d.to_string()).collect(),
+            adversaries:    _json.breakdown_adversaries.iter().map(|_a| _a.name.to_lowercase()).collect(),
+        }
+    }
+}
+impl Completer for ShellHelper {
+    type Candidate = Pair;
+    fn complete(&self, line: &str, pos: usize, _ctx: &Context<'_>) -> rustyline::Result<(usize, Vec<Pair>)>
+    {
+        let _line = &line[..pos];
+        // The word being completed starts after a space or a comma
+        let _start = _line.rfind(|c: char| c.is_whitespace() || c == ',').map(|_i| _i + 1).unwrap_or(0);
+        let _word = _line[_start..].to_lowercase();
+        let _previous: Vec<&str> = _line[.._start].split_whitespace().collect();
+        let _candidates: Vec<String> = match _previous.as_slice() {
+            [] => COMMANDS.iter().map(|_c| _c.to_string()).collect(),
+            ["filter"] => FILTERS.iter().chain(["clear"].iter()).map(|_f| _f.to_string()).collect(),
+            ["filter", "platform", ..] => Platform::ALL.iter().map(|_p| _p.to_string()).collect(),
+            ["filter", "tactic", ..] => Tactic::ALL.iter().map(|_t| _t.to_string()).collect(),
+            ["filter", "datasource", ..] => self.datasources.clone(),
+            ["filter", "adversary", ..] => self.adversaries.clone(),
+            ["subtechniques"] => vec!["on".to_string(), "off".to_string()],
+            ["export"] => EXPORTS.iter().map(|_e| _e.to_string()).collect(),
+            _ => self.words.iter().cloned().collect()
+        };
+        let _pairs: Vec<Pair> = _candidates.into_iter()
+            .filter(|_c| _c.to_lowercase().starts_with(_word.as_str()))
+            .map(|_c| Pair { display: _c.clone(), replacement: _c })
+            .collect();
+        Ok((_start, _pairs))
+    }
+}
+impl Hinter for ShellHelper {
+    type Hint = String;
+}
+impl Highlighter for ShellHelper {}
+impl Validator for ShellHelper {}
+impl Helper for ShellHelper {}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustyline::history::History;
+    use crate::modules::parser::EnterpriseMatrixBreakdown;
+    use crate::structs::enterprise::EnterpriseAdversary;
+    use crate::structs::types::Datasource;
+
+    fn shell() -> InteractiveShell
+    {
+        let _adversary = EnterpriseAdversary {
+            name:       "APT29".to_string(),
+            group_id:   "G0016".to_string(),
+            ..Default::default()
+        };
+        let _baseline = EnterpriseMatrixBreakdown {
+            uniques_techniques:     vec!["T1003".to_string(), "T1059".to_string()],
+            uniques_subtechniques:  vec!["T1003.001".to_string()],
+            datasources:            vec![Datasource::parse("process-monitoring")],
+            breakdown_adversaries:  vec![_adversary],
+            ..Default::default()
+        };
+        InteractiveShell {
+            searcher:       EnterpriseMatrixSearcher::from_baseline(_baseline),
+            filters:        vec![],
+            subtechniques:  false,
+            last:           None,
+        }
+    }
+
+    fn complete(shell: &InteractiveShell, line: &str) -> (usize, Vec<String>)
+    {
+        let _history = History::new();
+        let (_start, _pairs) = ShellHelper::new(&shell.searcher).complete(line, line.len(), &Context::new(&_history)).unwrap();
+        (_start, _pairs.into_iter().map(|_p| _p.replacement).collect())
+    }
+
+    fn reason(result: Result<bool, AssistantError>) -> String
+    {
+        match result {
+            Err(AssistantError::InvalidQuery(_reason)) => _reason,
+            _other => panic!("Expected An Invalid Query, Found {:?}", _other)
+        }
+    }
+
+    #[test]
+    fn execute_stops_on_exit_and_rejects_the_unknown_commands()
+    {
+        let mut _shell = shell();
+        assert!(!_shell.execute("exit").unwrap());
+        assert!(!_shell.execute("QUIT").unwrap());
+        assert!(reason(_shell.execute("find t1003")).starts_with("Unknown Command: find |"));
+        assert!(reason(_shell.execute("search")).starts_with("Expected A Search Term |"));
+        assert!(_shell.execute("subtechniques on").unwrap());
+        assert!(_shell.subtechniques);
+        assert!(reason(_shell.execute("subtechniques yes")).starts_with("Expected: subtechniques on|off"));
+        assert!(_shell.subtechniques);
+    }
+
+    #[test]
+    fn export_needs_a_search_a_format_and_a_csv_file()
+    {
+        let mut _shell = shell();
+        assert!(reason(_shell.execute("export json")).starts_with("Nothing To Export Yet |"));
+        assert!(reason(_shell.execute("export pdf")).starts_with("Export Format Not Supported: pdf |"));
+        assert!(reason(_shell.execute("export csv")).starts_with("Expected A File For The CSV Export |"));
+        assert!(_shell.last.is_none());
+    }
+
+    #[test]
+    fn filter_replaces_a_field_and_keeps_the_filters_of_a_failed_command()
+    {
+        let mut _shell = shell();
+        _shell.execute("filter platform linux").unwrap();
+        _shell.execute("filter adversary apt29").unwrap();
+        _shell.execute("filter platform windows,macos").unwrap();
+        let _wanted = vec![("adversary".to_string(), "apt29".to_string()), ("platform".to_string(), "windows,macos".to_string())];
+        assert_eq!(_shell.filters, _wanted);
+        assert!(_shell.execute("filter platform windoze").is_err());
+        assert!(_shell.execute("filter adversary apt99").is_err());
+        assert!(reason(_shell.execute("filter color red")).starts_with("Unknown Filter: color |"));
+        assert!(reason(_shell.execute("filter tactic")).starts_with("Expected A Value | e.g., filter tactic persistence"));
+        assert_eq!(_shell.filters, _wanted);
+        _shell.execute("filter clear").unwrap();
+        assert!(_shell.filters.is_empty());
+    }
+
+    #[test]
+    fn complete_follows_the_command_being_written()
+    {
+        let _shell = shell();
+        assert_eq!(complete(&_shell, "su"), (0, vec!["subtechniques".to_string()]));
+        assert_eq!(complete(&_shell, "filter "), (7, vec!["platform", "tactic", "datasource", "adversary", "clear"].into_iter().map(String::from).collect()));
+        assert_eq!(complete(&_shell, "filter datasource pro").1, vec!["process-monitoring".to_string()]);
+        assert_eq!(complete(&_shell, "filter adversary ap").1, vec!["apt29".to_string()]);
+        assert_eq!(complete(&_shell, "export j").1, vec!["json".to_string(), "jsonl".to_string()]);
+        assert_eq!(complete(&_shell, "search t1059,t1003"), (13, vec!["T1003".to_string(), "T1003.001".to_string()]));
+        assert!(complete(&_shell, "show APT").1.contains(&"APT29".to_string()));
+    }
+}
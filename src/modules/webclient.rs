@@ -1,10 +1,59 @@
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
 use reqwest;
+use reqwest::StatusCode;
+use reqwest::header::{ self, HeaderMap };
+use serde_derive::{ Deserialize, Serialize };
 use serde_json;
+use sha2::{ Digest, Sha256 };
+
 
+use crate::utils::fshandler::FileHandler;
+use crate::utils::regexes::RegexPatternManager;
+use crate::utils::config::AssistantConfig;
 
-#[path = "../utils/fshandler.rs"]
-mod fshandler;
-use fshandler::FileHandler;
+use crate::modules::importer::MatrixImporter;
+use crate::structs::errors::AssistantError;
+
+
+/// # Download Metadata
+/// Sidecar stored next to a downloaded matrix as `<matrix>.meta`.  It keeps the
+/// validators returned by the server to make conditional requests, the checksum
+/// of the installed matrix, and the validators of a partial download to resume it.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct DownloadMeta {
+    #[serde(default)]
+    pub url:                    String,
+    #[serde(default)]
+    pub etag:                   Option<String>,
+    #[serde(default)]
+    pub last_modified:          Option<String>,
+    #[serde(default)]
+    pub sha256:                 String,
+    #[serde(default)]
+    pub partial_etag:           Option<String>,
+    #[serde(default)]
+    pub partial_last_modified:  Option<String>,
+}
+impl DownloadMeta {
+    /// # Download Metadata - Load
+    /// Reads the sidecar of a matrix, a missing or unreadable sidecar
+    /// is treated as an empty one.
+    pub fn load(filename: &str) -> Self
+    {
+        FileHandler::read_resource("matrixes", format!("{}.meta", filename).as_str())
+            .and_then(|_content| serde_json::from_str(_content.as_str()).ok())
+            .unwrap_or_default()
+    }
+    /// # Download Metadata - Save
+    pub fn save(&self, filename: &str) -> Result<(), AssistantError>
+    {
+        let _fp = FileHandler::resource_path("matrixes", format!("{}.meta", filename).as_str());
+        FileHandler::write_atomic(_fp.as_str(), &serde_json::to_string_pretty(self)?)
+    }
+}
 
 
 /// # Mitre-Assistant: WebClient Module
@@ -12,52 +61,480 @@ use fshandler::FileHandler;
 /// It has several methods that are either blocking or non-blocking
 /// when connecting to the internet.
 pub struct WebClient {
-    pub source_urls: &'static [(&'static str, &'static str)]
+    pub source_base: String,
+    pub source_urls: &'static [(&'static str, &'static str)],
+    pub config:      AssistantConfig
 }
 impl WebClient {
     /// # Mitre-Assistant: Constructor
-    /// Instantiates an instance of a webclient.
+    /// Instantiates an instance of a webclient with the default settings,
+    /// downloading from the Mitre CTI repo on GitHub.
     /// 
     /// ## Example
-    /// ```rust
+    /// ```ignore
     /// let _wc = WebClient::new();
     /// ```
     pub fn new() -> WebClient
     {
+        WebClient::from_config(AssistantConfig::default())
+    }
+    /// # Mitre-Assistant: Constructor From Config
+    /// Instantiates an instance of a webclient using the settings of the
+    /// config file and environment variables - i.e., an internal mirror, a proxy,
+    /// a custom CA bundle or a timeout.
+    ///
+    /// The mirror must follow the layout of the Mitre CTI repo:
+    /// `<mirror>/<release>/enterprise-attack/enterprise-attack.json`
+    ///
+    /// ## Example
+    /// ```ignore
+    /// let _config = AssistantConfig { mirror: Some("http://127.0.0.1:8000".to_string()), ..Default::default() };
+    ///
+    /// let _wc = WebClient::from_config(_config);
+    /// ```
+    pub fn from_config(config: AssistantConfig) -> WebClient
+    {
+        let _base = match &config.mirror {
+            Some(_mirror) => _mirror.trim_end_matches('/').to_string(),
+            None => "https://raw.githubusercontent.com/mitre/cti".to_string()
+        };
         WebClient {
+            source_base: _base,
             source_urls: &[
-                ("enterprise-attack", "https://raw.githubusercontent.com/mitre/cti/master/enterprise-attack/enterprise-attack.json"),
-                ("mobile-attack", "https://raw.githubusercontent.com/mitre/cti/master/mobile-attack/mobile-attack.json"),
-                ("pre-attack", "https://raw.githubusercontent.com/mitre/cti/master/pre-attack/pre-attack.json")
-            ]
+                ("enterprise-attack", "enterprise-attack/enterprise-attack.json"),
+                ("mobile-attack", "mobile-attack/mobile-attack.json"),
+                ("pre-attack", "pre-attack/pre-attack.json")
+            ],
+            config
         }
     }
-    /// # Mitre-Assistant: Load Matrix (Blocking)
-    /// This method is a blocking (synchronous) method.
-    /// 
+    /// # Mitre-Assistant: Load
+    /// Instantiates an instance of a webclient with the settings of the
+    /// `config.json` file and the `MITRE_ASSISTANT_*` environment variables.
+    ///
     /// ## Example
-    /// ```rust
-    /// let _wc = WebClient::new();                     // Create a new webclient
-    /// 
-    /// let _mx = _wc.download("enterprise-attack");   // load the enterprise matrix
+    /// ```ignore
+    /// let _wc = WebClient::load()?;
+    /// ```
+    pub fn load() -> Result<WebClient, AssistantError>
+    {
+        Ok(WebClient::from_config(AssistantConfig::load()?))
+    }
+    /// # Mitre-Assistant: Build Client (Private Method)
+    /// Creates the blocking HTTP client with the proxy, CA bundle and
+    /// timeout settings of the config.
+    fn build_client(&self) -> Result<reqwest::blocking::Client, AssistantError>
+    {
+        let mut _builder = reqwest::blocking::Client::builder();
+        if let Some(_proxy) = &self.config.proxy {
+            _builder = _builder.proxy(reqwest::Proxy::all(_proxy.as_str())?);
+        }
+        if let Some(_ca_bundle) = &self.config.ca_bundle {
+            let _pem = match std::fs::read(_ca_bundle.as_str()) {
+                Ok(_pem) => _pem,
+                Err(_e) => return Err(AssistantError::MissingResource(format!("Unable To Read CA Bundle: {} - {}", _ca_bundle, _e)))
+            };
+            _builder = _builder.add_root_certificate(reqwest::Certificate::from_pem(&_pem)?);
+        }
+        if let Some(_timeout) = self.config.timeout {
+            _builder = _builder.timeout(Duration::from_secs(_timeout));
+        }
+        Ok(_builder.build()?)
+    }
+    /// # Mitre-Assistant: Resolve Release
+    /// Converts the version requested by the user into the git reference used
+    /// by the Mitre CTI repo.
+    ///
+    /// A release number like `v7.2` or `7.2` is mapped to the CTI tag `ATT&CK-v7.2`,
+    /// any other value, e.g., an all-digit commit like `1234567`, is considered a tag
+    /// or a commit and used as provided. When no version is requested, the `master`
+    /// branch is used.
+    ///
+    /// ## Example
+    /// ```ignore
+    /// let _wc = WebClient::new();
+    ///
+    /// assert_eq!(_wc.resolve_release("v7.2"), "ATT%26CK-v7.2");
+    /// assert_eq!(_wc.resolve_release("None"), "master");
     /// ```
-    pub fn download(&self, matrix_type: &str)  -> Result<String, Box<dyn std::error::Error>>
+    pub fn resolve_release(&self, version: &str) -> String
+    {
+        let _version = version.trim();
+        let _scanner = RegexPatternManager::load_release_version();
+        if _version == "None" || _version.is_empty() {
+            "master".to_string()
+        } else if _scanner.pattern.is_match(_version) {
+            format!("ATT%26CK-v{}", _version.trim_start_matches(['v', 'V']))
+        } else {
+            _version.replace("&", "%26")
+        }
+    }
+    /// # Mitre-Assistant: Matrix URL (Private Method)
+    /// Returns the URL of a matrix in the Mitre CTI repo, or in the mirror.
+    fn matrix_url(&self, matrix_type: &str, version: &str) -> String
     {
-        let _url = match matrix_type {
+        let _path = match matrix_type {
             "enterprise" => self.source_urls[0].1,
             "mobile" => self.source_urls[1].1,
             "pre-attack" => self.source_urls[2].1,
             _ => "None"
         };
+        format!("{}/{}/{}", self.source_base, self.resolve_release(version), _path)
+    }
+    /// # Mitre-Assistant: Header Value (Private Method)
+    fn header_value(headers: &HeaderMap, name: header::HeaderName) -> Option<String>
+    {
+        headers.get(name).and_then(|_v| _v.to_str().ok()).map(|_v| _v.to_string())
+    }
+    /// # Mitre-Assistant: Load Matrix (Blocking)
+    /// This method is a blocking (synchronous) method.
+    ///
+    /// The `version` parameter pins the download to a specific ATT&CK release,
+    /// tag or commit of the CTI repo.  Use `"None"` for the latest content.
+    ///
+    /// The `sha256` parameter is the expected checksum of the matrix, use `"None"`
+    /// to skip the comparison.
+    ///
+    /// The download is only installed when the server answers with a success status,
+    /// the content is a STIX bundle of the requested matrix, and its checksum matches.
+    /// Otherwise an error is returned and the previous matrix is kept.
+    ///
+    /// When a local copy exists, the request is conditional on the `ETag` and `Last-Modified`
+    /// validators of the previous download, and an interrupted download is resumed from
+    /// its `.part` file.
+    ///
+    /// Returns `true` when the content of the installed matrix changed.
+    /// 
+    /// ## Example
+    /// ```ignore
+    /// let _wc = WebClient::new();                                         // Create a new webclient
+    /// 
+    /// let _changed = _wc.download("enterprise", "None", "None")?;         // load the latest enterprise matrix
+    ///
+    /// let _changed = _wc.download("enterprise", "v7.2", "None")?;         // load the enterprise matrix of release v7.2
+    /// ```
+    pub fn download(&self, matrix_type: &str, version: &str, sha256: &str)  -> Result<bool, AssistantError>
+    {
+        let _url = self.matrix_url(matrix_type, version);
         let _dashes = "=".repeat(_url.len());
         println!("{}", _dashes);
-        println!("\nDownlading Matrix : {}\nDownloading From  : {}\n", matrix_type, _url);
+        println!("\nDownlading Matrix : {}\nMatrix Version    : {}\nDownloading From  : {}\n", matrix_type, version, _url);
         println!("{}", _dashes);
-        let _json = reqwest::blocking::get(_url)?.text()?;
-        let _filename = format!("{}.json", matrix_type);
-        if FileHandler::check_for_config_folder().unwrap() {
-            FileHandler::write_download(_filename.as_str(), &_json)?;
+        FileHandler::check_for_config_folder()?;
+        let _filename = FileHandler::versioned_name(matrix_type, version);
+        let _dst_file = FileHandler::resource_path("matrixes", _filename.as_str());
+        let _part_file = format!("{}.part", _dst_file);
+        let _installed = Path::new(_dst_file.as_str()).is_file();
+        let mut _meta = DownloadMeta::load(_filename.as_str());
+        if _meta.url != _url {
+            _meta = DownloadMeta { url: _url.clone(), ..Default::default() };
+        }
+        let _partial_size = fs::metadata(_part_file.as_str()).map(|_m| _m.len()).unwrap_or(0);
+        let _partial_validator = _meta.partial_etag.clone().or_else(|| _meta.partial_last_modified.clone());
+        let mut _request = self.build_client()?.get(_url.as_str());
+        if let Some(_validator) = _partial_validator.filter(|_| _partial_size > 0) {
+            _request = _request.header(header::RANGE, format!("bytes={}-", _partial_size))
+                               .header(header::IF_RANGE, _validator);
+        }
+        if _installed {
+            if let Some(_etag) = &_meta.etag {
+                _request = _request.header(header::IF_NONE_MATCH, _etag.as_str());
+            }
+            if let Some(_last_modified) = &_meta.last_modified {
+                _request = _request.header(header::IF_MODIFIED_SINCE, _last_modified.as_str());
+            }
+        }
+        let mut _response = _request.send()?;
+        let _status = _response.status();
+        if _status == StatusCode::NOT_MODIFIED {
+            println!("\n\t[ INFO ] Matrix Is Up To Date: {}\n\n", _dst_file);
+            return Ok(false);
+        }
+        if _status == StatusCode::RANGE_NOT_SATISFIABLE {
+            // The partial file is stale or complete, start over without it
+            fs::remove_file(_part_file.as_str())?;
+            return self.download(matrix_type, version, sha256);
+        }
+        if !_status.is_success() {
+            return Err(AssistantError::Download(format!("Server Answered With HTTP Status {} For {}", _status, _url)));
+        }
+        let _resumed = _status == StatusCode::PARTIAL_CONTENT;
+        if !_resumed {
+            _meta.partial_etag = WebClient::header_value(_response.headers(), header::ETAG);
+            _meta.partial_last_modified = WebClient::header_value(_response.headers(), header::LAST_MODIFIED);
+        }
+        let mut _file = FileHandler::open_partial(_part_file.as_str(), _resumed)?;
+        _meta.save(_filename.as_str())?;
+        if let Err(_e) = _response.copy_to(&mut _file) {
+            return Err(AssistantError::Download(format!("Transfer Interrupted - {} | Run The Download Again To Resume It", _e)));
+        }
+        drop(_file);
+        let _json = fs::read_to_string(_part_file.as_str())?;
+        if let Err(_e) = self.verify(matrix_type, &_json, sha256) {
+            fs::remove_file(_part_file.as_str())?;
+            _meta.partial_etag = None;
+            _meta.partial_last_modified = None;
+            _meta.save(_filename.as_str())?;
+            return Err(_e);
         }
-        Ok(_json)
+        let _sha256 = format!("{:x}", Sha256::digest(_json.as_bytes()));
+        if _installed && _meta.sha256.is_empty() {
+            _meta.sha256 = format!("{:x}", Sha256::digest(&fs::read(_dst_file.as_str())?));
+        }
+        let _changed = !_installed || _meta.sha256 != _sha256;
+        if _changed {
+            fs::rename(_part_file.as_str(), _dst_file.as_str())?;
+            println!("\n\t[ INFO ] File Saved: {}\n\n", _dst_file);
+        } else {
+            fs::remove_file(_part_file.as_str())?;
+            println!("\n\t[ INFO ] Matrix Content Unchanged: {}\n\n", _dst_file);
+        }
+        _meta.etag = _meta.partial_etag.take();
+        _meta.last_modified = _meta.partial_last_modified.take();
+        _meta.sha256 = _sha256;
+        _meta.save(_filename.as_str())?;
+        Ok(_changed)
     }
-}
\ No newline at end of file
+    /// # Mitre-Assistant: Check Update (Blocking)
+    /// Asks the server whether a newer matrix than the local copy is available,
+    /// without installing it.
+    ///
+    /// The validators of the previous download are compared first, when the server
+    /// or the local copy has none, the checksums of the remote and local content are compared.
+    ///
+    /// Returns `true` when a newer matrix is available, or when there is no local copy.
+    ///
+    /// ## Example
+    /// ```ignore
+    /// let _wc = WebClient::load()?;
+    ///
+    /// if _wc.check_update("enterprise", "None")? {
+    ///     _wc.download("enterprise", "None", "None")?;
+    /// }
+    /// ```
+    pub fn check_update(&self, matrix_type: &str, version: &str) -> Result<bool, AssistantError>
+    {
+        let _url = self.matrix_url(matrix_type, version);
+        let _filename = FileHandler::versioned_name(matrix_type, version);
+        let _dst_file = FileHandler::resource_path("matrixes", _filename.as_str());
+        if !Path::new(_dst_file.as_str()).is_file() {
+            println!("\n\t[ INFO ] No Local Copy Of The Matrix: {} | Download It With: `download -m {}`\n\n", _filename, matrix_type);
+            return Ok(true);
+        }
+        let mut _meta = DownloadMeta::load(_filename.as_str());
+        if _meta.url != _url {
+            _meta = DownloadMeta::default();
+        }
+        let _client = self.build_client()?;
+        let mut _request = _client.head(_url.as_str());
+        if let Some(_etag) = &_meta.etag {
+            _request = _request.header(header::IF_NONE_MATCH, _etag.as_str());
+        }
+        if let Some(_last_modified) = &_meta.last_modified {
+            _request = _request.header(header::IF_MODIFIED_SINCE, _last_modified.as_str());
+        }
+        let _response = _request.send()?;
+        let _status = _response.status();
+        let _newer = if _status == StatusCode::NOT_MODIFIED {
+            false
+        } else if !_status.is_success() {
+            return Err(AssistantError::Download(format!("Server Answered With HTTP Status {} For {}", _status, _url)));
+        } else {
+            let _etag = WebClient::header_value(_response.headers(), header::ETAG);
+            let _last_modified = WebClient::header_value(_response.headers(), header::LAST_MODIFIED);
+            match (_etag, &_meta.etag, _last_modified, &_meta.last_modified) {
+                (Some(_remote), Some(_local), _, _) => &_remote != _local,
+                (_, _, Some(_remote), Some(_local)) => &_remote != _local,
+                _ => {
+                    let _remote = _client.get(_url.as_str()).send()?.error_for_status()?.bytes()?;
+                    let _local = fs::read(_dst_file.as_str())?;
+                    Sha256::digest(&_remote) != Sha256::digest(&_local)
+                }
+            }
+        };
+        match _newer {
+            true => println!("\n\t[ INFO ] Newer Matrix Available: {} | Update It With: `update -m {}`\n\n", _url, matrix_type),
+            false => println!("\n\t[ INFO ] Matrix Is Up To Date: {}\n\n", _dst_file)
+        }
+        Ok(_newer)
+    }
+    /// # Mitre-Assistant: Verify (Private Method)
+    /// Ensures the downloaded content is a complete STIX bundle of the requested
+    /// matrix, and when a checksum is expected, that the SHA-256 of the content matches.
+    fn verify(&self, matrix_type: &str, content: &str, sha256: &str) -> Result<(), AssistantError>
+    {
+        let _domain = MatrixImporter::validate(content)?;
+        if _domain != matrix_type {
+            return Err(AssistantError::Download(format!("Expected The `{}` Matrix, Downloaded The `{}` Matrix", matrix_type, _domain)));
+        }
+        let _expected = sha256.trim();
+        if _expected != "None" && !_expected.is_empty() {
+            let _actual = format!("{:x}", Sha256::digest(content.as_bytes()));
+            if !_actual.eq_ignore_ascii_case(_expected) {
+                return Err(AssistantError::Download(format!("SHA-256 Mismatch - Expected: {} | Downloaded: {}", _expected, _actual)));
+            }
+        }
+        Ok(())
+    }
+}
+impl Default for WebClient {
+    fn default() -> Self
+    {
+        WebClient::new()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{ BufRead, BufReader, Write };
+    use std::net::TcpListener;
+    use std::thread::{ self, JoinHandle };
+
+    const BUNDLE: &str = r#"{"type": "bundle", "objects": [{"type": "x-mitre-matrix", "external_references": [{"external_id": "enterprise-attack"}]}]}"#;
+
+    /// Answers each connection with the next response, and returns the requests it read.
+    fn serve(responses: Vec<String>) -> (WebClient, JoinHandle<Vec<String>>)
+    {
+        let _listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let _mirror = format!("http://{}", _listener.local_addr().unwrap());
+        let _server = thread::spawn(move || {
+            let mut _requests: Vec<String> = vec![];
+            for _response in responses.iter() {
+                let (mut _stream, _) = _listener.accept().unwrap();
+                let mut _reader = BufReader::new(_stream.try_clone().unwrap());
+                let mut _request = String::new();
+                loop {
+                    let mut _line = String::new();
+                    if _reader.read_line(&mut _line).unwrap() == 0 || _line == "\r\n" {
+                        break;
+                    }
+                    _request.push_str(_line.to_lowercase().as_str());
+                }
+                _stream.write_all(_response.as_bytes()).unwrap();
+                _requests.push(_request);
+            }
+            _requests
+        });
+        let _config = AssistantConfig { mirror: Some(_mirror), ..Default::default() };
+        (WebClient::from_config(_config), _server)
+    }
+
+    fn response(status: &str, headers: &[&str], body: &str) -> String
+    {
+        let mut _headers: Vec<String> = headers.iter().map(|_h| _h.to_string()).collect();
+        _headers.push(format!("Content-Length: {}", body.len()));
+        _headers.push("Connection: close".to_string());
+        format!("HTTP/1.1 {}\r\n{}\r\n\r\n{}", status, _headers.join("\r\n"), body)
+    }
+
+    /// The matrixes of the tests are written in the test config folder, see `FileHandler::config_folder`.
+    fn version(name: &str) -> (String, String)
+    {
+        let _version = format!("test-{}", name);
+        let _filename = FileHandler::versioned_name("enterprise", _version.as_str());
+        (_version, _filename)
+    }
+
+    #[test]
+    fn download_sends_the_etag_and_keeps_an_unchanged_matrix()
+    {
+        let (_version, _filename) = version("etag");
+        let (_wc, _server) = serve(vec![
+            response("200 OK", &["ETag: \"v1\""], BUNDLE),
+            response("304 Not Modified", &[], ""),
+            response("304 Not Modified", &[], ""),
+            response("200 OK", &["ETag: \"v2\""], ""),
+        ]);
+        assert!(_wc.download("enterprise", _version.as_str(), "None").unwrap());
+        let _meta = DownloadMeta::load(_filename.as_str());
+        assert_eq!(_meta.etag.as_deref(), Some("\"v1\""));
+        assert_eq!(_meta.sha256, format!("{:x}", Sha256::digest(BUNDLE.as_bytes())));
+        assert!(!_wc.download("enterprise", _version.as_str(), "None").unwrap());
+        assert!(!_wc.check_update("enterprise", _version.as_str()).unwrap());
+        assert!(_wc.check_update("enterprise", _version.as_str()).unwrap());
+        let _requests = _server.join().unwrap();
+        assert!(!_requests[0].contains("if-none-match"));
+        assert!(_requests[1].starts_with("get ") && _requests[1].contains("if-none-match: \"v1\""));
+        assert!(_requests[2].starts_with("head ") && _requests[2].contains("if-none-match: \"v1\""));
+        assert_eq!(fs::read_to_string(FileHandler::resource_path("matrixes", _filename.as_str())).unwrap(), BUNDLE);
+    }
+
+    #[test]
+    fn download_resumes_a_partial_file()
+    {
+        let (_version, _filename) = version("resume");
+        let (_wc, _server) = serve(vec![
+            response("206 Partial Content", &["ETag: \"v1\""], &BUNDLE[40..]),
+        ]);
+        let _dst_file = FileHandler::resource_path("matrixes", _filename.as_str());
+        let mut _part = FileHandler::open_partial(format!("{}.part", _dst_file).as_str(), false).unwrap();
+        _part.write_all(&BUNDLE.as_bytes()[..40]).unwrap();
+        drop(_part);
+        let _meta = DownloadMeta {
+            url:            _wc.matrix_url("enterprise", _version.as_str()),
+            partial_etag:   Some("\"v1\"".to_string()),
+            ..Default::default()
+        };
+        _meta.save(_filename.as_str()).unwrap();
+        assert!(_wc.download("enterprise", _version.as_str(), "None").unwrap());
+        let _requests = _server.join().unwrap();
+        assert!(_requests[0].contains("range: bytes=40-"));
+        assert!(_requests[0].contains("if-range: \"v1\""));
+        assert_eq!(fs::read_to_string(_dst_file.as_str()).unwrap(), BUNDLE);
+        assert!(!Path::new(format!("{}.part", _dst_file).as_str()).exists());
+        assert_eq!(DownloadMeta::load(_filename.as_str()).etag.as_deref(), Some("\"v1\""));
+    }
+
+    #[test]
+    fn download_rejects_a_checksum_mismatch()
+    {
+        let (_version, _filename) = version("checksum");
+        let (_wc, _server) = serve(vec![
+            response("200 OK", &["ETag: \"v1\""], BUNDLE),
+        ]);
+        let _expected = "0".repeat(64);
+        match _wc.download("enterprise", _version.as_str(), _expected.as_str()) {
+            Err(AssistantError::Download(_reason)) => assert!(_reason.starts_with("SHA-256 Mismatch")),
+            _other => panic!("Expected A Download Error, Found {:?}", _other.map(|_| ()))
+        }
+        _server.join().unwrap();
+        let _dst_file = FileHandler::resource_path("matrixes", _filename.as_str());
+        assert!(!Path::new(_dst_file.as_str()).exists());
+        assert!(!Path::new(format!("{}.part", _dst_file).as_str()).exists());
+        assert_eq!(DownloadMeta::load(_filename.as_str()).partial_etag, None);
+    }
+
+    #[test]
+    fn download_keeps_the_previous_matrix_on_a_bad_answer()
+    {
+        let (_version, _filename) = version("previous");
+        let _mobile = BUNDLE.replace("enterprise-attack", "mobile-attack");
+        let (_wc, _server) = serve(vec![
+            response("200 OK", &["ETag: \"v1\""], BUNDLE),
+            response("404 Not Found", &[], "Not Found"),
+            response("200 OK", &["ETag: \"v2\""], &BUNDLE[..40]),
+            response("200 OK", &["ETag: \"v3\""], "<html>Rate Limited</html>"),
+            response("200 OK", &["ETag: \"v4\""], _mobile.as_str()),
+        ]);
+        assert!(_wc.download("enterprise", _version.as_str(), "None").unwrap());
+        match _wc.download("enterprise", _version.as_str(), "None") {
+            Err(AssistantError::Download(_reason)) => assert!(_reason.starts_with("Server Answered With HTTP Status 404")),
+            _other => panic!("Expected A Download Error, Found {:?}", _other.map(|_| ()))
+        }
+        assert!(matches!(_wc.download("enterprise", _version.as_str(), "None"), Err(AssistantError::Parse(_))));
+        assert!(matches!(_wc.download("enterprise", _version.as_str(), "None"), Err(AssistantError::Parse(_))));
+        match _wc.download("enterprise", _version.as_str(), "None") {
+            Err(AssistantError::Download(_reason)) => assert!(_reason.contains("Downloaded The `mobile` Matrix")),
+            _other => panic!("Expected A Download Error, Found {:?}", _other.map(|_| ()))
+        }
+        _server.join().unwrap();
+        let _dst_file = FileHandler::resource_path("matrixes", _filename.as_str());
+        assert_eq!(fs::read_to_string(_dst_file.as_str()).unwrap(), BUNDLE);
+        assert!(!Path::new(format!("{}.part", _dst_file).as_str()).exists());
+        let _meta = DownloadMeta::load(_filename.as_str());
+        assert_eq!(_meta.etag.as_deref(), Some("\"v1\""));
+        assert_eq!(_meta.sha256, format!("{:x}", Sha256::digest(BUNDLE.as_bytes())));
+    }
+}
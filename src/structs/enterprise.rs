@@ -1,6 +1,8 @@
 use serde_derive::{Deserialize, Serialize};
 use std::collections::HashSet;
 
+use crate::structs::types::{ pipe_or_list, Datasource, Platform, Tactic };
+
 
 #[derive(Debug,Deserialize, Serialize)]
 pub struct EnterpriseMatrixStatistics {
@@ -68,8 +70,8 @@ pub struct EnterpriseMatrixStatistics {
     pub count_subtechniques_impact:            usize,
     // Percentages of Specific Items
     // Use these with stats function
-    // To get the percentage, go to the parser.rs module
-    // and invoke the `get_percentage()` private method.
+    // To get the percentage, invoke the
+    // `EnterpriseMatrixStatistics::get_percentage()` function.
     // The total param is by the:
     //      `count_active_total_techniques`
     //      `count_active_total_subtechniques`
@@ -228,6 +230,25 @@ impl EnterpriseMatrixStatistics {
             percent_subtechniques_impact:                  String::from(""),                                      
         }
     }
+    /// # Get Percentage
+    /// Returns the share of `actual` in `total` as a whole percentage string - e.g., `42%`,
+    /// the fraction is dropped, and an empty `total` is `0%`.  Used by the stats and the coverage reports.
+    pub fn get_percentage(total: usize, actual: usize) -> String
+    {
+        if total == 0 {
+            return "0%".to_string();
+        }
+        let _high = total as f64;
+        let _low = actual as f64;
+        let _percent = (_low / _high) * 100f64;
+        format!("{}%", _percent.floor())
+    }
+}
+impl Default for EnterpriseMatrixStatistics {
+    fn default() -> Self
+    {
+        EnterpriseMatrixStatistics::new()
+    }
 }
 
 
@@ -249,16 +270,26 @@ impl EnterpriseTechniquesByPlatform {
         self.count = self.platforms.len();
     }
 }
+impl Default for EnterpriseTechniquesByPlatform {
+    fn default() -> Self
+    {
+        EnterpriseTechniquesByPlatform::new()
+    }
+}
 
 
+/// A technique of a tactic, the baselines written before the typed fields stored
+/// the platforms and the datasources as `|` joined strings, they are still read.
 #[derive(Debug,Deserialize, Serialize, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct EnterpriseTechnique {
     pub id:             String,
-    pub platform:       String,
+    #[serde(deserialize_with = "pipe_or_list")]
+    pub platform:       Vec<Platform>,
     pub tid:            String,
     pub technique:      String,
-    pub tactic:         String,
-    pub datasources:    String,
+    pub tactic:         Tactic,
+    #[serde(deserialize_with = "pipe_or_list")]
+    pub datasources:    Vec<Datasource>,
     pub has_subtechniques: bool,
     pub is_deprecated:  bool,
     pub is_revoked:     bool,
@@ -270,11 +301,11 @@ impl EnterpriseTechnique {
     {
         EnterpriseTechnique {
             id:                 String::from(""),
-            platform:           String::from("n_a"),
+            platform:           vec![],
             tid:                String::from(""),
             technique:          String::from(""),
-            tactic:             String::from("n_a"),
-            datasources:        String::from("n_a"),
+            tactic:             Tactic::None,
+            datasources:        vec![],
             has_subtechniques:  false,
             is_deprecated:      false,
             is_revoked:         false,
@@ -287,6 +318,12 @@ impl EnterpriseTechnique {
         self.count_subtechniques = self.subtechniques.len();
     }
 }
+impl Default for EnterpriseTechnique {
+    fn default() -> Self
+    {
+        EnterpriseTechnique::new()
+    }
+}
 
 
 #[derive(Debug,Deserialize, Serialize)]
@@ -306,6 +343,12 @@ impl EnterpriseSubtechniquesByPlatform {
         self.count = self.platforms.len();
     }
 }
+impl Default for EnterpriseSubtechniquesByPlatform {
+    fn default() -> Self
+    {
+        EnterpriseSubtechniquesByPlatform::new()
+    }
+}
 
 
 #[derive(Debug,Deserialize, Serialize)]
@@ -339,11 +382,12 @@ impl EnterpriseTactic {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd)]
 pub struct EnterpriseAdversary {
     pub id:         String,
     pub name:       String,
-    pub aliases:    String,
+    #[serde(deserialize_with = "pipe_or_list")]
+    pub aliases:    Vec<String>,
     pub group_id:   String,
     pub is_revoked: bool,
     pub profile:    EnterpriseAdversaryProfile,
@@ -354,7 +398,7 @@ impl EnterpriseAdversary {
         EnterpriseAdversary {
             id:         "none".to_string(),
             name:       "none".to_string(),
-            aliases:    "none".to_string(),
+            aliases:    vec![],
             group_id:   "none".to_string(),
             is_revoked: false,
             profile:    EnterpriseAdversaryProfile::new()
@@ -364,10 +408,29 @@ impl EnterpriseAdversary {
     {
         self.profile.update();
     }
+    /// # Adversary - Is Named
+    /// Returns `true` when `name` is the name, the group ID or an alias of the
+    /// adversary, ignoring the case and the spaces - e.g., `apt 29` or `G0016`.
+    pub fn is_named(&self, name: &str) -> bool
+    {
+        is_named(name, self.name.as_str(), self.group_id.as_str(), &self.aliases)
+    }
+    /// # Adversary - Uses
+    /// Returns `true` when the adversary uses a technique or subtechnique.
+    pub fn uses(&self, tid: &str) -> bool
+    {
+        uses(tid, &self.profile.techniques, &self.profile.subtechniques)
+    }
+}
+impl Default for EnterpriseAdversary {
+    fn default() -> Self
+    {
+        EnterpriseAdversary::new()
+    }
 }
 
 
-#[derive(Debug, Deserialize, Serialize, Hash, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Debug, Clone, Deserialize, Serialize, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct EnterpriseAdversaryProfile {
     pub malware:        EnterpriseProfileEntry,
     pub tools:          EnterpriseProfileEntry,
@@ -395,9 +458,15 @@ impl EnterpriseAdversaryProfile {
         self.tactics.update();
     }
 }
+impl Default for EnterpriseAdversaryProfile {
+    fn default() -> Self
+    {
+        EnterpriseAdversaryProfile::new()
+    }
+}
 
 
-#[derive(Debug, Deserialize, Serialize, Hash, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Debug, Clone, Deserialize, Serialize, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct EnterpriseProfileEntry {
     pub count: usize,
     pub items: Vec<String>
@@ -418,14 +487,22 @@ impl EnterpriseProfileEntry {
         self.count = self.items.len();
     }
 }
+impl Default for EnterpriseProfileEntry {
+    fn default() -> Self
+    {
+        EnterpriseProfileEntry::new()
+    }
+}
 
 
 #[derive(Debug, Deserialize, Serialize, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct EnterpriseTool {
     pub id:         String,
     pub name:       String,
-    pub aliases:    String,
-    pub platforms:  String,
+    #[serde(deserialize_with = "pipe_or_list")]
+    pub aliases:    Vec<String>,
+    #[serde(deserialize_with = "pipe_or_list")]
+    pub platforms:  Vec<Platform>,
     pub tool_id:    String,
     pub is_revoked: bool,
     pub profile:    EnterpriseToolProfile,
@@ -437,8 +514,8 @@ impl EnterpriseTool {
         EnterpriseTool {
             id:         "none".to_string(),
             name:       "none".to_string(),
-            aliases:    "none".to_string(),
-            platforms:  "none".to_string(),
+            aliases:    vec![],
+            platforms:  vec![],
             tool_id:    "none".to_string(),
             is_revoked: false,
             profile:    EnterpriseToolProfile::new()
@@ -448,6 +525,25 @@ impl EnterpriseTool {
     {
         self.profile.update();
     }
+    /// # Tool - Is Named
+    /// Returns `true` when `name` is the name, the tool ID or an alias of the tool,
+    /// ignoring the case and the spaces - e.g., `net` or `S0039`.
+    pub fn is_named(&self, name: &str) -> bool
+    {
+        is_named(name, self.name.as_str(), self.tool_id.as_str(), &self.aliases)
+    }
+    /// # Tool - Uses
+    /// Returns `true` when the tool is used for a technique or subtechnique.
+    pub fn uses(&self, tid: &str) -> bool
+    {
+        uses(tid, &self.profile.techniques, &self.profile.subtechniques)
+    }
+}
+impl Default for EnterpriseTool {
+    fn default() -> Self
+    {
+        EnterpriseTool::new()
+    }
 }
 
 
@@ -470,14 +566,122 @@ impl EnterpriseToolProfile {
         self.subtechniques.update();
     }
 }
+impl Default for EnterpriseToolProfile {
+    fn default() -> Self
+    {
+        EnterpriseToolProfile::new()
+    }
+}
+
+
+/// # Enterprise Description
+/// The text of a technique, subtechnique, tactic, adversary, malware, tool or
+/// mitigation, without its citations - kept for the full-text search.
+#[derive(Debug, Deserialize, Serialize, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct EnterpriseDescription {
+    pub kind:           String,
+    pub id:             String,
+    pub name:           String,
+    pub description:    String,
+    /// The `x_mitre_detection` of a technique, `none` otherwise
+    pub detection:      String,
+}
+
+
+/// # Enterprise Mitigation
+/// A `course-of-action` of the matrix - e.g., `M1026 Privileged Account Management`.
+#[derive(Debug, Deserialize, Serialize, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct EnterpriseMitigation {
+    pub id:             String,
+    pub name:           String,
+    pub mitigation_id:  String,
+    pub is_deprecated:  bool,
+    #[serde(default)]
+    pub profile:        EnterpriseMitigationProfile,
+}
+impl EnterpriseMitigation {
+    pub fn new() -> Self
+    {
+        EnterpriseMitigation {
+            id:             "none".to_string(),
+            name:           "none".to_string(),
+            mitigation_id:  "none".to_string(),
+            is_deprecated:  false,
+            profile:        EnterpriseMitigationProfile::new(),
+        }
+    }
+}
+impl Default for EnterpriseMitigation {
+    fn default() -> Self
+    {
+        EnterpriseMitigation::new()
+    }
+}
+
+
+/// # Enterprise Mitigation Profile
+/// The techniques and subtechniques of a `mitigates` relationship.
+#[derive(Debug, Deserialize, Serialize, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct EnterpriseMitigationProfile {
+    pub techniques:     EnterpriseProfileEntry,
+    pub subtechniques:  EnterpriseProfileEntry
+}
+impl EnterpriseMitigationProfile {
+    pub fn new() -> Self
+    {
+        EnterpriseMitigationProfile {
+            techniques:     EnterpriseProfileEntry::new(),
+            subtechniques:  EnterpriseProfileEntry::new()
+        }
+    }
+    pub fn update(&mut self)
+    {
+        self.techniques.update();
+        self.subtechniques.update();
+    }
+}
+impl Default for EnterpriseMitigationProfile {
+    fn default() -> Self
+    {
+        EnterpriseMitigationProfile::new()
+    }
+}
+
+
+/// # Enterprise Tactic Detail
+/// An `x-mitre-tactic` of the matrix - e.g., `TA0006 Credential Access`, its
+/// shortname is the tactic of the techniques.
+#[derive(Debug, Deserialize, Serialize, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct EnterpriseTacticDetail {
+    pub id:             String,
+    pub name:           String,
+    pub tactic_id:      String,
+    pub shortname:      String,
+}
+
+
+/// # Enterprise Reference
+/// An external reference of a technique, subtechnique, tactic, adversary, malware,
+/// tool or mitigation, `none` for a missing value.
+#[derive(Debug, Clone, Deserialize, Serialize, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct EnterpriseReference {
+    /// The ATT&CK ID of the object citing the reference - e.g., `T1003`
+    pub id:             String,
+    pub source:         String,
+    pub external_id:    String,
+    pub url:            String,
+    pub description:    String,
+}
 
 
 #[derive(Debug, Deserialize, Serialize, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct EnterpriseMalware {
     pub id:         String,
     pub name:       String,
-    pub aliases:    String,
-    pub platforms:  String,
+    #[serde(deserialize_with = "pipe_or_list")]
+    pub aliases:    Vec<String>,
+    #[serde(deserialize_with = "pipe_or_list")]
+    pub platforms:  Vec<Platform>,
     pub malware_id: String,
     pub is_revoked: bool,
     pub profile:    EnterpriseMalwareProfile
@@ -488,13 +692,32 @@ impl EnterpriseMalware {
         EnterpriseMalware {
             id:         "none".to_string(),
             name:       "none".to_string(),
-            aliases:    "none".to_string(),
-            platforms:  "none".to_string(),
+            aliases:    vec![],
+            platforms:  vec![],
             malware_id: "none".to_string(),
             is_revoked: false,
             profile:    EnterpriseMalwareProfile::new()
         }
     }
+    /// # Malware - Is Named
+    /// Returns `true` when `name` is the name, the malware ID or an alias of the
+    /// malware, ignoring the case and the spaces - e.g., `cobalt strike` or `S0154`.
+    pub fn is_named(&self, name: &str) -> bool
+    {
+        is_named(name, self.name.as_str(), self.malware_id.as_str(), &self.aliases)
+    }
+    /// # Malware - Uses
+    /// Returns `true` when the malware is used for a technique or subtechnique.
+    pub fn uses(&self, tid: &str) -> bool
+    {
+        uses(tid, &self.profile.techniques, &self.profile.subtechniques)
+    }
+}
+impl Default for EnterpriseMalware {
+    fn default() -> Self
+    {
+        EnterpriseMalware::new()
+    }
 }
 
 
@@ -517,6 +740,12 @@ impl EnterpriseMalwareProfile {
         self.subtechniques.update();
     }
 }
+impl Default for EnterpriseMalwareProfile {
+    fn default() -> Self
+    {
+        EnterpriseMalwareProfile::new()
+    }
+}
 
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -525,7 +754,11 @@ pub struct EnterpriseRelationships {
     pub adversary_to_techniques:    HashSet<EnterpriseRelationship>,
     pub adversary_to_tools:         HashSet<EnterpriseRelationship>,
     pub malware_to_techniques:      HashSet<EnterpriseRelationship>,
-    pub tool_to_techniques:         HashSet<EnterpriseRelationship>
+    pub tool_to_techniques:         HashSet<EnterpriseRelationship>,
+    #[serde(default)]
+    pub revoked_by:                 HashSet<EnterpriseRelationship>,
+    #[serde(default)]
+    pub mitigation_to_techniques:   HashSet<EnterpriseRelationship>
 }
 impl EnterpriseRelationships {
     pub fn new() -> Self
@@ -535,10 +768,18 @@ impl EnterpriseRelationships {
             adversary_to_techniques:    HashSet::new(),
             adversary_to_tools:         HashSet::new(),
             malware_to_techniques:      HashSet::new(),
-            tool_to_techniques:         HashSet::new()
+            tool_to_techniques:         HashSet::new(),
+            revoked_by:                 HashSet::new(),
+            mitigation_to_techniques:   HashSet::new()
         }
     }
 }
+impl Default for EnterpriseRelationships {
+    fn default() -> Self
+    {
+        EnterpriseRelationships::new()
+    }
+}
 
 
 #[derive(Debug, Deserialize, Serialize, Hash, Eq, PartialEq, PartialOrd, Ord)]
@@ -558,4 +799,29 @@ impl EnterpriseRelationship {
             target:         "none".to_string()
         }
     }
-}
\ No newline at end of file
+}
+impl Default for EnterpriseRelationship {
+    fn default() -> Self
+    {
+        EnterpriseRelationship::new()
+    }
+}
+
+
+/// # Is Named (Private Function)
+/// The aliases of the baseline are lowercase with dashes, the names are compared
+/// the same way.
+fn is_named(wanted: &str, name: &str, id: &str, aliases: &[String]) -> bool
+{
+    let _normalize = |_name: &str| _name.trim().to_lowercase().replace(" ", "-");
+    let _wanted = _normalize(wanted);
+    _normalize(name) == _wanted || id.to_lowercase() == _wanted || aliases.contains(&_wanted)
+}
+
+
+/// # Uses (Private Function)
+fn uses(tid: &str, techniques: &EnterpriseProfileEntry, subtechniques: &EnterpriseProfileEntry) -> bool
+{
+    let _tid = tid.to_uppercase();
+    techniques.items.contains(&_tid) || subtechniques.items.contains(&_tid)
+}
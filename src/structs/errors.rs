@@ -1,16 +1,138 @@
-use std::fmt::{self, Formatter, Display, Debug};
+use std::fmt::{self, Formatter, Display};
 
-pub struct CustomPathError;
 
-impl fmt::Display for CustomPathError {
+/// # Assistant Error
+/// The errors of the assistant, returned by the library and mapped to an exit
+/// code by the command line.  The message of each error is meant for the user,
+/// with a hint on how to solve it when there is one.
+///
+/// # Example
+/// ```ignore
+/// return Err(AssistantError::MissingResource("Baseline Not Found: baseline-enterprise.json".to_string()));
+///
+/// std::process::exit(_err.exit_code());
+/// ```
+pub enum AssistantError {
+    /// A file or folder cannot be read or written
+    Io(std::io::Error),
+    /// A file given by the user cannot be read, with its path
+    File { path: String, source: std::io::Error },
+    /// A download failed or cannot be trusted - i.e., a bad HTTP status,
+    /// a truncated transfer or a checksum mismatch
+    Download(String),
+    /// A bundle, a baseline or an input file is malformed
+    Parse(String),
+    /// A matrix, a baseline or an input file does not exist
+    MissingResource(String),
+    /// A search term, an argument or a selection is not valid
+    InvalidQuery(String),
+}
+impl AssistantError {
+    /// # Assistant Error - Exit Code
+    /// Returns the exit code of the error, following the `sysexits.h` convention.
+    pub fn exit_code(&self) -> i32
+    {
+        match self {
+            AssistantError::InvalidQuery(_) => 64,      // EX_USAGE
+            AssistantError::Parse(_) => 65,             // EX_DATAERR
+            AssistantError::MissingResource(_) => 66,   // EX_NOINPUT
+            AssistantError::Download(_) => 69,          // EX_UNAVAILABLE
+            AssistantError::Io(_) | AssistantError::File { .. } => 74,   // EX_IOERR
+        }
+    }
+}
+
+impl fmt::Display for AssistantError {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result
     {
-        write!(f, "Custom Path Error: Cannot Find Resource in Config Folder")
+        match self {
+            AssistantError::Io(_e) => write!(f, "File Error: {}", _e),
+            AssistantError::File { path, source } => write!(f, "File Error: {} | {}", path, source),
+            AssistantError::Download(_reason) => write!(f, "Download Error: {}", _reason),
+            AssistantError::Parse(_reason) => write!(f, "Parse Error: {}", _reason),
+            AssistantError::MissingResource(_reason) => write!(f, "Missing Resource: {}", _reason),
+            AssistantError::InvalidQuery(_reason) => write!(f, "Invalid Query: {}", _reason),
+        }
     }
 }
 
-impl fmt::Debug for CustomPathError {
+impl fmt::Debug for AssistantError {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{{ file: {}, line: {} }}", file!(), line!()) // programmer-facing output
+        Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for AssistantError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)>
+    {
+        match self {
+            AssistantError::Io(_e) | AssistantError::File { source: _e, .. } => Some(_e),
+            _ => None
+        }
+    }
+}
+
+impl From<std::io::Error> for AssistantError {
+    fn from(e: std::io::Error) -> Self
+    {
+        AssistantError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for AssistantError {
+    fn from(e: serde_json::Error) -> Self
+    {
+        match e.classify() {
+            serde_json::error::Category::Io => AssistantError::Io(e.into()),
+            _ => AssistantError::Parse(format!("Malformed JSON | {}", e))
+        }
+    }
+}
+
+impl From<csv::Error> for AssistantError {
+    fn from(e: csv::Error) -> Self
+    {
+        match e.into_kind() {
+            csv::ErrorKind::Io(_e) => AssistantError::Io(_e),
+            _kind => AssistantError::Parse(format!("Malformed CSV | {:?}", _kind))
+        }
     }
-}
\ No newline at end of file
+}
+
+impl From<reqwest::Error> for AssistantError {
+    fn from(e: reqwest::Error) -> Self
+    {
+        AssistantError::Download(e.to_string())
+    }
+}
+
+impl From<std::num::ParseIntError> for AssistantError {
+    fn from(e: std::num::ParseIntError) -> Self
+    {
+        AssistantError::InvalidQuery(format!("Expected A Number | {}", e))
+    }
+}
+
+impl From<std::num::ParseFloatError> for AssistantError {
+    fn from(e: std::num::ParseFloatError) -> Self
+    {
+        AssistantError::InvalidQuery(format!("Expected A Number | {}", e))
+    }
+}
+
+impl From<regex::Error> for AssistantError {
+    fn from(e: regex::Error) -> Self
+    {
+        AssistantError::InvalidQuery(format!("Invalid Pattern | {}", e))
+    }
+}
+
+impl From<rustyline::error::ReadlineError> for AssistantError {
+    fn from(e: rustyline::error::ReadlineError) -> Self
+    {
+        match e {
+            rustyline::error::ReadlineError::Io(_e) => AssistantError::Io(_e),
+            _e => AssistantError::Io(std::io::Error::other(_e.to_string()))
+        }
+    }
+}
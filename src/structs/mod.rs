@@ -1,2 +1,3 @@
 pub mod errors;
-pub mod enterprise;
\ No newline at end of file
+pub mod enterprise;
+pub mod types;
@@ -0,0 +1,316 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{ Deserialize, Deserializer };
+use serde_derive::{ Deserialize, Serialize };
+
+
+use crate::structs::errors::AssistantError;
+
+
+/// # Slug Enum (Private Macro)
+/// Declares an enum of the names used by the ATT&CK matrixes, read and written as
+/// their slug - e.g., `azure-ad`.  A name that is not a variant is kept as `Other`,
+/// so a baseline of a newer release can still be read.
+///
+/// A placeholder variant can follow the names, for the rows without a value - it
+/// is not part of `ALL` and the user cannot ask for it.
+macro_rules! slug_enum {
+    ($name:ident, $label:expr, { $($variant:ident => $slug:expr),+ $(,)? }) => {
+        slug_enum!($name, $label, { $($variant => $slug),+ }, {});
+    };
+    ($name:ident, $label:expr, { $($variant:ident => $slug:expr),+ $(,)? },
+        { $($blank:ident => $blank_slug:expr $(, $blank_alias:expr)*)? }) => {
+        #[derive(Debug, Clone, Deserialize, Serialize, Hash, Eq, PartialEq, Ord, PartialOrd)]
+        #[serde(from = "String", into = "String")]
+        pub enum $name {
+            $($variant,)+
+            $($blank,)?
+            Other(String),
+        }
+        impl $name {
+            /// Every variant, `Other` excepted, in the order of the matrix.
+            pub const ALL: &'static [$name] = &[$($name::$variant),+];
+            /// Reads a name, ignoring the case, the spaces and the underscores.
+            pub fn parse(name: &str) -> Self
+            {
+                let _slug = slug(name);
+                match _slug.as_str() {
+                    $($slug => $name::$variant,)+
+                    $($blank_slug $(| $blank_alias)* => $name::$blank,)?
+                    _ => $name::Other(_slug),
+                }
+            }
+            pub fn as_str(&self) -> &str
+            {
+                match self {
+                    $($name::$variant => $slug,)+
+                    $($name::$blank => $blank_slug,)?
+                    $name::Other(_slug) => _slug.as_str(),
+                }
+            }
+            /// Returns `true` when the name is not one of `ALL` - an unknown name or
+            /// the placeholder.
+            pub fn is_other(&self) -> bool
+            {
+                match self {
+                    $($name::$blank)|* | $name::Other(_) => true,
+                    _ => false
+                }
+            }
+        }
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+            {
+                write!(f, "{}", self.as_str())
+            }
+        }
+        /// Strict parsing, for the names given by the user.
+        impl FromStr for $name {
+            type Err = AssistantError;
+            fn from_str(name: &str) -> Result<Self, Self::Err>
+            {
+                match $name::parse(name) {
+                    $($name::$blank)|* | $name::Other(_) => {
+                        let _known: Vec<&str> = $name::ALL.iter().map(|_v| _v.as_str()).collect();
+                        Err(AssistantError::InvalidQuery(format!("{} Not Found: {} | Use: {}", $label, name, _known.join(", "))))
+                    },
+                    _value => Ok(_value)
+                }
+            }
+        }
+        impl From<String> for $name {
+            fn from(name: String) -> Self
+            {
+                $name::parse(name.as_str())
+            }
+        }
+        impl From<$name> for String {
+            fn from(value: $name) -> Self
+            {
+                value.as_str().to_string()
+            }
+        }
+    };
+}
+
+
+slug_enum!(Tactic, "Tactic", {
+    Reconnaissance      => "reconnaissance",
+    ResourceDevelopment => "resource-development",
+    InitialAccess       => "initial-access",
+    Execution           => "execution",
+    Persistence         => "persistence",
+    PrivilegeEscalation => "privilege-escalation",
+    DefenseEvasion      => "defense-evasion",
+    CredentialAccess    => "credential-access",
+    Discovery           => "discovery",
+    LateralMovement     => "lateral-movement",
+    Collection          => "collection",
+    CommandAndControl   => "command-and-control",
+    Exfiltration        => "exfiltration",
+    Impact              => "impact",
+}, {
+    // The tactic of the rows without a kill chain phase, `n_a` in the baselines
+    None                => "n-a", "none", ""
+});
+
+
+slug_enum!(Platform, "Platform", {
+    Aws         => "aws",
+    Azure       => "azure",
+    AzureAd     => "azure-ad",
+    Gcp         => "gcp",
+    Linux       => "linux",
+    Macos       => "macos",
+    Office365   => "office-365",
+    Saas        => "saas",
+    Windows     => "windows",
+});
+
+
+slug_enum!(StixKind, "STIX Kind", {
+    AttackPattern       => "attack-pattern",
+    CourseOfAction      => "course-of-action",
+    Identity            => "identity",
+    IntrusionSet        => "intrusion-set",
+    Malware             => "malware",
+    MarkingDefinition   => "marking-definition",
+    Relationship        => "relationship",
+    Tool                => "tool",
+    XMitreMatrix        => "x-mitre-matrix",
+    XMitreTactic        => "x-mitre-tactic",
+});
+impl StixKind {
+    /// # STIX Kind - Of ID
+    /// Returns the kind of a STIX ID - e.g., `intrusion-set--c2d561ac-...` is an `IntrusionSet`.
+    pub fn of_id(id: &str) -> Self
+    {
+        StixKind::parse(id.split("--").next().unwrap_or(""))
+    }
+}
+
+
+/// # Datasource
+/// A datasource of a technique, kept as its slug - e.g., `Windows Registry` is
+/// `windows-registry` and `API monitoring` is `api-monitoring`.
+#[derive(Debug, Clone, Deserialize, Serialize, Hash, Eq, PartialEq, Ord, PartialOrd)]
+#[serde(from = "String", into = "String")]
+pub struct Datasource(String);
+impl Datasource {
+    pub fn parse(name: &str) -> Self
+    {
+        Datasource(name.trim().to_lowercase().replace(" ", "-").replace("/", "-"))
+    }
+    pub fn as_str(&self) -> &str
+    {
+        self.0.as_str()
+    }
+}
+impl fmt::Display for Datasource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        write!(f, "{}", self.0)
+    }
+}
+impl FromStr for Datasource {
+    type Err = AssistantError;
+    fn from_str(name: &str) -> Result<Self, Self::Err>
+    {
+        match name.trim().is_empty() {
+            true => Err(AssistantError::InvalidQuery("Datasource Not Found: An Empty Name".to_string())),
+            false => Ok(Datasource::parse(name))
+        }
+    }
+}
+impl From<String> for Datasource {
+    fn from(name: String) -> Self
+    {
+        Datasource::parse(name.as_str())
+    }
+}
+impl From<Datasource> for String {
+    fn from(value: Datasource) -> Self
+    {
+        value.0
+    }
+}
+
+
+/// # Slug (Private Function)
+/// Normalizes a name the way the parser does - e.g., `Azure AD` is `azure-ad`.
+fn slug(name: &str) -> String
+{
+    name.trim().to_lowercase().replace(" ", "-").replace("_", "-")
+}
+
+
+/// # Join
+/// Joins typed values for a table or a CSV cell, an empty list is `none`.
+///
+/// ```ignore
+/// assert_eq!(join(&_technique.platform, "|"), "linux|windows");
+/// ```
+pub fn join<T: fmt::Display>(values: &[T], separator: &str) -> String
+{
+    match values.is_empty() {
+        true => "none".to_string(),
+        false => values.iter().map(|_v| _v.to_string()).collect::<Vec<String>>().join(separator)
+    }
+}
+
+
+/// # Pipe Or List
+/// Deserializes a list written by this release, or the `|` joined string of the
+/// baselines written by earlier releases - where `none` and `n_a` are an empty list.
+///
+/// ```ignore
+/// #[serde(deserialize_with = "pipe_or_list")]
+/// pub platform: Vec<Platform>,
+/// ```
+pub fn pipe_or_list<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where D: Deserializer<'de>, T: From<String>
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum PipeOrList {
+        List(Vec<String>),
+        Pipe(String),
+    }
+    let _values = match PipeOrList::deserialize(deserializer)? {
+        PipeOrList::List(_values) => _values,
+        PipeOrList::Pipe(_value) => _value.split('|').map(|_v| _v.to_string()).collect()
+    };
+    Ok(_values.into_iter()
+              .filter(|_v| !_v.is_empty() && _v != "none" && _v != "n_a")
+              .map(T::from)
+              .collect())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::enterprise::EnterpriseTechnique;
+
+    #[test]
+    fn slug_enum_parses_the_names_of_the_matrix()
+    {
+        assert_eq!(Tactic::parse("Credential Access"), Tactic::CredentialAccess);
+        assert_eq!(Tactic::parse("command_and_control"), Tactic::CommandAndControl);
+        assert_eq!(Platform::parse(" Azure AD "), Platform::AzureAd);
+        assert_eq!(Platform::parse("Containers"), Platform::Other("containers".to_string()));
+        assert!(Platform::parse("containers").is_other());
+        assert_eq!(StixKind::of_id("intrusion-set--c2d561ac"), StixKind::IntrusionSet);
+        assert_eq!(Platform::Office365.to_string(), "office-365");
+    }
+
+    #[test]
+    fn slug_enum_reads_the_placeholder_but_the_user_cannot_ask_for_it()
+    {
+        for _name in ["n_a", "n-a", "none", ""].iter() {
+            assert_eq!(Tactic::parse(_name), Tactic::None);
+            assert!(_name.parse::<Tactic>().is_err());
+        }
+        assert!(Tactic::None.is_other());
+        assert!(!Tactic::ALL.contains(&Tactic::None));
+        assert_eq!(Tactic::ALL.len(), 14);
+        assert!("Credential Access".parse::<Tactic>().is_ok());
+        assert!("windoze".parse::<Platform>().is_err());
+    }
+
+    #[test]
+    fn slug_enum_orders_the_tactics_by_the_kill_chain()
+    {
+        let mut _tactics = vec![Tactic::Other("zzz".to_string()), Tactic::Impact, Tactic::None, Tactic::Reconnaissance];
+        _tactics.sort();
+        assert_eq!(_tactics, vec![Tactic::Reconnaissance, Tactic::Impact, Tactic::None, Tactic::Other("zzz".to_string())]);
+    }
+
+    #[test]
+    fn datasource_normalizes_its_name()
+    {
+        assert_eq!(Datasource::parse("Windows Registry").as_str(), "windows-registry");
+        assert_eq!(Datasource::parse("Process use of network/API").as_str(), "process-use-of-network-api");
+        assert!(" ".parse::<Datasource>().is_err());
+        assert_eq!(join::<Platform>(&[], "|"), "none");
+        assert_eq!(join(&[Platform::Linux, Platform::Windows], "|"), "linux|windows");
+    }
+
+    #[test]
+    fn pipe_or_list_reads_old_and_new_baselines()
+    {
+        let _old = r#"{"id":"attack-pattern--1","platform":"linux|windows","tid":"T1003","technique":"OS Credential Dumping",
+            "tactic":"n_a","datasources":"none","has_subtechniques":false,"is_deprecated":false,"is_revoked":false,
+            "subtechniques":[],"count_subtechniques":0}"#;
+        let _row: EnterpriseTechnique = serde_json::from_str(_old).unwrap();
+        assert_eq!(_row.platform, vec![Platform::Linux, Platform::Windows]);
+        assert!(_row.datasources.is_empty());
+        assert_eq!(_row.tactic, Tactic::None);
+        let _new = serde_json::to_string(&_row).unwrap();
+        assert!(_new.contains(r#""platform":["linux","windows"]"#));
+        let _row: EnterpriseTechnique = serde_json::from_str(_new.as_str()).unwrap();
+        assert_eq!(_row.platform, vec![Platform::Linux, Platform::Windows]);
+        assert_eq!(_row.tactic, Tactic::None);
+    }
+}
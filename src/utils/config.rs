@@ -0,0 +1,100 @@
+use std::env;
+use std::path::Path;
+
+use serde_derive::{Deserialize, Serialize};
+use crate::structs::errors::AssistantError;
+use crate::utils::fshandler::FileHandler;
+
+
+/// # Globals
+/// Environment variables that override the settings of the config file.
+static _ENV_CONFIG: &str = "MITRE_ASSISTANT_CONFIG";
+static _ENV_MIRROR: &str = "MITRE_ASSISTANT_MIRROR";
+static _ENV_PROXY: &str = "MITRE_ASSISTANT_PROXY";
+static _ENV_CA_BUNDLE: &str = "MITRE_ASSISTANT_CA_BUNDLE";
+static _ENV_TIMEOUT: &str = "MITRE_ASSISTANT_TIMEOUT";
+
+
+/// # Assistant Config
+/// Settings used when connecting to the internet, loaded from the
+/// `config.json` file of the config folder and overridden by environment variables.
+///
+/// ```text
+/// ~/.mitre-assistant/config.json
+///
+/// {
+///     "mirror":    "http://cti-mirror.internal/mitre/cti",
+///     "proxy":     "http://proxy.internal:3128",
+///     "ca_bundle": "/etc/ssl/certs/internal-ca.pem",
+///     "timeout":   120
+/// }
+/// ```
+///
+/// | Setting     | Environment Variable          | Purpose                                        |
+/// |-------------|-------------------------------|------------------------------------------------|
+/// | n/a         | `MITRE_ASSISTANT_CONFIG`      | Path of an alternate config file               |
+/// | `mirror`    | `MITRE_ASSISTANT_MIRROR`      | Base URL laid out like the Mitre CTI repo      |
+/// | `proxy`     | `MITRE_ASSISTANT_PROXY`       | HTTP(S) proxy for all requests                 |
+/// | `ca_bundle` | `MITRE_ASSISTANT_CA_BUNDLE`   | PEM file with an additional root certificate   |
+/// | `timeout`   | `MITRE_ASSISTANT_TIMEOUT`     | Request timeout in seconds                     |
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct AssistantConfig {
+    #[serde(default)]
+    pub mirror:     Option<String>,
+    #[serde(default)]
+    pub proxy:      Option<String>,
+    #[serde(default)]
+    pub ca_bundle:  Option<String>,
+    #[serde(default)]
+    pub timeout:    Option<u64>,
+}
+impl AssistantConfig {
+    /// # Assistant Config - Load
+    /// Reads the config file when it exists, then applies the overrides
+    /// from the environment variables.
+    ///
+    /// ```ignore
+    /// let _config = AssistantConfig::load()?;
+    /// ```
+    pub fn load() -> Result<Self, AssistantError>
+    {
+        let _path = match env::var(_ENV_CONFIG).ok().filter(|_p| !_p.trim().is_empty()) {
+            Some(_p) => _p,
+            None => format!("{}/{}", FileHandler::config_folder(), "config.json")
+        };
+        let mut _config = if Path::new(_path.as_str()).is_file() {
+            let _content = FileHandler::read_to_string(_path.as_str())?;
+            match serde_json::from_str::<AssistantConfig>(_content.as_str()) {
+                Ok(_c) => _c,
+                Err(_e) => return Err(AssistantError::Parse(format!("Invalid Config File: {} - {}", _path, _e)))
+            }
+        } else {
+            AssistantConfig::default()
+        };
+        _config.apply_env()?;
+        Ok(_config)
+    }
+    /// # Assistant Config - Apply Environment (Private Method)
+    /// Environment variables take precedence over the config file,
+    /// variables set to an empty value are ignored.
+    fn apply_env(&mut self) -> Result<(), AssistantError>
+    {
+        let _var = |name: &str| env::var(name).ok().filter(|_v| !_v.trim().is_empty());
+        if let Some(_v) = _var(_ENV_MIRROR) {
+            self.mirror = Some(_v);
+        }
+        if let Some(_v) = _var(_ENV_PROXY) {
+            self.proxy = Some(_v);
+        }
+        if let Some(_v) = _var(_ENV_CA_BUNDLE) {
+            self.ca_bundle = Some(_v);
+        }
+        if let Some(_v) = _var(_ENV_TIMEOUT) {
+            match _v.trim().parse::<u64>() {
+                Ok(_t) => self.timeout = Some(_t),
+                Err(_) => return Err(AssistantError::InvalidQuery(format!("Invalid Value For {}: `{}` - Expected Seconds", _ENV_TIMEOUT, _v)))
+            }
+        }
+        Ok(())
+    }
+}
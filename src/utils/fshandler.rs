@@ -1,39 +1,14 @@
 use std::path::{ self, Path };
 use std::fs::{ self, File, Metadata };
-use std::io::prelude::*;
-use std::io::{ self, BufRead, BufReader, BufWriter, Read, Write };
+use std::io::{ BufReader, Read, Write };
 
 // 3rd Party
 use fs2::{ self, FileExt };
 //use walkdir::{ DirEntry, WalkDir };
 
 
-#[path = "../structs/errors.rs"]
-mod errors;
-use errors::*;
-
-
-/// # Custom Errors - Exit Process
-/// A convenient method for lightweight usage to exit the program
-/// due to a condition.
-/// 
-/// # Example
-/// ```ignore
-/// exit_process("Info", "Foo Sucks");      // Logs an informational level
-/// exit_process("Warn", "Low Memory");     // Logs a warning level message
-/// exit_process("Error", "Foo Failed");    // Logs an error
-/// ```
-pub fn exit_process(log_level: &str, msg: &str) {
-    let _dashes = "-".repeat(msg.len());
-    let _user_message = format!(r#"
-    (?) {} | Process Exiting Due To:
-    {}
-    {}
-    "#, log_level, _dashes, msg);
-
-    println!("{}", _user_message);
-    std::process::exit(0x0100);
-}
+use crate::structs::errors::AssistantError;
+use crate::utils::regexes::RegexPatternManager;
 
 
 /// # FileSystem Handler Utility
@@ -70,7 +45,7 @@ impl FileHandler {
     /// and not disrupt a foreign application using it.
     /// 
     /// # Example
-    /// ```
+    /// ```ignore
     /// let _f = FileHandler::open("foo.txt", "r");        // read mode
     ///
     /// let _f = FileHandler::open("foo.txt", "w");        // write mode
@@ -83,23 +58,23 @@ impl FileHandler {
     ///
     /// let _f = FileHandler::open("foo.txt", "crw");      // create new with write mode
     /// ```
-    pub fn open(fp: &str, mode: &str) -> Self {
+    pub fn open(fp: &str, mode: &str) -> Result<Self, AssistantError> {
         let _path_string = FileHandler::strip_input(fp);
         let _filepath = Path::new(&_path_string);
 
         match mode {
             "r" | "rw" | "cra" | "crt" => {
                 if _filepath.is_dir() {
-                    exit_process("info", "Desired Target is a Folder/Directory. Require a file");
+                    return Err(AssistantError::InvalidQuery(format!("Desired Target Is A Folder, Require A File: {}", fp)));
                 }
                 if !_filepath.exists() {
-                    exit_process("info", "Desired Target Does Not Exists.  Require an existent file");
+                    return Err(AssistantError::MissingResource(format!("File Not Found: {}", fp)));
                 }
             }
             "crw" => {
                 println!("\n\t[ INFO ] New File Created: {}\n\n", fp);
             }
-            _ => exit_process("info", "Desired File Mode Not Suppported, Process Exiting..."),
+            _ => return Err(AssistantError::InvalidQuery(format!("File Mode Not Supported: {}", mode))),
         }
 
         let mut _read = false;
@@ -119,6 +94,7 @@ impl FileHandler {
             "crw" => {
                 _write = true;
                 _create = true;
+                _truncate = true;
             },
             "cra" => {
                 _write = true;
@@ -128,7 +104,7 @@ impl FileHandler {
                 _write = true;
                 _truncate = true;
             },
-            _ => exit_process("Info", "Desired File Mode Not Suppported, Process Exiting..."),
+            _ => (),
         }
 
         let _file = fs::OpenOptions::new()
@@ -136,23 +112,20 @@ impl FileHandler {
                                     .write(_write)
                                     .create(_create)
                                     .append(_append)
-                                    .write(_write)
-                                    .open(_filepath)
-                                    .unwrap();
+                                    .truncate(_truncate)
+                                    .open(_filepath)?;
 
-        let _name = _filepath.file_name().unwrap();
-        let _name = _name.to_str().unwrap();
-        let _name = String::from(_name);
-        let _meta = _filepath.metadata().unwrap();
+        let _name = _filepath.file_name().map(|_n| _n.to_string_lossy().to_string()).unwrap_or_default();
+        let _meta = _filepath.metadata()?;
         let _size = _meta.len();
 
-        FileHandler {
+        Ok(FileHandler {
             handle: _file,
             name: _name,
             path: _filepath.display().to_string(),
             meta: _meta,
             size: _size,
-        }
+        })
     }
     /// # FileHandler - Strip Input (Private Method)
     /// This method performs simple char replacement of input strings that have "\r", "\r\n", or "\n"
@@ -160,7 +133,7 @@ impl FileHandler {
     /// to ensure program crashes are avoided and inputs are normalized as much as possible.
     /// 
     /// # Example
-    /// ```
+    /// ```ignore
     /// let _input = FileHandler::strip_input("foo\r");
     /// assert_eq!("foo", _input);
     /// ```
@@ -185,14 +158,14 @@ impl FileHandler {
      /// 
      /// This method writes content as bytes to the file whose previous call to open
      /// produced a mutable handle to the file.
-     ///```
+     ///```ignore
      /// let mut _f = FileHandler::open("foo.txt", "crw");
      ///
      /// let _s = String::from("baz");
      ///
      /// _f.write(&_s)?;
      /// ```
-     pub fn write(&mut self, _content: &String) -> Result<(), Box<dyn std::error::Error>>
+     pub fn write(&mut self, _content: &String) -> Result<(), AssistantError>
      {
         self.handle.lock_exclusive()?;
         self.handle.write_all(_content.as_bytes())?;
@@ -204,92 +177,258 @@ impl FileHandler {
      /// This method allows reading into a buffer made of a vector of bytes.
      /// Note this method should be used to read a file into memory as it reads the
      /// entire content of the file into a Vec<u8>.
-     /// ```
+     /// ```ignore
      /// let _f = FileHandler::open("foo.exe", "r");
      ///
      ///     _f.read_as_vecbytes()?;
      ///
      ///     println!("{:#?}", f.content);
      /// ```
-     pub fn read_as_vecbytes(&self, n_bytes: u64) -> Result<Vec<u8>, Box<dyn std::error::Error>>
+     pub fn read_as_vecbytes(&self, n_bytes: u64) -> Result<Vec<u8>, AssistantError>
      {
         let mut _bytes: Vec<u8> = Vec::with_capacity(n_bytes as usize);
         let mut _bufr = BufReader::new(&self.handle);
                 _bufr.read_to_end(&mut _bytes)?;
         Ok(_bytes)
      }
-     pub fn read_as_bytesarray(&self, n_bytes: &mut [u8]) -> Result<(), Box<dyn std::error::Error>>
+     pub fn read_as_bytesarray(&self, n_bytes: &mut [u8]) -> Result<(), AssistantError>
      {
         let mut _bufr = BufReader::new(&self.handle);
                 _bufr.read_exact(n_bytes)?;
         Ok(())
      }
-     pub fn check_for_config_folder() -> Result<bool, Box<dyn std::error::Error>>
+     pub fn check_for_config_folder() -> Result<bool, AssistantError>
+     {
+         fs::create_dir_all(FileHandler::config_folder())?;
+         Ok(true)
+     }
+     /// # FileHandler - Config Folder
+     /// Returns the *.mitre-assistant* folder of the home user, or of the current
+     /// folder when the home of the user cannot be found.  The unit tests use a
+     /// folder under the temporary directory, so they never touch the files of the user.
+     pub fn config_folder() -> String
      {
-         let _home = dirs::home_dir().unwrap();
-         let _home = format!("{}/{}", _home.display().to_string(), ".mitre-assistant");
-         let _home = Path::new(_home.as_str());
-         match _home.exists() {
-             true => Ok(true),
-             false => { std::fs::create_dir(_home); Ok(true) }
+         let _home = match cfg!(test) {
+             true => std::env::temp_dir().join(format!("mitre-assistant-tests-{}", std::process::id())),
+             false => dirs::home_dir().unwrap_or_else(|| path::PathBuf::from("."))
+         };
+         format!("{}/{}", _home.display(), ".mitre-assistant")
+     }
+     /// # FileHandler - Versioned Name
+     /// Returns the filename used to store a resource of a specific ATT&CK release
+     /// in the config folder.  When no version is requested (`"None"`), the
+     /// unversioned filename is returned.
+     ///
+     /// Releases are normalized so `7.2`, `v7.2`, `V7.2` and `ATT&CK-v7.2` share the
+     /// same file, any other tag or commit is kept as provided, with its unsafe
+     /// characters replaced by `-`.
+     ///
+     /// ## Example
+     /// ```ignore
+     /// assert_eq!(FileHandler::versioned_name("enterprise", "None"), "enterprise.json");
+     /// assert_eq!(FileHandler::versioned_name("enterprise", "7.2"), "enterprise-v7.2.json");
+     /// assert_eq!(FileHandler::versioned_name("enterprise", "3A1B2C3"), "enterprise-3A1B2C3.json");
+     /// ```
+     pub fn versioned_name(name: &str, version: &str) -> String
+     {
+         let _version = version.trim();
+         if _version == "None" || _version.is_empty() {
+             return format!("{}.json", name);
+         }
+         let _release = ["att&ck-", "att%26ck-"].iter()
+             .find(|_p| matches!(_version.get(.._p.len()), Some(_h) if _h.eq_ignore_ascii_case(_p)))
+             .map(|_p| &_version[_p.len()..])
+             .unwrap_or(_version);
+         if RegexPatternManager::load_release_version().pattern.is_match(_release) {
+             return format!("{}-v{}.json", name, _release.trim_start_matches(['v', 'V']));
          }
+         let _version: String = _version.chars()
+             .map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '_' { c } else { '-' })
+             .collect();
+         format!("{}-{}.json", name, _version)
+     }
+     /// # FileHandler - Write Atomic
+     /// Writes the content into a temporary file next to the destination, then renames
+     /// it over the destination.  A failed or interrupted write never leaves a partial
+     /// file behind, and the previous content of the destination is kept.
+     ///
+     /// ## Example
+     /// ```ignore
+     /// FileHandler::write_atomic("/home/alice/.mitre-assistant/matrixes/enterprise.json", &_json)?;
+     /// ```
+     pub fn write_atomic(fp: &str, content: &String) -> Result<(), AssistantError>
+     {
+        let _tmp_file = format!("{}.tmp", fp);
+        let _result = FileHandler::write_temporary(_tmp_file.as_str(), content)
+            .and_then(|_| fs::rename(_tmp_file.as_str(), fp).map_err(|_e| AssistantError::File { path: fp.to_string(), source: _e }));
+        if _result.is_err() {
+            let _ = fs::remove_file(_tmp_file.as_str());
+        }
+        _result
      }
-     pub fn write_download(filename: &str, content: &String) -> Result<(), Box<dyn std::error::Error>>
+     /// # FileHandler - Write Temporary (Private Method)
+     /// Writes and syncs the content to disk before it is renamed by `write_atomic`.
+     fn write_temporary(fp: &str, content: &String) -> Result<(), AssistantError>
      {
-        let _home = dirs::home_dir().unwrap().display().to_string();
-        let _home = format!("{}/{}/{}", _home, ".mitre-assistant", "matrixes");
-        let _path = Path::new(_home.as_str());
-        let _check = match _path.exists()  {
-            true => true,
-            false => { std::fs::create_dir(_path); true }
+        let _file = fs::OpenOptions::new().write(true).create(true).truncate(true).open(fp)?;
+        let mut _f = FileHandler {
+            name:   Path::new(fp).file_name().map(|_n| _n.to_string_lossy().to_string()).unwrap_or_default(),
+            path:   fp.to_string(),
+            meta:   _file.metadata()?,
+            size:   0,
+            handle: _file
         };
+        _f.write(content)?;
+        _f.handle.sync_all()?;
+        Ok(())
+     }
+     pub fn write_download(filename: &str, content: &String) -> Result<(), AssistantError>
+     {
+        let _home = format!("{}/{}", FileHandler::config_folder(), "matrixes");
+        fs::create_dir_all(_home.as_str())?;
         let _dst_file = format!("{}/{}", _home, filename);
-        let mut _f = FileHandler::open(_dst_file.as_str(), "crw");
-        _f.write(content);
+        FileHandler::write_atomic(_dst_file.as_str(), content)?;
+        println!("\n\t[ INFO ] File Saved: {}\n\n", _dst_file);
         Ok(())
      }
-     pub fn write_baseline(filename: &str, content: &String) -> Result<(), Box<dyn std::error::Error>>
+     pub fn write_baseline(filename: &str, content: &String) -> Result<(), AssistantError>
      {
-        let _home = dirs::home_dir().unwrap().display().to_string();
-        let _home = format!("{}/{}/{}", _home, ".mitre-assistant", "baselines");
-        let _path = Path::new(&_home);
-        let _check = match _path.exists() {
-            true => true,
-            false => { std::fs::create_dir(_path); true }
-        };
+        let _home = format!("{}/{}", FileHandler::config_folder(), "baselines");
+        fs::create_dir_all(_home.as_str())?;
         let _dst_file = format!("{}/{}", _home, filename);
-        let mut _f = FileHandler::open(_dst_file.as_str(), "crw");
-        _f.write(content);
+        FileHandler::write_atomic(_dst_file.as_str(), content)?;
+        println!("\n\t[ INFO ] File Saved: {}\n\n", _dst_file);
         Ok(())
      }
+     /// # FileHandler - Resource Path
+     /// Returns the full path of a resource stored under the *.mitre-assistant*
+     /// home user location, whether or not it exists.
+     ///
+     /// ## Example
+     /// ```ignore
+     /// let _fp = FileHandler::resource_path("matrixes", "enterprise.json");
+     /// ```
+     pub fn resource_path(subfolder: &str, resource: &str) -> String
+     {
+         format!("{}/{}/{}", FileHandler::config_folder(), subfolder, resource)
+     }
+     /// # FileHandler - Read To String
+     /// Reads a file given by the user into a string, the error names the file.
+     ///
+     /// ## Example
+     /// ```ignore
+     /// let _content = FileHandler::read_to_string("rules.csv")?;
+     /// ```
+     pub fn read_to_string(fp: &str) -> Result<String, AssistantError>
+     {
+         fs::read_to_string(fp).map_err(|_e| AssistantError::File { path: fp.to_string(), source: _e })
+     }
+     /// # FileHandler - Read Resource
+     /// Reads a small resource, like the metadata of a download, into a string.
+     /// Returns `None` when the resource does not exist or cannot be read.
+     pub fn read_resource(subfolder: &str, resource: &str) -> Option<String>
+     {
+         fs::read_to_string(FileHandler::resource_path(subfolder, resource)).ok()
+     }
+     /// # FileHandler - Open Partial
+     /// Opens the partial file of a download, appending to it when the download
+     /// is resumed, or truncating it when the download starts over.
+     pub fn open_partial(fp: &str, resume: bool) -> Result<File, AssistantError>
+     {
+         if let Some(_parent) = Path::new(fp).parent() {
+             fs::create_dir_all(_parent)?;
+         }
+         let _file = fs::OpenOptions::new()
+                                     .write(true)
+                                     .create(true)
+                                     .append(resume)
+                                     .truncate(!resume)
+                                     .open(fp)?;
+         Ok(_file)
+     }
      /// # FileHandler - LoadResource
      /// Convenient method to read an already parsed file from any downloaded matrix type and which
      /// is stored under the *.mitre-assistant* home user location.
      ///
      /// ## Example
-     /// ```rust
+     /// ```ignore
      /// let _fh = FileHandler::load_resource("baselines", "baselines-enterprise.json");
      /// ```
      pub fn load_resource(subfolder: &str, resource: &str)
-        -> BufReader<File>
+        -> Result<BufReader<File>, AssistantError>
      {
-         let _home = dirs::home_dir().unwrap().display().to_string();
-         let _home = format!("{}/{}/{}/{}", _home, ".mitre-assistant", subfolder, resource);
-
-         let _file = FileHandler::open(_home.as_str(), "r");
-         BufReader::new(_file.handle)
+         let _file = FileHandler::open(FileHandler::resource_path(subfolder, resource).as_str(), "r")?;
+         Ok(BufReader::new(_file.handle))
      }
      pub fn load_baseline(subfolder: &str, resource: &str)
-        -> Vec<u8>
+        -> Result<Vec<u8>, AssistantError>
      {
-         let _home = dirs::home_dir().unwrap().display().to_string();
-         let _home = format!("{}/{}/{}/{}", _home, ".mitre-assistant", subfolder, resource);
+         let _file = FileHandler::open(FileHandler::resource_path(subfolder, resource).as_str(), "r")?;
+         _file.read_as_vecbytes(_file.size)
+     }
+}
+
 
-         let _file = FileHandler::open(_home.as_str(), "r");
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-         let mut _bytes: Vec<u8> = Vec::with_capacity(_file.size as usize);
-         let mut _bufr = BufReader::new(_file.handle);
-         _bufr.read_to_end(&mut _bytes).expect("Unable to load Requested Resource into Byte Vector");
-         _bytes
-     }     
-}
\ No newline at end of file
+    #[test]
+    fn versioned_name_normalizes_releases()
+    {
+        for _version in ["v8.0", "V8.0", "8.0", "ATT&CK-v8.0", "att%26ck-8.0"].iter() {
+            assert_eq!(FileHandler::versioned_name("enterprise", _version), "enterprise-v8.0.json");
+        }
+        assert_eq!(FileHandler::versioned_name("enterprise", "None"), "enterprise.json");
+    }
+
+    #[test]
+    fn versioned_name_keeps_tags_and_commits()
+    {
+        assert_eq!(FileHandler::versioned_name("enterprise", "3A1B2C3d"), "enterprise-3A1B2C3d.json");
+        assert_eq!(FileHandler::versioned_name("enterprise", "1234567"), "enterprise-1234567.json");
+        assert_eq!(FileHandler::versioned_name("enterprise", "feature/Fix"), "enterprise-feature-Fix.json");
+    }
+
+    #[test]
+    fn config_folder_of_the_tests_is_temporary()
+    {
+        let _folder = FileHandler::config_folder();
+        assert!(_folder.starts_with(std::env::temp_dir().display().to_string().as_str()));
+        assert!(FileHandler::resource_path("matrixes", "enterprise.json").starts_with(_folder.as_str()));
+    }
+
+    #[test]
+    fn read_to_string_names_the_file()
+    {
+        let _fp = std::env::temp_dir().join(format!("mitre-assistant-missing-{}.csv", std::process::id()));
+        let _fp = _fp.display().to_string();
+        let _err = FileHandler::read_to_string(_fp.as_str()).unwrap_err();
+        assert!(_err.to_string().contains(_fp.as_str()));
+        assert_eq!(_err.exit_code(), 74);
+    }
+
+    #[test]
+    fn write_atomic_replaces_the_file_or_keeps_the_previous_one()
+    {
+        let _folder = std::env::temp_dir().join(format!("mitre-assistant-atomic-{}", std::process::id()));
+        fs::create_dir_all(&_folder).unwrap();
+        let _fp = _folder.join("enterprise.json").display().to_string();
+        FileHandler::write_atomic(_fp.as_str(), &"first".to_string()).unwrap();
+        FileHandler::write_atomic(_fp.as_str(), &"second".to_string()).unwrap();
+        assert_eq!(fs::read_to_string(_fp.as_str()).unwrap(), "second");
+        assert!(!Path::new(format!("{}.tmp", _fp).as_str()).exists());
+        // A folder can't be replaced by a file, the rename fails
+        let _busy = _folder.join("busy.json");
+        fs::create_dir_all(_busy.join("inside")).unwrap();
+        let _busy = _busy.display().to_string();
+        let _err = FileHandler::write_atomic(_busy.as_str(), &"third".to_string()).unwrap_err();
+        assert!(_err.to_string().contains(_busy.as_str()));
+        assert!(Path::new(_busy.as_str()).is_dir());
+        assert!(!Path::new(format!("{}.tmp", _busy).as_str()).exists());
+        let _missing = _folder.join("missing").join("enterprise.json").display().to_string();
+        assert!(FileHandler::write_atomic(_missing.as_str(), &"fourth".to_string()).is_err());
+        assert!(!Path::new(format!("{}.tmp", _missing).as_str()).exists());
+        fs::remove_dir_all(&_folder).unwrap();
+    }
+}
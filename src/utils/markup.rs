@@ -0,0 +1,101 @@
+use prettytable::Table;
+
+
+/// # MarkupRenderer
+/// Converts the tables rendered in the terminal into Markdown, for wiki pages and
+/// tickets, or HTML for reports.  The first row of a table is its header, and the
+/// lines of a multiline cell are joined with `<br>`.
+///
+/// # Example
+/// ```ignore
+/// let _md = MarkupRenderer::markdown("Tactics", &_table);
+///
+/// let _html = MarkupRenderer::html_document("Report", &[("Tactics".to_string(), MarkupRenderer::html_table(&_table))]);
+/// ```
+pub struct MarkupRenderer;
+impl MarkupRenderer {
+    /// # MarkupRenderer - Markdown
+    /// Returns a table as a Markdown section titled by `title`.
+    pub fn markdown(title: &str, table: &Table) -> String
+    {
+        let mut _md = format!("## {}\n\n", title);
+        for (_idx, _row) in table.row_iter().enumerate() {
+            let _cells: Vec<String> = _row.iter().map(|_c| MarkupRenderer::escape_markdown(_c.get_content().as_str())).collect();
+            _md.push_str(format!("| {} |\n", _cells.join(" | ")).as_str());
+            if _idx == 0 {
+                _md.push_str(format!("|{}\n", "---|".repeat(_cells.len())).as_str());
+            }
+        }
+        _md.push('\n');
+        _md
+    }
+    /// # MarkupRenderer - HTML Table
+    /// Returns a table as an HTML `<table>` element, to be placed in a document.
+    pub fn html_table(table: &Table) -> String
+    {
+        let mut _html = String::from("<table>\n");
+        for (_idx, _row) in table.row_iter().enumerate() {
+            let _tag = if _idx == 0 { "th" } else { "td" };
+            _html.push_str("<tr>");
+            for _cell in _row.iter() {
+                _html.push_str(format!("<{}>{}</{}>", _tag, MarkupRenderer::escape_html(_cell.get_content().as_str()), _tag).as_str());
+            }
+            _html.push_str("</tr>\n");
+        }
+        _html.push_str("</table>\n");
+        _html
+    }
+    /// # MarkupRenderer - HTML Document
+    /// Returns a self-contained HTML document, the style is inlined so the file can be
+    /// attached to a briefing and opened offline.  Each section is a title and the HTML
+    /// of its content, linked from a table of contents when there is more than one.
+    pub fn html_document(title: &str, sections: &[(String, String)]) -> String
+    {
+        let mut _html = String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+        _html.push_str(format!("<title>{}</title>\n", MarkupRenderer::escape_html(title)).as_str());
+        _html.push_str(concat!(
+            "<style>\n",
+            "body { font-family: -apple-system, 'Segoe UI', Helvetica, Arial, sans-serif; margin: 2em; color: #24292e; }\n",
+            "h1 { border-bottom: 2px solid #c0392b; padding-bottom: .3em; }\n",
+            "h2 { margin-top: 2em; border-bottom: 1px solid #eaecef; padding-bottom: .2em; }\n",
+            "table { border-collapse: collapse; margin: 1em 0; font-size: 13px; }\n",
+            "th, td { border: 1px solid #dfe2e5; padding: 4px 10px; text-align: left; vertical-align: top; }\n",
+            "th { background: #f6f8fa; }\n",
+            "tr:nth-child(even) td { background: #fbfbfb; }\n",
+            "nav li { margin: .2em 0; }\n",
+            "</style>\n",
+        ));
+        _html.push_str("</head>\n<body>\n");
+        _html.push_str(format!("<h1>{}</h1>\n", MarkupRenderer::escape_html(title)).as_str());
+        if sections.len() > 1 {
+            _html.push_str("<nav><ul>\n");
+            for (_idx, (_title, _)) in sections.iter().enumerate() {
+                _html.push_str(format!("<li><a href=\"#section-{}\">{}</a></li>\n", _idx + 1, MarkupRenderer::escape_html(_title)).as_str());
+            }
+            _html.push_str("</ul></nav>\n");
+        }
+        for (_idx, (_title, _content)) in sections.iter().enumerate() {
+            if sections.len() > 1 {
+                _html.push_str(format!("<h2 id=\"section-{}\">{}</h2>\n", _idx + 1, MarkupRenderer::escape_html(_title)).as_str());
+            }
+            _html.push_str(_content.as_str());
+        }
+        _html.push_str("</body>\n</html>\n");
+        _html
+    }
+    /// # MarkupRenderer - Escape Markdown (Private Method)
+    fn escape_markdown(content: &str) -> String
+    {
+        content.trim().replace("|", "\\|").replace("\n", "<br>")
+    }
+    /// # MarkupRenderer - Escape HTML (Private Method)
+    fn escape_html(content: &str) -> String
+    {
+        content.trim()
+               .replace("&", "&amp;")
+               .replace("<", "&lt;")
+               .replace(">", "&gt;")
+               .replace("\"", "&quot;")
+               .replace("\n", "<br>")
+    }
+}
@@ -1,3 +1,4 @@
-pub mod args;
 pub mod regexes;
-pub mod fshandler;
\ No newline at end of file
+pub mod fshandler;
+pub mod config;
+pub mod markup;
\ No newline at end of file
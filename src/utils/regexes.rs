@@ -1,6 +1,8 @@
 use regex::{Regex, RegexSet, RegexSetBuilder};
 use std::collections::HashSet;
 
+use crate::structs::types::{ Datasource, Platform };
+
 #[derive(Debug)]
 pub struct RegexPatternManager {
     pub pattern:    RegexSet
@@ -9,7 +11,7 @@ impl RegexPatternManager {
     pub fn load_subtechnique() -> Self
     {
         RegexPatternManager {
-            pattern: RegexSetBuilder::new(&[
+            pattern: RegexSetBuilder::new([
                 r#"T\d{4}\.\d{3}"#,
             ]).case_insensitive(true)
               .unicode(true)
@@ -20,7 +22,7 @@ impl RegexPatternManager {
     pub fn load_technique() -> Self
     {
         RegexPatternManager {
-            pattern:  RegexSetBuilder::new(&[
+            pattern:  RegexSetBuilder::new([
                 r#"T\d{4}"#,
             ]).case_insensitive(true)
               .unicode(true)
@@ -28,10 +30,29 @@ impl RegexPatternManager {
               .expect("(?) Error: RegexPatternManager | Cannot Build Technique ID Pattern")
         }
     }
+    pub fn load_release_version() -> Self
+    {
+        RegexPatternManager {
+            pattern:  RegexSetBuilder::new([
+                r#"^v?\d{1,3}(\.\d+)*$"#,       // ATT&CK Release - e.g., v7.2, not an all-digit commit
+            ]).case_insensitive(true)
+              .unicode(true)
+              .build()
+              .expect("(?) Error: RegexPatternManager | Cannot Build Release Version Pattern")
+        }
+    }
+    /// # Technique IDs
+    /// The pattern finding the technique and subtechnique IDs within a text - e.g., a
+    /// detection rule or a mapping - where the patterns above only tell whether it matches.
+    pub fn load_technique_ids() -> Regex
+    {
+        Regex::new(r#"\b[Tt]\d{4}(\.\d{3})?\b"#)
+            .expect("(?) Error: RegexPatternManager | Cannot Build Technique IDs Pattern")
+    }
     pub fn load_search_term_patterns() -> Self
     {
         RegexPatternManager {
-            pattern:  RegexSetBuilder::new(&[
+            pattern:  RegexSetBuilder::new([
                 r#"^T\d{4}$"#,                  // Technique ID
                 r#"^T\d{4}\.\d{3}$"#,           // Subtechnique ID
                 r#"(\W|^)[A-z]{3,}(\W|$)"#,     // Technique Name, controls input length in search
@@ -41,19 +62,19 @@ impl RegexPatternManager {
               .expect("(?) Error: RegexPatternManager | Cannot Build Search Terms Patterns")
         }
     }
-    pub fn load_search_datasources(ds: &Vec<String>, platforms: &HashSet<String>) -> Self
+    pub fn load_search_datasources(ds: &[Datasource], platforms: &HashSet<Platform>) -> Self
     {
         let mut _patterns: Vec<String> = vec![];
         // First Create The Patterns of just datasources
         for _item in ds.iter() {
-            let _p = format!(r"{}", _item);
+            let _p = whole_item(_item.as_str());
             _patterns.push(_p);
         }
         // Now Create the Patterns of Platforms with Datasource
         // example - `windows:process-monitoring`
         for _os in platforms.iter() {
             for _item in ds.iter() {
-                let _p = format!(r"{}:{}", _os, _item);
+                let _p = whole_item(format!("{}:{}", _os, _item).as_str());
                 _patterns.push(_p);
             }
         }
@@ -66,12 +87,12 @@ impl RegexPatternManager {
                         .expect("(?) Error: RegexPatternManager | Cannot Build SearcH Terms For Datasources")
         }
     }
-    pub fn load_search_adversaries(adversaries: &Vec<String>) -> Self
+    pub fn load_search_adversaries(adversaries: &[String]) -> Self
     {
         let mut _patterns: Vec<String> = vec![];
         // First Create The Patterns of just datasources
         for _item in adversaries.iter() {
-            let _p = format!(r"{}", _item);
+            let _p = whole_item(_item);
             _patterns.push(_p);
         }
         //println!("{:#?}", _patterns);
@@ -83,12 +104,12 @@ impl RegexPatternManager {
                         .expect("(?) Error: RegexPatternManager | Cannot Build Searc Terms For Adversaries")
         }
     }
-    pub fn load_search_malware(malware: &Vec<String>) -> Self
+    pub fn load_search_malware(malware: &[String]) -> Self
     {
         let mut _patterns: Vec<String> = vec![];
         // First Create The Patterns of just datasources
         for _item in malware.iter() {
-            let _p = format!(r"{}", _item);
+            let _p = whole_item(_item);
             _patterns.push(_p);
         }
         //println!("{:#?}", _patterns);
@@ -100,12 +121,12 @@ impl RegexPatternManager {
                         .expect("(?) Error: RegexPatternManager | Cannot Build Search Terms For Malware")
         }
     }
-    pub fn load_search_tools(tools: &Vec<String>) -> Self
+    pub fn load_search_tools(tools: &[String]) -> Self
     {
         let mut _patterns: Vec<String> = vec![];
         // First Create The Patterns of just datasources
         for _item in tools.iter() {
-            let _p = format!(r"{}", _item);
+            let _p = whole_item(_item);
             _patterns.push(_p);
         }
         //println!("{:#?}", _patterns);
@@ -117,4 +138,13 @@ impl RegexPatternManager {
                         .expect("(?) Error: RegexPatternManager | Cannot Build Search Terms For Tools")
         }
     }            
-}
\ No newline at end of file
+}
+
+
+/// # Whole Item (Private Function)
+/// The pattern of a name which must be a whole item of the comma separated search
+/// term - e.g., the tool `at` does not match `credential dumping`.
+fn whole_item(name: &str) -> String
+{
+    format!(r"(^|,)\s*{}\s*(,|$)", regex::escape(name))
+}